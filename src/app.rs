@@ -1,9 +1,15 @@
+use crate::session::{
+    CodeConfigSnapshot, SESSION_EXTENSION, SESSION_VERSION, Session, SessionError, UiSettings,
+    load_session, save_session,
+};
+use crate::state::sweep::SweepJob;
 use crate::state::{CodeConfig, Labs1To3State, Labs4To6ExperimentResult};
 use crate::ui::{render_labs1to3_ui, render_labs4to6_ui};
 use eframe::{App, Frame, egui};
+use serde::{Deserialize, Serialize};
 
-#[derive(PartialEq, Eq, Clone, Copy)]
-enum LabMode {
+#[derive(PartialEq, Eq, Clone, Copy, Serialize, Deserialize)]
+pub enum LabMode {
     Labs1To3,
     Labs4To6,
 }
@@ -11,8 +17,11 @@ enum LabMode {
 pub struct InfoEntropyApp {
     lab_mode: LabMode,
     labs1to3_state: Labs1To3State,
+    /// Идущий перебор параметра Labs 1-3
+    sweep_job: Option<SweepJob>,
     code_config: CodeConfig,
     labs4to6_results: Vec<Labs4To6ExperimentResult>,
+    status_message: Option<String>,
 }
 
 impl Default for InfoEntropyApp {
@@ -20,16 +29,142 @@ impl Default for InfoEntropyApp {
         Self {
             lab_mode: LabMode::Labs1To3,
             labs1to3_state: Labs1To3State::default(),
+            sweep_job: None,
             code_config: CodeConfig::new(60),
             labs4to6_results: vec![],
+            status_message: None,
         }
     }
 }
 
+impl InfoEntropyApp {
+    /// Создание приложения с восстановлением настроек предыдущего запуска
+    pub fn new(cc: &eframe::CreationContext<'_>) -> Self {
+        let mut app = Self::default();
+        if let Some(settings) = cc
+            .storage
+            .and_then(|storage| eframe::get_value::<UiSettings>(storage, eframe::APP_KEY))
+        {
+            app.apply_settings(settings);
+        }
+        app
+    }
+
+    /// Текущие настройки интерфейса (без результатов и сгенерированных кодов)
+    fn to_settings(&self) -> UiSettings {
+        let mut labs1to3_state = self.labs1to3_state.clone();
+        labs1to3_state.results.clear();
+        labs1to3_state.sweep_results.clear();
+
+        let mut code_config = CodeConfigSnapshot::from(&self.code_config);
+        code_config.systematic_code = None;
+        code_config.cyclic_code = None;
+        code_config.ber_results.clear();
+
+        UiSettings {
+            lab_mode: self.lab_mode,
+            labs1to3_state,
+            code_config: Some(code_config),
+        }
+    }
+
+    /// Применение сохраненных настроек интерфейса
+    fn apply_settings(&mut self, settings: UiSettings) {
+        self.lab_mode = settings.lab_mode;
+        self.labs1to3_state = settings.labs1to3_state;
+        if let Some(code_config) = settings.code_config
+            && let Ok(code_config) = CodeConfig::try_from(code_config)
+        {
+            self.code_config = code_config;
+        }
+    }
+
+    /// Снимок текущей сессии
+    fn to_session(&self) -> Session {
+        Session {
+            version: SESSION_VERSION,
+            lab_mode: self.lab_mode,
+            labs1to3_state: self.labs1to3_state.clone(),
+            code_config: (&self.code_config).into(),
+            labs4to6_results: self.labs4to6_results.clone(),
+        }
+    }
+
+    /// Восстановление состояния из сессии
+    ///
+    /// # Errors
+    /// Возвращает ошибку, если сохраненный код некорректен; состояние при этом не меняется
+    fn apply_session(&mut self, session: Session) -> Result<(), SessionError> {
+        self.code_config =
+            CodeConfig::try_from(session.code_config).map_err(SessionError::InvalidCode)?;
+        self.lab_mode = session.lab_mode;
+        self.labs1to3_state = session.labs1to3_state;
+        self.sweep_job = None;
+        self.labs4to6_results = session.labs4to6_results;
+        Ok(())
+    }
+
+    /// Диалог сохранения сессии
+    fn save_session_dialog(&mut self) {
+        let Some(path) = rfd::FileDialog::new()
+            .add_filter("Сессия", &[SESSION_EXTENSION])
+            .set_file_name(format!("session.{SESSION_EXTENSION}"))
+            .save_file()
+        else {
+            return;
+        };
+
+        self.status_message = Some(match save_session(&path, &self.to_session()) {
+            Ok(()) => format!("Сессия сохранена: {}", path.display()),
+            Err(err) => format!("Не удалось сохранить сессию: {err}"),
+        });
+    }
+
+    /// Диалог открытия сессии
+    fn open_session_dialog(&mut self) {
+        let Some(path) = rfd::FileDialog::new()
+            .add_filter("Сессия", &[SESSION_EXTENSION])
+            .pick_file()
+        else {
+            return;
+        };
+
+        self.status_message = Some(
+            match load_session(&path).and_then(|session| self.apply_session(session)) {
+                Ok(()) => format!("Сессия загружена: {}", path.display()),
+                Err(err) => format!("Не удалось открыть сессию: {err}"),
+            },
+        );
+    }
+
+    /// Рендеринг меню
+    fn render_menu_bar(&mut self, ui: &mut egui::Ui) {
+        egui::MenuBar::new().ui(ui, |ui| {
+            ui.menu_button("Файл", |ui| {
+                if ui.button("Открыть сессию...").clicked() {
+                    self.open_session_dialog();
+                }
+                if ui.button("Сохранить сессию...").clicked() {
+                    self.save_session_dialog();
+                }
+            });
+
+            if let Some(ref message) = self.status_message {
+                ui.separator();
+                ui.label(message);
+            }
+        });
+    }
+}
+
 impl App for InfoEntropyApp {
     fn update(&mut self, ctx: &egui::Context, _frame: &mut Frame) {
         ctx.request_repaint();
 
+        egui::TopBottomPanel::top("menu_bar").show(ctx, |ui| {
+            self.render_menu_bar(ui);
+        });
+
         egui::CentralPanel::default().show(ctx, |ui| {
             // Mode selector
             ui.horizontal(|ui| {
@@ -49,7 +184,7 @@ impl App for InfoEntropyApp {
 
             match self.lab_mode {
                 LabMode::Labs1To3 => {
-                    render_labs1to3_ui(ui, &mut self.labs1to3_state);
+                    render_labs1to3_ui(ui, &mut self.labs1to3_state, &mut self.sweep_job);
                 }
                 LabMode::Labs4To6 => {
                     render_labs4to6_ui(ui, &mut self.code_config, &mut self.labs4to6_results);
@@ -57,4 +192,8 @@ impl App for InfoEntropyApp {
             }
         });
     }
+
+    fn save(&mut self, storage: &mut dyn eframe::Storage) {
+        eframe::set_value(storage, eframe::APP_KEY, &self.to_settings());
+    }
 }
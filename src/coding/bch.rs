@@ -0,0 +1,285 @@
+use crate::coding::cyclic::CyclicCode;
+use crate::coding::gf::{GaloisField, berlekamp_massey};
+use crate::coding::poly::{degree, poly_mul};
+use serde::{Deserialize, Serialize};
+
+/// Параметры кода БЧХ, задаваемые пользователем
+#[derive(Clone, Copy, Serialize, Deserialize)]
+#[serde(default)]
+pub struct BchParameters {
+    /// Степень расширения поля GF(2^m), длина кода `n = 2^m - 1`
+    pub m: usize,
+    /// Конструктивное расстояние δ
+    pub designed_distance: usize,
+}
+
+impl Default for BchParameters {
+    fn default() -> Self {
+        Self {
+            m: 4,
+            designed_distance: 5,
+        }
+    }
+}
+
+impl BchParameters {
+    pub fn build(self) -> Result<BchCode, String> {
+        BchCode::new(self.m, self.designed_distance)
+    }
+}
+
+/// Результат декодирования кода БЧХ
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub enum BchErrorInfo {
+    NoError,
+    /// Исправлены ошибки в разрядах (с 1)
+    Corrected(Vec<usize>),
+    /// Число ошибок больше t: корни Λ(x) не найдены или их меньше степени Λ(x)
+    Uncorrectable,
+}
+
+/// Промежуточные величины декодирования для карточки эксперимента
+#[derive(Clone)]
+pub struct BchDecoding {
+    pub corrected: Vec<bool>,
+    pub info: BchErrorInfo,
+    /// Синдромы `S_j = r(α^j)`, `j = 1..2t`
+    pub syndromes: Vec<usize>,
+    /// Многочлен локаторов ошибок Λ(x) (коэффициенты по возрастанию степени)
+    pub locator: Vec<usize>,
+    /// Степени x, найденные процедурой Ченя (`Λ(α^-e) = 0`)
+    pub error_powers: Vec<usize>,
+}
+
+/// Примитивный узкий код БЧХ длины `n = 2^m - 1` с конструктивным расстоянием δ
+///
+/// Порождающий многочлен - НОК минимальных многочленов `α, α^2, ..., α^(δ-1)`
+#[derive(Clone)]
+pub struct BchCode {
+    pub designed_distance: usize,
+    /// Число гарантированно исправляемых ошибок `t = (δ - 1) / 2`
+    pub t: usize,
+    pub field: GaloisField,
+    /// Минимальные многочлены, вошедшие в g(x), и представители их циклотомических классов
+    pub minimal_polynomials: Vec<(usize, Vec<bool>)>,
+    /// Двоичный циклический код с тем же g(x): кодирование и двоичный синдром
+    pub cyclic: CyclicCode,
+}
+
+impl BchCode {
+    pub const MIN_M: usize = 3;
+    pub const MAX_M: usize = 10;
+
+    pub fn new(m: usize, designed_distance: usize) -> Result<Self, String> {
+        if !(Self::MIN_M..=Self::MAX_M).contains(&m) {
+            return Err(format!(
+                "m должно быть от {} до {}",
+                Self::MIN_M,
+                Self::MAX_M
+            ));
+        }
+        let field = GaloisField::new(m).ok_or("не удалось построить поле GF(2^m)")?;
+        let n = field.order();
+        if !(2..=n).contains(&designed_distance) {
+            return Err(format!("δ должно быть от 2 до n = {n}"));
+        }
+
+        let mut covered = vec![false; n];
+        let mut minimal_polynomials = vec![];
+        let mut generator = vec![true];
+        for power in 1..designed_distance {
+            if covered[power] {
+                continue;
+            }
+            let coset = cyclotomic_coset(power, n);
+            for &member in &coset {
+                covered[member] = true;
+            }
+            let minimal = minimal_polynomial(&field, &coset);
+            generator = poly_mul(&generator, &minimal);
+            minimal_polynomials.push((power, minimal));
+        }
+
+        let p = degree(&generator).unwrap_or(0);
+        if p >= n {
+            return Err("степень g(x) не меньше n: код не содержит сообщений".to_string());
+        }
+        let cyclic = CyclicCode::new(n - p, n, generator)?;
+        Ok(Self {
+            designed_distance,
+            t: (designed_distance - 1) / 2,
+            field,
+            minimal_polynomials,
+            cyclic,
+        })
+    }
+
+    /// Синдромы `S_j = r(α^j)`, `j = 1..2t`; разряд `i` слова - коэффициент при `x^(n-1-i)`
+    #[must_use]
+    pub fn syndromes(&self, received: &[bool]) -> Vec<usize> {
+        let n = self.cyclic.n;
+        (1..=2 * self.t)
+            .map(|j| {
+                received
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, bit)| **bit)
+                    .fold(0, |sum, (i, _)| {
+                        sum ^ self.field.alpha_pow(power_i64(j * (n - 1 - i)))
+                    })
+            })
+            .collect()
+    }
+
+    /// Декодирование: синдромы, алгоритм Берлекэмпа–Мэсси и процедура Ченя
+    #[must_use]
+    pub fn decode(&self, received: &[bool]) -> BchDecoding {
+        let n = self.cyclic.n;
+        let syndromes = self.syndromes(received);
+        let mut corrected = received.to_vec();
+        if syndromes.iter().all(|&s| s == 0) {
+            return BchDecoding {
+                corrected,
+                info: BchErrorInfo::NoError,
+                syndromes,
+                locator: vec![1],
+                error_powers: vec![],
+            };
+        }
+
+        let (locator, length) = berlekamp_massey(&self.field, &syndromes);
+        // Корни Λ(x) - обратные локаторы: Λ(α^-e) = 0 для ошибки в x^e
+        let error_powers: Vec<usize> = (0..n)
+            .filter(|&e| {
+                self.field
+                    .eval(&locator, self.field.alpha_pow(-power_i64(e)))
+                    == 0
+            })
+            .collect();
+
+        let info = if length > self.t || error_powers.len() != length {
+            BchErrorInfo::Uncorrectable
+        } else {
+            let mut positions: Vec<usize> = error_powers.iter().map(|&e| n - e).collect();
+            positions.sort_unstable();
+            for &position in &positions {
+                corrected[position - 1] = !corrected[position - 1];
+            }
+            BchErrorInfo::Corrected(positions)
+        };
+        BchDecoding {
+            corrected,
+            info,
+            syndromes,
+            locator,
+            error_powers,
+        }
+    }
+}
+
+/// Циклотомический класс `{s, 2s, 4s, ...} mod n`
+fn cyclotomic_coset(representative: usize, n: usize) -> Vec<usize> {
+    let mut coset = vec![representative];
+    let mut next = representative * 2 % n;
+    while next != representative {
+        coset.push(next);
+        next = next * 2 % n;
+    }
+    coset
+}
+
+/// Минимальный многочлен: `П (x + α^c)` по элементам циклотомического класса
+fn minimal_polynomial(field: &GaloisField, coset: &[usize]) -> Vec<bool> {
+    coset
+        .iter()
+        .fold(vec![1], |poly, &c| {
+            field.poly_mul(&poly, &[field.alpha_pow(power_i64(c)), 1])
+        })
+        .into_iter()
+        .map(|c| c == 1)
+        .collect()
+}
+
+/// Степень α как `i64` (степени ограничены `n^2` при `n < 2^10`)
+fn power_i64(power: usize) -> i64 {
+    i64::try_from(power).unwrap_or(i64::MAX)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn flip(word: &[bool], positions: &[usize]) -> Vec<bool> {
+        let mut received = word.to_vec();
+        for &position in positions {
+            received[position] = !received[position];
+        }
+        received
+    }
+
+    fn message(k: usize) -> Vec<bool> {
+        (0..k).map(|i| i % 3 == 0).collect()
+    }
+
+    #[test]
+    fn known_dimensions() {
+        for (m, delta, k) in [
+            (4, 3, 11),
+            (4, 5, 7),
+            (4, 7, 5),
+            (5, 5, 21),
+            (5, 7, 16),
+            (6, 5, 51),
+        ] {
+            let code = BchCode::new(m, delta).unwrap();
+            assert_eq!(code.cyclic.k, k, "m = {m}, δ = {delta}");
+            assert!(code.cyclic.divides_x_n_plus_one());
+        }
+        // g(x) кода (15, 7) = x^8 + x^7 + x^6 + x^4 + 1
+        let code = BchCode::new(4, 5).unwrap();
+        assert_eq!(
+            code.cyclic.generator,
+            vec![true, false, false, false, true, false, true, true, true]
+        );
+    }
+
+    #[test]
+    fn rejects_invalid_parameters() {
+        assert!(BchCode::new(2, 3).is_err());
+        assert!(BchCode::new(4, 1).is_err());
+        assert!(BchCode::new(4, 16).is_err());
+    }
+
+    #[test]
+    fn corrects_all_double_errors_in_15_7() {
+        let code = BchCode::new(4, 5).unwrap();
+        let codeword = code.cyclic.encode(&message(code.cyclic.k));
+        assert!(matches!(code.decode(&codeword).info, BchErrorInfo::NoError));
+        for i in 0..15 {
+            for j in i..15 {
+                let positions: Vec<usize> = if i == j { vec![i] } else { vec![i, j] };
+                let decoding = code.decode(&flip(&codeword, &positions));
+                assert_eq!(decoding.corrected, codeword, "ошибки в {positions:?}");
+                let expected: Vec<usize> = positions.iter().map(|p| p + 1).collect();
+                assert!(matches!(decoding.info, BchErrorInfo::Corrected(ref p) if *p == expected));
+            }
+        }
+    }
+
+    #[test]
+    fn corrects_up_to_t_errors_in_longer_codes() {
+        for (m, delta) in [(5, 7), (6, 9), (7, 11)] {
+            let code = BchCode::new(m, delta).unwrap();
+            let n = code.cyclic.n;
+            let codeword = code.cyclic.encode(&message(code.cyclic.k));
+            for start in 0..n {
+                let positions: Vec<usize> = (0..code.t).map(|e| (start + e * 5) % n).collect();
+                let decoding = code.decode(&flip(&codeword, &positions));
+                assert_eq!(
+                    decoding.corrected, codeword,
+                    "n = {n}, ошибки в {positions:?}"
+                );
+            }
+        }
+    }
+}
@@ -0,0 +1,303 @@
+use serde::{Deserialize, Serialize};
+
+/// Параметры сверточного кода, задаваемые пользователем
+#[derive(Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ConvolutionalParameters {
+    /// Длина кодового ограничения K (число разрядов регистра вместе с текущим входом)
+    pub constraint_length: usize,
+    /// Порождающие многочлены в восьмеричной записи через пробел или запятую
+    pub generators: String,
+}
+
+impl Default for ConvolutionalParameters {
+    fn default() -> Self {
+        Self {
+            constraint_length: 3,
+            generators: "7, 5".to_string(),
+        }
+    }
+}
+
+impl ConvolutionalParameters {
+    pub fn build(&self) -> Result<ConvolutionalCode, String> {
+        let generators = self
+            .generators
+            .split([',', ' ', ';'])
+            .filter(|part| !part.is_empty())
+            .map(|part| {
+                usize::from_str_radix(part, 8)
+                    .map_err(|_| format!("«{part}» - не восьмеричное число"))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        ConvolutionalCode::new(self.constraint_length, generators)
+    }
+}
+
+/// Результат декодирования сверточного кода
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub enum ViterbiErrorInfo {
+    NoError,
+    /// Найден путь на расстоянии Хэмминга `distance` от принятой последовательности
+    Corrected(usize),
+    /// Выживший путь на расстоянии `distance`, но сообщение восстановлено неверно
+    Miscorrected(usize),
+}
+
+/// Решетка декодера Витерби
+#[derive(Clone)]
+pub struct ViterbiDecoding {
+    /// Декодированное сообщение без хвостовых нулей
+    pub message: Vec<bool>,
+    /// Метрики путей: `metrics[t][s]` - лучшее расстояние до состояния `s` на шаге `t`
+    pub metrics: Vec<Vec<Option<usize>>>,
+    /// Выжившие ветви: `survivors[t][s]` - предыдущее состояние и входной бит
+    pub survivors: Vec<Vec<Option<(usize, bool)>>>,
+    /// Состояния выжившего пути, начиная с нулевого
+    pub path: Vec<usize>,
+    /// Метрика выжившего пути в нулевом конечном состоянии
+    pub distance: usize,
+}
+
+/// Сверточный код со скоростью 1/r, завершаемый K - 1 нулевыми битами
+///
+/// Старший разряд порождающего многочлена соответствует текущему входу,
+/// состояние - предыдущие K - 1 входов (последний вход в старшем разряде)
+#[derive(Clone)]
+pub struct ConvolutionalCode {
+    pub constraint_length: usize,
+    pub generators: Vec<usize>,
+    /// Свободное расстояние `d_free`
+    pub free_distance: usize,
+}
+
+impl ConvolutionalCode {
+    pub const MIN_K: usize = 2;
+    pub const MAX_K: usize = 7;
+
+    pub fn new(constraint_length: usize, generators: Vec<usize>) -> Result<Self, String> {
+        if !(Self::MIN_K..=Self::MAX_K).contains(&constraint_length) {
+            return Err(format!(
+                "K должно быть от {} до {}",
+                Self::MIN_K,
+                Self::MAX_K
+            ));
+        }
+        if generators.len() < 2 {
+            return Err("нужно не меньше двух порождающих многочленов".to_string());
+        }
+        let limit = 1 << constraint_length;
+        if let Some(g) = generators.iter().find(|&&g| g == 0 || g >= limit) {
+            return Err(format!(
+                "многочлен {g:o} должен быть ненулевым и иметь степень меньше K = {constraint_length}"
+            ));
+        }
+        let mut code = Self {
+            constraint_length,
+            generators,
+            free_distance: 0,
+        };
+        code.free_distance = code.compute_free_distance();
+        Ok(code)
+    }
+
+    /// Число выходных битов на один входной
+    #[must_use]
+    pub const fn rate_denominator(&self) -> usize {
+        self.generators.len()
+    }
+
+    /// Число состояний решетки `2^(K-1)`
+    #[must_use]
+    pub const fn states(&self) -> usize {
+        1 << (self.constraint_length - 1)
+    }
+
+    /// Длина закодированной последовательности для сообщения из `k` битов
+    #[must_use]
+    pub const fn encoded_length(&self, k: usize) -> usize {
+        (k + self.constraint_length - 1) * self.rate_denominator()
+    }
+
+    /// Переход из состояния по входному биту: выходные биты и новое состояние
+    fn step(&self, state: usize, input: bool) -> (Vec<bool>, usize) {
+        let register = (usize::from(input) << (self.constraint_length - 1)) | state;
+        let output = self
+            .generators
+            .iter()
+            .map(|g| (register & g).count_ones() % 2 == 1)
+            .collect();
+        (output, register >> 1)
+    }
+
+    /// Кодирование с завершением решетки в нулевом состоянии
+    #[must_use]
+    pub fn encode(&self, message: &[bool]) -> Vec<bool> {
+        let tail = std::iter::repeat_n(false, self.constraint_length - 1);
+        let mut state = 0;
+        let mut encoded = Vec::with_capacity(self.encoded_length(message.len()));
+        for input in message.iter().copied().chain(tail) {
+            let (output, next) = self.step(state, input);
+            encoded.extend(output);
+            state = next;
+        }
+        encoded
+    }
+
+    /// Декодирование Витерби с жесткими решениями (метрика - расстояние Хэмминга)
+    #[must_use]
+    pub fn decode(&self, received: &[bool]) -> ViterbiDecoding {
+        let r = self.rate_denominator();
+        let steps = received.len() / r;
+        let mut metrics = vec![vec![None; self.states()]; steps + 1];
+        let mut survivors = vec![vec![None; self.states()]; steps + 1];
+        metrics[0][0] = Some(0);
+
+        for (t, symbol) in received.chunks_exact(r).enumerate() {
+            for state in 0..self.states() {
+                let Some(metric) = metrics[t][state] else {
+                    continue;
+                };
+                for input in [false, true] {
+                    let (output, next) = self.step(state, input);
+                    let branch = output.iter().zip(symbol).filter(|(a, b)| a != b).count();
+                    let candidate = metric + branch;
+                    if metrics[t + 1][next].is_none_or(|best| candidate < best) {
+                        metrics[t + 1][next] = Some(candidate);
+                        survivors[t + 1][next] = Some((state, input));
+                    }
+                }
+            }
+        }
+
+        // Трассировка назад из нулевого состояния (решетка завершена хвостом)
+        let mut path = vec![0; steps + 1];
+        let mut inputs = vec![false; steps];
+        for t in (1..=steps).rev() {
+            if let Some((previous, input)) = survivors[t][path[t]] {
+                path[t - 1] = previous;
+                inputs[t - 1] = input;
+            }
+        }
+        inputs.truncate(steps.saturating_sub(self.constraint_length - 1));
+
+        ViterbiDecoding {
+            message: inputs,
+            distance: metrics[steps][0].unwrap_or(0),
+            metrics,
+            survivors,
+            path,
+        }
+    }
+
+    /// Свободное расстояние: минимальный вес пути, вышедшего из нулевого состояния
+    /// и вернувшегося в него
+    fn compute_free_distance(&self) -> usize {
+        let weight = |state, input| {
+            let (output, next): (Vec<bool>, usize) = self.step(state, input);
+            (output.iter().filter(|&&bit| bit).count(), next)
+        };
+        let (start_weight, start) = weight(0, true);
+        let mut distance = vec![usize::MAX; self.states()];
+        let mut visited = vec![false; self.states()];
+        distance[start] = start_weight;
+        // Алгоритм Дейкстры по графу состояний без ребра 0 → 0
+        while let Some(state) = (0..self.states())
+            .filter(|&s| !visited[s] && distance[s] != usize::MAX)
+            .min_by_key(|&s| distance[s])
+        {
+            if state == 0 {
+                break;
+            }
+            visited[state] = true;
+            for input in [false, true] {
+                let (w, next) = weight(state, input);
+                distance[next] = distance[next].min(distance[state] + w);
+            }
+        }
+        distance[0]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn code(constraint_length: usize, generators: &str) -> ConvolutionalCode {
+        ConvolutionalParameters {
+            constraint_length,
+            generators: generators.to_string(),
+        }
+        .build()
+        .unwrap()
+    }
+
+    fn message(k: usize) -> Vec<bool> {
+        (0..k).map(|i| (i * 7 + 3) % 5 < 2).collect()
+    }
+
+    #[test]
+    fn known_free_distances() {
+        assert_eq!(code(3, "7, 5").free_distance, 5);
+        assert_eq!(code(7, "171, 133").free_distance, 10);
+        assert_eq!(code(3, "7, 7, 5").free_distance, 8);
+        assert_eq!(code(4, "15, 17").free_distance, 6);
+    }
+
+    #[test]
+    fn rejects_invalid_parameters() {
+        assert!(ConvolutionalCode::new(3, vec![7]).is_err());
+        assert!(ConvolutionalCode::new(1, vec![1, 1]).is_err());
+        assert!(ConvolutionalCode::new(3, vec![7, 0]).is_err());
+        assert!(ConvolutionalCode::new(3, vec![7, 0o17]).is_err());
+        assert!(
+            ConvolutionalParameters {
+                constraint_length: 3,
+                generators: "7, 9".to_string(),
+            }
+            .build()
+            .is_err()
+        );
+    }
+
+    #[test]
+    fn encodes_known_sequence() {
+        // 1011 + хвост 00 кодом (7, 5): 11 10 00 01 01 11
+        let encoded = code(3, "7, 5").encode(&[true, false, true, true]);
+        let expected: Vec<bool> = "111000010111".chars().map(|c| c == '1').collect();
+        assert_eq!(encoded, expected);
+    }
+
+    #[test]
+    fn round_trip_without_errors() {
+        for code in [code(3, "7, 5"), code(7, "171, 133"), code(3, "7, 7, 5")] {
+            let message = message(20);
+            let encoded = code.encode(&message);
+            assert_eq!(encoded.len(), code.encoded_length(20));
+            let decoding = code.decode(&encoded);
+            assert_eq!(decoding.message, message);
+            assert_eq!(decoding.distance, 0);
+        }
+    }
+
+    #[test]
+    fn corrects_every_single_and_spaced_double_error() {
+        for code in [code(3, "7, 5"), code(4, "15, 17"), code(7, "171, 133")] {
+            let message = message(16);
+            let encoded = code.encode(&message);
+            for i in 0..encoded.len() {
+                let mut received = encoded.clone();
+                received[i] = !received[i];
+                let decoding = code.decode(&received);
+                assert_eq!(decoding.message, message, "ошибка в {i}");
+                assert_eq!(decoding.distance, 1);
+                // Ошибки, разнесенные дальше длины ограничения, исправляются независимо
+                let j = i + 4 * code.constraint_length * code.rate_denominator();
+                if j < encoded.len() {
+                    received[j] = !received[j];
+                    assert_eq!(code.decode(&received).message, message, "ошибки в {i}, {j}");
+                }
+            }
+        }
+    }
+}
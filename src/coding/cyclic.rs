@@ -0,0 +1,480 @@
+use crate::coding::poly::{
+    degree, divides, from_mask, is_irreducible, is_primitive, period, poly_mod, trim,
+    x_pow_n_plus_one,
+};
+use entropy_fx::coding::cyclic::{CyclicErrorInfo, create_cyclic_code, encode_cyclic};
+use serde::{Deserialize, Serialize};
+
+/// Двоичный циклический (n, k)-код с порождающим многочленом g(x) степени `p = n - k`
+///
+/// Разряд `i` кодового слова - коэффициент при `x^(n-1-i)`: сначала идут
+/// информационные разряды, затем проверочные
+#[derive(Clone, Serialize, Deserialize)]
+pub struct CyclicCode {
+    pub k: usize,
+    pub n: usize,
+    pub p: usize,
+    /// Порождающий многочлен (коэффициенты по возрастанию степени)
+    pub generator: Vec<bool>,
+    /// Синдромы одиночных ошибок и их позиции (с 0)
+    pub syndrome_table: Vec<(Vec<bool>, usize)>,
+}
+
+impl CyclicCode {
+    /// Код с заданным порождающим многочленом
+    ///
+    /// Многочлен должен иметь степень `n - k` и ненулевой свободный член
+    pub fn new(k: usize, n: usize, generator: Vec<bool>) -> Result<Self, String> {
+        if k == 0 || k >= n {
+            return Err(format!("некорректные параметры кода: n = {n}, k = {k}"));
+        }
+        let p = n - k;
+        match degree(&generator) {
+            Some(d) if d == p => {}
+            Some(d) => return Err(format!("степень g(x) равна {d}, а должна быть p = {p}")),
+            None => return Err("g(x) равен нулю".to_string()),
+        }
+        if !generator[0] {
+            return Err("свободный член g(x) должен быть равен 1".to_string());
+        }
+
+        let mut code = Self {
+            k,
+            n,
+            p,
+            generator,
+            syndrome_table: vec![],
+        };
+        code.syndrome_table = (0..n)
+            .map(|position| {
+                let mut error = vec![false; n];
+                error[position] = true;
+                (code.syndrome(&error), position)
+            })
+            .collect();
+        Ok(code)
+    }
+
+    /// Код с выбранным пользователем g(x), который должен делить `x^n + 1`
+    ///
+    /// Укороченный код (g(x) не делит `x^n + 1`) строится, только если `allow_shortened`
+    pub fn with_generator(
+        k: usize,
+        n: usize,
+        generator: Vec<bool>,
+        allow_shortened: bool,
+    ) -> Result<Self, String> {
+        let code = Self::new(k, n, generator)?;
+        if !allow_shortened && !code.divides_x_n_plus_one() {
+            return Err(format!(
+                "g(x) не делит x^{n} + 1; чтобы использовать его, разрешите укороченный код"
+            ));
+        }
+        Ok(code)
+    }
+
+    /// Код с порождающим многочленом по умолчанию - [`Self::legacy_generator`]
+    #[must_use]
+    pub fn with_default_generator(k: usize, n: usize) -> Option<Self> {
+        Self::new(k, n, Self::legacy_generator(k)).ok()
+    }
+
+    /// Порождающий многочлен кода `entropy_fx::coding::cyclic::create_cyclic_code(k)`;
+    /// выбор g(x) в интерфейсе только переопределяет его
+    ///
+    /// Систематическое кодовое слово сообщения `m(x) = 1` совпадает с g(x)
+    #[must_use]
+    pub fn legacy_generator(k: usize) -> Vec<bool> {
+        let mut message = vec![false; k];
+        if let Some(last) = message.last_mut() {
+            *last = true;
+        }
+        let codeword = encode_cyclic(&message, &create_cyclic_code(k));
+        trim(Self::to_polynomial(&codeword))
+    }
+
+    /// Слово как многочлен (коэффициенты по возрастанию степени)
+    fn to_polynomial(word: &[bool]) -> Vec<bool> {
+        word.iter().rev().copied().collect()
+    }
+
+    /// Систематическое кодирование: `c(x) = m(x) x^p + (m(x) x^p mod g(x))`
+    #[must_use]
+    pub fn encode(&self, message: &[bool]) -> Vec<bool> {
+        let mut codeword = message.to_vec();
+        codeword.resize(self.n, false);
+        let remainder = poly_mod(&Self::to_polynomial(&codeword), &self.generator);
+        for (bit, &r) in codeword[self.k..].iter_mut().zip(remainder.iter().rev()) {
+            *bit = r;
+        }
+        codeword
+    }
+
+    /// Синдром `s(x) = r(x) mod g(x)`, разряды от старшего к младшему
+    #[must_use]
+    pub fn syndrome(&self, received: &[bool]) -> Vec<bool> {
+        poly_mod(&Self::to_polynomial(received), &self.generator)
+            .into_iter()
+            .rev()
+            .collect()
+    }
+
+    /// Исправление одиночной ошибки по таблице синдромов
+    ///
+    /// Позиция в `CyclicErrorInfo::SingleError` нумеруется с 1
+    #[must_use]
+    pub fn decode(&self, received: &[bool]) -> (Vec<bool>, CyclicErrorInfo) {
+        let syndrome = self.syndrome(received);
+        let mut corrected = received.to_vec();
+        if !syndrome.iter().any(|&bit| bit) {
+            return (corrected, CyclicErrorInfo::NoError);
+        }
+        match self.syndrome_table.iter().find(|(s, _)| *s == syndrome) {
+            Some(&(_, position)) => {
+                corrected[position] = !corrected[position];
+                (corrected, CyclicErrorInfo::SingleError(position + 1))
+            }
+            None => (corrected, CyclicErrorInfo::Uncorrectable),
+        }
+    }
+
+    /// Декодирование выбранным способом
+    #[must_use]
+    pub fn decode_with(
+        &self,
+        received: &[bool],
+        decoder: CyclicDecoder,
+    ) -> (Vec<bool>, CyclicErrorInfo) {
+        match decoder {
+            CyclicDecoder::Table => self.decode(received),
+            CyclicDecoder::Meggitt => {
+                let decoding = self.decode_meggitt(received);
+                (decoding.corrected, decoding.info)
+            }
+            CyclicDecoder::ErrorTrapping => {
+                let decoding = self.decode_error_trapping(received);
+                (decoding.corrected, decoding.info)
+            }
+        }
+    }
+
+    /// Синдром сдвинутого слова: `x s(x) mod g(x)` (коэффициенты по возрастанию степени)
+    fn shift_syndrome(&self, syndrome: &mut [bool]) {
+        let carry = syndrome.last().copied().unwrap_or(false);
+        syndrome.rotate_right(1);
+        syndrome[0] = false;
+        if carry {
+            for (s, &g) in syndrome.iter_mut().zip(&self.generator) {
+                *s ^= g;
+            }
+        }
+    }
+
+    /// Декодер Меггитта
+    ///
+    /// На сдвиге j в регистре синдром слова `x^j r(x)`. Если он равен синдрому
+    /// ошибки в разряде `x^(n-1)`, ошибочен разряд j исходного слова: он инвертируется,
+    /// а из синдрома вычитается синдром этой ошибки
+    #[must_use]
+    pub fn decode_meggitt(&self, received: &[bool]) -> ShiftDecoding {
+        let mut highest_error = vec![false; self.n];
+        highest_error[0] = true;
+        let pattern = poly_mod(&Self::to_polynomial(&highest_error), &self.generator);
+
+        let mut syndrome = poly_mod(&Self::to_polynomial(received), &self.generator);
+        let mut corrected = received.to_vec();
+        let mut flipped = vec![];
+        let mut trace = vec![];
+        for (shift, bit) in corrected.iter_mut().enumerate() {
+            if !syndrome.iter().any(|&bit| bit) {
+                break;
+            }
+            let mut step = ShiftStep {
+                shift,
+                syndrome: syndrome.iter().rev().copied().collect(),
+                corrected: vec![],
+            };
+            if syndrome == pattern {
+                *bit = !*bit;
+                flipped.push(shift);
+                step.corrected.push(shift);
+                for (s, &e) in syndrome.iter_mut().zip(&pattern) {
+                    *s ^= e;
+                }
+            }
+            trace.push(step);
+            self.shift_syndrome(&mut syndrome);
+        }
+
+        // Ненулевой остаток синдрома или несколько исправлений - ошибка не одиночная
+        let info = match flipped[..] {
+            [] if trace.is_empty() => CyclicErrorInfo::NoError,
+            [position] if !syndrome.iter().any(|&bit| bit) => {
+                CyclicErrorInfo::SingleError(position + 1)
+            }
+            _ => {
+                corrected = received.to_vec();
+                CyclicErrorInfo::Uncorrectable
+            }
+        };
+        ShiftDecoding {
+            corrected,
+            info,
+            trace,
+        }
+    }
+
+    /// Декодер с вылавливанием ошибок
+    ///
+    /// Если вес синдрома `x^j r(x) mod g(x)` равен 1, ошибка сдвинута в проверочные
+    /// разряды и равна самому синдрому: `e(x) = x^(N-j) s_j(x) mod (x^N + 1)`, где N - период g(x)
+    #[must_use]
+    pub fn decode_error_trapping(&self, received: &[bool]) -> ShiftDecoding {
+        let length = self.generator_period().unwrap_or(self.n).max(self.n);
+        let mut syndrome = poly_mod(&Self::to_polynomial(received), &self.generator);
+        let mut trace = vec![];
+        let mut corrected = received.to_vec();
+
+        if !syndrome.iter().any(|&bit| bit) {
+            return ShiftDecoding {
+                corrected,
+                info: CyclicErrorInfo::NoError,
+                trace,
+            };
+        }
+        for shift in 0..length {
+            let mut step = ShiftStep {
+                shift,
+                syndrome: syndrome.iter().rev().copied().collect(),
+                corrected: vec![],
+            };
+            // Вес синдрома 1: ошибка сдвинута в проверочный разряд x^d. Ее степень
+            // в исходном слове за пределами n означает, что ошибка не одиночная
+            let trapped = syndrome
+                .iter()
+                .position(|&bit| bit)
+                .filter(|_| syndrome.iter().filter(|&&bit| bit).count() == 1)
+                .map(|d| (d + length - shift) % length)
+                .filter(|&power| power < self.n);
+            if let Some(power) = trapped {
+                let position = self.n - 1 - power;
+                corrected[position] = !corrected[position];
+                step.corrected.push(position);
+                trace.push(step);
+                return ShiftDecoding {
+                    corrected,
+                    info: CyclicErrorInfo::SingleError(position + 1),
+                    trace,
+                };
+            }
+            trace.push(step);
+            self.shift_syndrome(&mut syndrome);
+        }
+
+        ShiftDecoding {
+            corrected,
+            info: CyclicErrorInfo::Uncorrectable,
+            trace,
+        }
+    }
+
+    /// Делит ли g(x) многочлен `x^n + 1` (иначе код - укороченный)
+    #[must_use]
+    pub fn divides_x_n_plus_one(&self) -> bool {
+        divides(&self.generator, &x_pow_n_plus_one(self.n))
+    }
+
+    /// Период g(x): длина циклического кода, укорочением которого получен данный
+    #[must_use]
+    pub fn generator_period(&self) -> Option<usize> {
+        period(&self.generator, 1 << self.p.min(20))
+    }
+
+    /// Все ли синдромы одиночных ошибок различны и ненулевые
+    ///
+    /// `x^i = x^j mod g(x)` при `i > j` тогда и только тогда, когда период g(x) не больше `i - j`
+    #[must_use]
+    pub fn corrects_single_errors(&self) -> bool {
+        period(&self.generator, self.n - 1).is_none()
+    }
+}
+
+/// Способ декодирования циклического кода
+#[derive(PartialEq, Eq, Clone, Copy, Debug, Default, Serialize, Deserialize)]
+pub enum CyclicDecoder {
+    /// Поиск синдрома в таблице синдромов одиночных ошибок
+    #[default]
+    Table,
+    /// Декодер Меггитта: синдром сдвигается, пока не совпадет с синдромом ошибки в старшем разряде
+    Meggitt,
+    /// Вылавливание ошибок: синдром сдвигается, пока его вес не станет равен 1
+    ErrorTrapping,
+}
+
+impl CyclicDecoder {
+    pub const ALL: [Self; 3] = [Self::Table, Self::Meggitt, Self::ErrorTrapping];
+
+    #[must_use]
+    pub const fn label(self) -> &'static str {
+        match self {
+            Self::Table => "Таблица синдромов",
+            Self::Meggitt => "Декодер Меггитта",
+            Self::ErrorTrapping => "Вылавливание ошибок",
+        }
+    }
+}
+
+/// Один сдвиг синдромного регистра
+#[derive(Clone, Debug)]
+pub struct ShiftStep {
+    /// Номер сдвига j: в регистре `x^j r(x) mod g(x)`
+    pub shift: usize,
+    /// Синдром до исправления, разряды от старшего к младшему
+    pub syndrome: Vec<bool>,
+    /// Разряды слова (с 0), исправленные на этом сдвиге
+    pub corrected: Vec<usize>,
+}
+
+/// Результат декодирования сдвигами
+#[derive(Clone)]
+pub struct ShiftDecoding {
+    pub corrected: Vec<bool>,
+    pub info: CyclicErrorInfo,
+    pub trace: Vec<ShiftStep>,
+}
+
+/// Наибольшая степень, для которой перебираются многочлены-кандидаты
+pub const MAX_CANDIDATE_DEGREE: usize = 14;
+
+/// Многочлен-кандидат в порождающие и его свойства
+#[derive(Clone)]
+pub struct GeneratorCandidate {
+    pub polynomial: Vec<bool>,
+    pub irreducible: bool,
+    pub primitive: bool,
+    pub period: Option<usize>,
+    /// Делит ли многочлен `x^n + 1`
+    pub divides: bool,
+}
+
+/// Многочлены степени `p` со свободным членом 1, пригодные в качестве порождающих
+/// для кода длины `n`: неприводимые и делители `x^n + 1`
+#[must_use]
+pub fn generator_candidates(p: usize, n: usize) -> Vec<GeneratorCandidate> {
+    if p == 0 || p > MAX_CANDIDATE_DEGREE {
+        return vec![];
+    }
+    let x_n_plus_one = x_pow_n_plus_one(n);
+    (0..1u64 << (p - 1))
+        .map(|middle| from_mask((1 << p) | (middle << 1) | 1))
+        .filter_map(|polynomial| {
+            let irreducible = is_irreducible(&polynomial);
+            let divides = divides(&polynomial, &x_n_plus_one);
+            (irreducible || divides).then(|| GeneratorCandidate {
+                irreducible,
+                primitive: irreducible && is_primitive(&polynomial),
+                period: period(&polynomial, 1 << p),
+                divides,
+                polynomial,
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// (7, 4) и (15, 11) коды Хэмминга и укороченный (10, 6) код с g(x) = x^4 + x + 1
+    fn codes() -> Vec<CyclicCode> {
+        vec![
+            CyclicCode::new(4, 7, vec![true, true, false, true]).unwrap(),
+            CyclicCode::new(11, 15, vec![true, true, false, false, true]).unwrap(),
+            CyclicCode::new(6, 10, vec![true, true, false, false, true]).unwrap(),
+        ]
+    }
+
+    fn message(k: usize, value: usize) -> Vec<bool> {
+        (0..k).map(|i| (value >> i) & 1 == 1).collect()
+    }
+
+    #[test]
+    fn codewords_have_zero_syndrome() {
+        for code in codes() {
+            for value in 0..1 << code.k.min(8) {
+                let codeword = code.encode(&message(code.k, value));
+                assert_eq!(&codeword[..code.k], &message(code.k, value)[..]);
+                assert!(code.syndrome(&codeword).iter().all(|&bit| !bit));
+            }
+        }
+    }
+
+    #[test]
+    fn every_decoder_corrects_every_single_error() {
+        for code in codes() {
+            let codeword = code.encode(&message(code.k, 0b1011));
+            for position in 0..code.n {
+                let mut received = codeword.clone();
+                received[position] = !received[position];
+                for decoder in CyclicDecoder::ALL {
+                    let (corrected, info) = code.decode_with(&received, decoder);
+                    assert_eq!(
+                        corrected, codeword,
+                        "{decoder:?}, n = {}, position {position}",
+                        code.n
+                    );
+                    assert!(
+                        matches!(info, CyclicErrorInfo::SingleError(p) if p == position + 1),
+                        "{decoder:?}, n = {}, position {position}",
+                        code.n
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn decoders_leave_codewords_unchanged() {
+        for code in codes() {
+            let codeword = code.encode(&message(code.k, 0b0110));
+            for decoder in CyclicDecoder::ALL {
+                let (corrected, info) = code.decode_with(&codeword, decoder);
+                assert_eq!(corrected, codeword);
+                assert!(matches!(info, CyclicErrorInfo::NoError));
+            }
+        }
+    }
+
+    #[test]
+    fn meggitt_trace_flips_exactly_the_error() {
+        let code = &codes()[1];
+        let codeword = code.encode(&message(code.k, 0b101));
+        let mut received = codeword;
+        received[5] = !received[5];
+        let decoding = code.decode_meggitt(&received);
+        let flipped: Vec<usize> = decoding
+            .trace
+            .iter()
+            .flat_map(|step| step.corrected.iter().copied())
+            .collect();
+        assert_eq!(flipped, vec![5]);
+    }
+
+    #[test]
+    fn shortened_code_properties() {
+        let codes = codes();
+        assert!(codes[0].divides_x_n_plus_one());
+        assert!(!codes[2].divides_x_n_plus_one());
+        assert_eq!(codes[2].generator_period(), Some(15));
+        assert!(codes.iter().all(CyclicCode::corrects_single_errors));
+    }
+
+    #[test]
+    fn shortened_code_requires_opt_in() {
+        let divisor = vec![true, true, false, true];
+        let non_divisor = vec![true, true, true, true];
+        assert!(CyclicCode::with_generator(4, 7, divisor, false).is_ok());
+        assert!(CyclicCode::with_generator(4, 7, non_divisor.clone(), false).is_err());
+        assert!(CyclicCode::with_generator(4, 7, non_divisor, true).is_ok());
+    }
+}
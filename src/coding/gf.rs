@@ -0,0 +1,252 @@
+use crate::coding::poly::first_primitive;
+
+/// Поле Галуа GF(2^m), построенное по примитивному многочлену степени m
+///
+/// Элементы - целые числа `0..2^m`, биты которых - коэффициенты многочлена от α
+#[derive(Clone)]
+pub struct GaloisField {
+    pub m: usize,
+    /// Примитивный многочлен (коэффициенты по возрастанию степени)
+    pub primitive: Vec<bool>,
+    /// `exp[i] = α^i`, таблица удвоена, чтобы не брать остаток при умножении
+    exp: Vec<usize>,
+    /// `log[α^i] = i`, `log[0]` не используется
+    log: Vec<usize>,
+}
+
+impl GaloisField {
+    /// Наибольшая поддерживаемая степень расширения
+    pub const MAX_M: usize = 16;
+
+    #[must_use]
+    pub fn new(m: usize) -> Option<Self> {
+        if !(2..=Self::MAX_M).contains(&m) {
+            return None;
+        }
+        let primitive = first_primitive(m)?;
+        let modulus = primitive
+            .iter()
+            .enumerate()
+            .fold(0usize, |value, (i, &c)| value | (usize::from(c) << i));
+        let order = (1 << m) - 1;
+        let mut exp = vec![0; 2 * order];
+        let mut log = vec![0; order + 1];
+        let mut value = 1;
+        for i in 0..order {
+            exp[i] = value;
+            exp[i + order] = value;
+            log[value] = i;
+            value <<= 1;
+            if value >> m == 1 {
+                value ^= modulus;
+            }
+        }
+        Some(Self {
+            m,
+            primitive,
+            exp,
+            log,
+        })
+    }
+
+    /// Число ненулевых элементов `2^m - 1`
+    #[must_use]
+    pub const fn order(&self) -> usize {
+        (1 << self.m) - 1
+    }
+
+    /// `α^power` для любой (в том числе отрицательной) степени
+    #[must_use]
+    pub fn alpha_pow(&self, power: i64) -> usize {
+        let order = i64::try_from(self.order()).unwrap_or(i64::MAX);
+        // rem_euclid дает значение в 0..order, поэтому приведение без потерь
+        #[allow(clippy::cast_sign_loss, clippy::cast_possible_truncation)]
+        let index = power.rem_euclid(order) as usize;
+        self.exp[index]
+    }
+
+    /// Логарифм ненулевого элемента по основанию α
+    #[must_use]
+    pub fn log(&self, x: usize) -> Option<usize> {
+        (x != 0).then(|| self.log[x])
+    }
+
+    #[must_use]
+    pub fn mul(&self, a: usize, b: usize) -> usize {
+        if a == 0 || b == 0 {
+            0
+        } else {
+            self.exp[self.log[a] + self.log[b]]
+        }
+    }
+
+    /// Деление на ненулевой элемент
+    #[must_use]
+    pub fn div(&self, a: usize, b: usize) -> usize {
+        assert!(b != 0, "деление на ноль в GF(2^m)");
+        if a == 0 {
+            0
+        } else {
+            self.exp[self.log[a] + self.order() - self.log[b]]
+        }
+    }
+
+    /// Значение многочлена (коэффициенты по возрастанию степени) в точке x по схеме Горнера
+    #[must_use]
+    pub fn eval(&self, poly: &[usize], x: usize) -> usize {
+        poly.iter()
+            .rev()
+            .fold(0, |value, &c| self.mul(value, x) ^ c)
+    }
+
+    /// Произведение многочленов над GF(2^m)
+    #[must_use]
+    pub fn poly_mul(&self, a: &[usize], b: &[usize]) -> Vec<usize> {
+        let mut product = vec![0; (a.len() + b.len()).saturating_sub(1)];
+        for (i, &x) in a.iter().enumerate() {
+            for (j, &y) in b.iter().enumerate() {
+                product[i + j] ^= self.mul(x, y);
+            }
+        }
+        product
+    }
+
+    /// Запись элемента степенью α: `0`, `1`, `α^5`
+    #[must_use]
+    pub fn format(&self, x: usize) -> String {
+        match self.log(x) {
+            None => "0".to_string(),
+            Some(0) => "1".to_string(),
+            Some(1) => "α".to_string(),
+            Some(power) => format!("α^{power}"),
+        }
+    }
+
+    /// Запись многочлена над полем: `1 + α^3 x + α x^2`
+    #[must_use]
+    pub fn format_poly(&self, poly: &[usize]) -> String {
+        let terms: Vec<String> = poly
+            .iter()
+            .enumerate()
+            .filter(|(_, c)| **c != 0)
+            .map(|(i, &c)| {
+                let coefficient = if c == 1 && i > 0 {
+                    String::new()
+                } else {
+                    self.format(c)
+                };
+                match i {
+                    0 => coefficient,
+                    1 => format!("{coefficient}x"),
+                    _ => format!("{coefficient}x^{i}"),
+                }
+            })
+            .collect();
+        if terms.is_empty() {
+            "0".to_string()
+        } else {
+            terms.join(" + ")
+        }
+    }
+}
+
+/// Алгоритм Берлекэмпа–Мэсси: многочлен локаторов ошибок Λ(x) по синдромам `S_1, S_2, ...`
+///
+/// Возвращает коэффициенты Λ по возрастанию степени (`Λ_0 = 1`) и длину регистра L
+#[must_use]
+pub fn berlekamp_massey(field: &GaloisField, syndromes: &[usize]) -> (Vec<usize>, usize) {
+    let mut locator = vec![1];
+    let mut previous = vec![1];
+    let mut length = 0;
+    let mut shift = 1;
+    let mut previous_discrepancy = 1;
+
+    for step in 0..syndromes.len() {
+        let discrepancy = (1..=length)
+            .filter(|&i| i < locator.len())
+            .fold(syndromes[step], |d, i| {
+                d ^ field.mul(locator[i], syndromes[step - i])
+            });
+        if discrepancy == 0 {
+            shift += 1;
+            continue;
+        }
+
+        // Λ(x) - d/b x^shift B(x)
+        let factor = field.div(discrepancy, previous_discrepancy);
+        let mut updated = locator.clone();
+        updated.resize(updated.len().max(previous.len() + shift), 0);
+        for (i, &b) in previous.iter().enumerate() {
+            updated[i + shift] ^= field.mul(factor, b);
+        }
+
+        if 2 * length <= step {
+            previous = std::mem::replace(&mut locator, updated);
+            length = step + 1 - length;
+            previous_discrepancy = discrepancy;
+            shift = 1;
+        } else {
+            locator = updated;
+            shift += 1;
+        }
+    }
+
+    while locator.len() > 1 && locator.last() == Some(&0) {
+        locator.pop();
+    }
+    (locator, length)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn field_arithmetic() {
+        let field = GaloisField::new(4).unwrap();
+        assert_eq!(field.order(), 15);
+        assert_eq!(field.alpha_pow(15), 1);
+        assert_eq!(field.alpha_pow(-1), field.alpha_pow(14));
+        for a in 1..16 {
+            assert_eq!(
+                field.alpha_pow(i64::try_from(field.log(a).unwrap()).unwrap()),
+                a
+            );
+            assert_eq!(field.mul(a, field.div(1, a)), 1);
+            for b in 1..16 {
+                assert_eq!(field.div(field.mul(a, b), b), a);
+            }
+        }
+        assert_eq!(field.mul(0, 7), 0);
+        assert_eq!(field.log(0), None);
+    }
+
+    #[test]
+    fn polynomial_evaluation() {
+        let field = GaloisField::new(3).unwrap();
+        // (x + α)(x + α^2) обращается в ноль в α и α^2
+        let poly = field.poly_mul(&[field.alpha_pow(1), 1], &[field.alpha_pow(2), 1]);
+        assert_eq!(field.eval(&poly, field.alpha_pow(1)), 0);
+        assert_eq!(field.eval(&poly, field.alpha_pow(2)), 0);
+        assert_ne!(field.eval(&poly, 1), 0);
+    }
+
+    #[test]
+    fn berlekamp_massey_finds_error_locators() {
+        let field = GaloisField::new(4).unwrap();
+        let locators = [field.alpha_pow(3), field.alpha_pow(10)];
+        let syndromes: Vec<usize> = (1..=4)
+            .map(|j| {
+                locators.iter().fold(0, |sum, &x| {
+                    sum ^ field.alpha_pow(j * i64::try_from(field.log(x).unwrap()).unwrap())
+                })
+            })
+            .collect();
+        let (locator, length) = berlekamp_massey(&field, &syndromes);
+        assert_eq!(length, 2);
+        assert_eq!(locator.len(), 3);
+        for x in locators {
+            assert_eq!(field.eval(&locator, field.div(1, x)), 0);
+        }
+    }
+}
@@ -0,0 +1,194 @@
+use crate::coding::cyclic::CyclicCode;
+use crate::coding::linear::LinearCode;
+use crate::formatting::polynomial::{PolynomialNotation, parse_polynomial};
+use serde::{Deserialize, Serialize};
+
+/// Порождающий многочлен кода Голея `g(x) = x^11 + x^10 + x^6 + x^5 + x^4 + x^2 + 1`
+pub const GOLAY_GENERATOR_OCTAL: &str = "6165";
+
+/// Результат декодирования кода Голея
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub enum GolayErrorInfo {
+    NoError,
+    /// Исправлены разряды (с 1)
+    Corrected(Vec<usize>),
+    /// Синдром не соответствует ни одной ошибке веса не больше 3
+    Uncorrectable,
+}
+
+/// Код Голея (23, 12) или расширенный код Голея (24, 12)
+///
+/// Порождающая матрица `G = [I | P]` получена систематическим кодированием циклического
+/// кода с многочленом [`GOLAY_GENERATOR_OCTAL`]; в расширенном коде к каждой строке
+/// добавлен разряд общей проверки на четность
+#[derive(Clone)]
+pub struct GolayCode {
+    pub extended: bool,
+    pub linear: LinearCode,
+    /// Шаблон ошибки веса не больше 3 для каждого синдрома (номер синдрома - его двоичное значение)
+    syndrome_table: Vec<Option<Vec<usize>>>,
+}
+
+impl GolayCode {
+    pub const K: usize = 12;
+    /// Гарантированно исправляемое число ошибок
+    pub const T: usize = 3;
+
+    pub fn new(extended: bool) -> Result<Self, String> {
+        let generator = parse_polynomial(GOLAY_GENERATOR_OCTAL, PolynomialNotation::Octal)?;
+        let cyclic = CyclicCode::new(Self::K, 23, generator)?;
+        let parity: Vec<Vec<bool>> = (0..Self::K)
+            .map(|i| {
+                let mut message = vec![false; Self::K];
+                message[i] = true;
+                let mut row = cyclic.encode(&message)[Self::K..].to_vec();
+                if extended {
+                    // Вес строки G = 1 + вес P; дополняем до четного
+                    row.push(row.iter().filter(|&&bit| bit).count() % 2 == 0);
+                }
+                row
+            })
+            .collect();
+        let linear = LinearCode::from_parity_part(&parity)?;
+
+        let mut code = Self {
+            extended,
+            syndrome_table: vec![None; 1 << linear.p()],
+            linear,
+        };
+        code.build_syndrome_table();
+        Ok(code)
+    }
+
+    /// Заполнение таблицы синдромов всеми ошибками веса 0..=3
+    fn build_syndrome_table(&mut self) {
+        let n = self.linear.n;
+        let columns: Vec<usize> = (0..n)
+            .map(|j| syndrome_index(&self.linear.column(j)))
+            .collect();
+        self.syndrome_table[0] = Some(vec![]);
+        for a in 0..n {
+            self.syndrome_table[columns[a]] = Some(vec![a]);
+            for b in a + 1..n {
+                self.syndrome_table[columns[a] ^ columns[b]] = Some(vec![a, b]);
+                for c in b + 1..n {
+                    self.syndrome_table[columns[a] ^ columns[b] ^ columns[c]] = Some(vec![a, b, c]);
+                }
+            }
+        }
+    }
+
+    /// Число синдромов, которым соответствует исправляемая ошибка
+    #[must_use]
+    pub fn correctable_syndromes(&self) -> usize {
+        self.syndrome_table.iter().flatten().count()
+    }
+
+    /// Синдромное декодирование ошибок веса до 3
+    #[must_use]
+    pub fn decode(&self, received: &[bool]) -> (Vec<bool>, GolayErrorInfo) {
+        let mut corrected = received.to_vec();
+        let syndrome = syndrome_index(&self.linear.syndrome(received));
+        match self.syndrome_table[syndrome] {
+            Some(ref positions) if positions.is_empty() => (corrected, GolayErrorInfo::NoError),
+            Some(ref positions) => {
+                for &position in positions {
+                    corrected[position] = !corrected[position];
+                }
+                let positions = positions.iter().map(|p| p + 1).collect();
+                (corrected, GolayErrorInfo::Corrected(positions))
+            }
+            None => (corrected, GolayErrorInfo::Uncorrectable),
+        }
+    }
+}
+
+/// Синдром как двоичное число (первый разряд - старший)
+fn syndrome_index(syndrome: &[bool]) -> usize {
+    syndrome
+        .iter()
+        .fold(0, |index, &bit| (index << 1) | usize::from(bit))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn minimum_distance(code: &GolayCode) -> usize {
+        (1..1usize << GolayCode::K)
+            .map(|value| {
+                let message: Vec<bool> = (0..GolayCode::K).map(|i| value >> i & 1 == 1).collect();
+                code.linear
+                    .encode(&message)
+                    .iter()
+                    .filter(|&&bit| bit)
+                    .count()
+            })
+            .min()
+            .unwrap()
+    }
+
+    fn error_patterns(n: usize) -> Vec<Vec<usize>> {
+        let mut patterns = vec![vec![]];
+        for a in 0..n {
+            patterns.push(vec![a]);
+            for b in a + 1..n {
+                patterns.push(vec![a, b]);
+                for c in b + 1..n {
+                    patterns.push(vec![a, b, c]);
+                }
+            }
+        }
+        patterns
+    }
+
+    #[test]
+    fn minimum_distances() {
+        assert_eq!(minimum_distance(&GolayCode::new(false).unwrap()), 7);
+        assert_eq!(minimum_distance(&GolayCode::new(true).unwrap()), 8);
+    }
+
+    #[test]
+    fn perfect_code_uses_every_syndrome() {
+        let code = GolayCode::new(false).unwrap();
+        assert_eq!(code.linear.n, 23);
+        assert_eq!(code.correctable_syndromes(), 1 << 11);
+        assert_eq!(GolayCode::new(true).unwrap().correctable_syndromes(), 2325);
+    }
+
+    #[test]
+    fn corrects_every_error_up_to_weight_three() {
+        for extended in [false, true] {
+            let code = GolayCode::new(extended).unwrap();
+            let message: Vec<bool> = (0..GolayCode::K).map(|i| i % 3 != 1).collect();
+            let codeword = code.linear.encode(&message);
+            for positions in error_patterns(code.linear.n) {
+                let mut received = codeword.clone();
+                for &position in &positions {
+                    received[position] = !received[position];
+                }
+                let (corrected, info) = code.decode(&received);
+                assert_eq!(corrected, codeword, "ошибки в {positions:?}");
+                let expected: Vec<usize> = positions.iter().map(|p| p + 1).collect();
+                match info {
+                    GolayErrorInfo::NoError => assert!(positions.is_empty()),
+                    GolayErrorInfo::Corrected(found) => assert_eq!(found, expected),
+                    GolayErrorInfo::Uncorrectable => panic!("ошибки в {positions:?} не исправлены"),
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn extended_code_detects_four_errors() {
+        let code = GolayCode::new(true).unwrap();
+        let mut received = code.linear.encode(&[true; GolayCode::K]);
+        for position in [0, 5, 13, 23] {
+            received[position] = !received[position];
+        }
+        assert!(matches!(
+            code.decode(&received).1,
+            GolayErrorInfo::Uncorrectable
+        ));
+    }
+}
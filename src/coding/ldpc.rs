@@ -0,0 +1,627 @@
+use crate::coding::linear::LinearCode;
+use crate::utils::random::rng_from_seed;
+use entropy_fx::coding::systematic::BinaryMatrix;
+use rand::seq::SliceRandom;
+use serde::{Deserialize, Serialize};
+
+/// Ограничение сообщений «проверка → бит», чтобы `atanh` оставался конечным
+const MAX_LLR: f64 = 30.0;
+
+/// Алгоритм декодирования LDPC-кода
+#[derive(PartialEq, Eq, Clone, Copy, Debug, Default, Serialize, Deserialize)]
+pub enum LdpcDecoder {
+    /// Жесткие решения: инвертируются биты с наибольшим числом невыполненных проверок
+    BitFlipping,
+    /// Распространение доверия (сумма-произведение) по логарифмам отношения правдоподобия
+    #[default]
+    SumProduct,
+}
+
+impl LdpcDecoder {
+    pub const ALL: [Self; 2] = [Self::BitFlipping, Self::SumProduct];
+
+    #[must_use]
+    pub const fn label(self) -> &'static str {
+        match self {
+            Self::BitFlipping => "Инвертирование битов (bit-flipping)",
+            Self::SumProduct => "Сумма-произведение (belief propagation)",
+        }
+    }
+}
+
+/// Параметры LDPC-кода и декодера, задаваемые пользователем
+#[derive(Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct LdpcParameters {
+    /// Длина кода для случайной регулярной матрицы
+    pub n: usize,
+    /// Число единиц в столбце H
+    pub column_weight: usize,
+    /// Число единиц в строке H
+    pub row_weight: usize,
+    /// Seed построения случайной матрицы
+    pub seed: u64,
+    /// Проверочная матрица, загруженная из файла alist (`None` - случайная регулярная)
+    pub loaded: Option<BinaryMatrix>,
+    pub decoder: LdpcDecoder,
+    pub max_iterations: usize,
+    /// Вероятность ошибки в канале для начальных LLR декодера сумма-произведение
+    pub crossover_probability: f64,
+}
+
+impl Default for LdpcParameters {
+    fn default() -> Self {
+        Self {
+            n: 24,
+            column_weight: 3,
+            row_weight: 6,
+            seed: 1,
+            loaded: None,
+            decoder: LdpcDecoder::default(),
+            max_iterations: 20,
+            crossover_probability: 0.05,
+        }
+    }
+}
+
+impl LdpcParameters {
+    pub const MAX_N: usize = 1000;
+
+    pub fn build(&self) -> Result<LdpcCode, String> {
+        match self.loaded {
+            Some(ref matrix) => LdpcCode::from_parity_check(matrix.clone()),
+            None => LdpcCode::from_parity_check(gallager_matrix(
+                self.n,
+                self.column_weight,
+                self.row_weight,
+                self.seed,
+            )?),
+        }
+    }
+}
+
+/// Результат декодирования LDPC-кода
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub enum LdpcErrorInfo {
+    NoError,
+    /// Синдром обнулился за указанное число итераций
+    Converged(usize),
+    /// Синдром не обнулился за допустимое число итераций
+    NotConverged,
+}
+
+/// Итог декодирования, сохраняемый в результате эксперимента
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct LdpcReport {
+    pub info: LdpcErrorInfo,
+    pub decoder: LdpcDecoder,
+    /// Вес синдрома принятого слова и после каждой итерации
+    pub syndrome_weights: Vec<usize>,
+}
+
+/// Итог декодирования с весом синдрома после каждой итерации
+#[derive(Clone)]
+pub struct LdpcDecoding {
+    pub corrected: Vec<bool>,
+    pub info: LdpcErrorInfo,
+    /// Вес синдрома принятого слова и после каждой итерации
+    pub syndrome_weights: Vec<usize>,
+}
+
+/// LDPC-код, заданный разреженной проверочной матрицей H
+///
+/// Порождающая матрица строится приведением H к ступенчатому виду: информационными
+/// становятся разряды вне ведущих столбцов, k = n - rank H
+#[derive(Clone)]
+pub struct LdpcCode {
+    /// H и систематический кодер
+    pub linear: LinearCode,
+    /// Для каждой проверки - номера входящих в нее битов
+    checks: Vec<Vec<usize>>,
+    /// Для каждого бита - номера проверок, в которые он входит
+    variables: Vec<Vec<usize>>,
+}
+
+impl LdpcCode {
+    pub fn from_parity_check(parity_check: BinaryMatrix) -> Result<Self, String> {
+        let n = parity_check.first().map_or(0, Vec::len);
+        if n == 0 || parity_check.iter().any(|row| row.len() != n) {
+            return Err("матрица H пуста или имеет строки разной длины".to_string());
+        }
+        let generator = generator_from_parity_check(&parity_check);
+        if generator.is_empty() {
+            return Err("матрица H имеет полный ранг по столбцам: k = 0".to_string());
+        }
+        let checks: Vec<Vec<usize>> = parity_check
+            .iter()
+            .map(|row| (0..n).filter(|&j| row[j]).collect())
+            .collect();
+        let mut variables = vec![vec![]; n];
+        for (c, bits) in checks.iter().enumerate() {
+            for &v in bits {
+                variables[v].push(c);
+            }
+        }
+        Ok(Self {
+            linear: LinearCode {
+                k: generator.len(),
+                n,
+                generator,
+                parity_check,
+            },
+            checks,
+            variables,
+        })
+    }
+
+    /// Число проверок (строк H)
+    #[must_use]
+    pub const fn checks(&self) -> usize {
+        self.checks.len()
+    }
+
+    /// Число единиц в H
+    #[must_use]
+    pub fn ones(&self) -> usize {
+        self.checks.iter().map(Vec::len).sum()
+    }
+
+    fn syndrome_weight(&self, word: &[bool]) -> usize {
+        self.checks
+            .iter()
+            .filter(|bits| bits.iter().filter(|&&v| word[v]).count() % 2 == 1)
+            .count()
+    }
+
+    /// Декодирование с ограничением числа итераций
+    #[must_use]
+    pub fn decode(
+        &self,
+        received: &[bool],
+        decoder: LdpcDecoder,
+        max_iterations: usize,
+        crossover_probability: f64,
+    ) -> LdpcDecoding {
+        let mut decoding = match decoder {
+            LdpcDecoder::BitFlipping => self.bit_flipping(received, max_iterations),
+            LdpcDecoder::SumProduct => {
+                self.sum_product(received, max_iterations, crossover_probability)
+            }
+        };
+        let iterations = decoding.syndrome_weights.len() - 1;
+        decoding.info = match decoding.syndrome_weights.last() {
+            Some(0) if iterations == 0 => LdpcErrorInfo::NoError,
+            Some(0) => LdpcErrorInfo::Converged(iterations),
+            _ => {
+                decoding.corrected = received.to_vec();
+                LdpcErrorInfo::NotConverged
+            }
+        };
+        decoding
+    }
+
+    /// На каждой итерации инвертируются биты с наибольшим числом невыполненных проверок
+    fn bit_flipping(&self, received: &[bool], max_iterations: usize) -> LdpcDecoding {
+        let mut word = received.to_vec();
+        let mut syndrome_weights = vec![self.syndrome_weight(&word)];
+        while syndrome_weights.last() != Some(&0) && syndrome_weights.len() <= max_iterations {
+            let unsatisfied: Vec<usize> = self
+                .variables
+                .iter()
+                .map(|checks| {
+                    checks
+                        .iter()
+                        .filter(|&&c| self.checks[c].iter().filter(|&&v| word[v]).count() % 2 == 1)
+                        .count()
+                })
+                .collect();
+            let worst = unsatisfied.iter().copied().max().unwrap_or(0);
+            for (bit, &count) in word.iter_mut().zip(&unsatisfied) {
+                if count == worst {
+                    *bit = !*bit;
+                }
+            }
+            syndrome_weights.push(self.syndrome_weight(&word));
+        }
+        LdpcDecoding {
+            corrected: word,
+            info: LdpcErrorInfo::NoError,
+            syndrome_weights,
+        }
+    }
+
+    /// Декодер сумма-произведение в логарифмической области
+    ///
+    /// Начальные LLR двоичного симметричного канала: `±ln((1 - p) / p)`
+    fn sum_product(
+        &self,
+        received: &[bool],
+        max_iterations: usize,
+        crossover_probability: f64,
+    ) -> LdpcDecoding {
+        let p = crossover_probability.clamp(1e-6, 0.5 - 1e-6);
+        let magnitude = ((1.0 - p) / p).ln();
+        let channel: Vec<f64> = received
+            .iter()
+            .map(|&bit| if bit { -magnitude } else { magnitude })
+            .collect();
+        // Сообщения «проверка → бит» в порядке битов внутри каждой проверки
+        let mut check_messages: Vec<Vec<f64>> = self
+            .checks
+            .iter()
+            .map(|bits| vec![0.0; bits.len()])
+            .collect();
+
+        let mut word = received.to_vec();
+        let mut syndrome_weights = vec![self.syndrome_weight(&word)];
+        while syndrome_weights.last() != Some(&0) && syndrome_weights.len() <= max_iterations {
+            let mut totals = channel.clone();
+            for (bits, messages) in self.checks.iter().zip(&check_messages) {
+                for (&v, &message) in bits.iter().zip(messages) {
+                    totals[v] += message;
+                }
+            }
+            for (bits, messages) in self.checks.iter().zip(&mut check_messages) {
+                let halves: Vec<f64> = bits
+                    .iter()
+                    .zip(messages.iter())
+                    .map(|(&v, &message)| ((totals[v] - message) / 2.0).tanh())
+                    .collect();
+                for (i, message) in messages.iter_mut().enumerate() {
+                    let product: f64 = halves
+                        .iter()
+                        .enumerate()
+                        .filter(|&(j, _)| j != i)
+                        .map(|(_, &h)| h)
+                        .product();
+                    *message = (2.0 * product.atanh()).clamp(-MAX_LLR, MAX_LLR);
+                }
+            }
+
+            let mut posterior = channel.clone();
+            for (bits, messages) in self.checks.iter().zip(&check_messages) {
+                for (&v, &message) in bits.iter().zip(messages) {
+                    posterior[v] += message;
+                }
+            }
+            word = posterior.iter().map(|&llr| llr < 0.0).collect();
+            syndrome_weights.push(self.syndrome_weight(&word));
+        }
+        LdpcDecoding {
+            corrected: word,
+            info: LdpcErrorInfo::NoError,
+            syndrome_weights,
+        }
+    }
+}
+
+/// Порождающая матрица по проверочной: приведение H к ступенчатому виду над GF(2)
+///
+/// Строка `t` - кодовое слово с единицей в `t`-м информационном разряде
+/// (столбец без ведущего элемента) и значениями ведущих разрядов из приведенной H
+fn generator_from_parity_check(parity_check: &BinaryMatrix) -> BinaryMatrix {
+    let n = parity_check.first().map_or(0, Vec::len);
+    let mut reduced = parity_check.clone();
+    let mut pivots = vec![];
+    for column in 0..n {
+        let row = pivots.len();
+        let Some(pivot) = (row..reduced.len()).find(|&r| reduced[r][column]) else {
+            continue;
+        };
+        reduced.swap(row, pivot);
+        let pivot_row = reduced[row].clone();
+        for (r, other) in reduced.iter_mut().enumerate() {
+            if r != row && other[column] {
+                for (a, &b) in other.iter_mut().zip(&pivot_row) {
+                    *a ^= b;
+                }
+            }
+        }
+        pivots.push(column);
+    }
+
+    (0..n)
+        .filter(|column| !pivots.contains(column))
+        .map(|information| {
+            let mut word = vec![false; n];
+            word[information] = true;
+            for (row, &pivot) in pivots.iter().enumerate() {
+                word[pivot] = reduced[row][information];
+            }
+            word
+        })
+        .collect()
+}
+
+/// Случайная регулярная матрица Галлагера: `wc` полос по `n / wr` строк,
+/// первая полоса - подряд идущие блоки из `wr` единиц, остальные - ее перестановки столбцов
+pub fn gallager_matrix(n: usize, wc: usize, wr: usize, seed: u64) -> Result<BinaryMatrix, String> {
+    if wr < 2 || wc < 2 || wc >= wr {
+        return Err("нужны веса 2 ≤ wc < wr".to_string());
+    }
+    if n == 0 || !n.is_multiple_of(wr) || n > LdpcParameters::MAX_N {
+        return Err(format!(
+            "n должно быть кратно wr = {wr} и не больше {}",
+            LdpcParameters::MAX_N
+        ));
+    }
+    let band: BinaryMatrix = (0..n / wr)
+        .map(|i| (0..n).map(|j| j / wr == i).collect())
+        .collect();
+    let mut rng = rng_from_seed(seed);
+    let mut matrix = band.clone();
+    for _ in 1..wc {
+        let mut permutation: Vec<usize> = (0..n).collect();
+        permutation.shuffle(&mut rng);
+        matrix.extend(
+            band.iter()
+                .map(|row| permutation.iter().map(|&j| row[j]).collect::<Vec<_>>()),
+        );
+    }
+    Ok(matrix)
+}
+
+/// Разбор проверочной матрицы в формате alist
+///
+/// Строки: `n m`, максимальные веса, веса столбцов, веса строк, затем n списков
+/// проверок для столбцов и m списков битов для строк (с 1, нули - заполнители до
+/// максимального веса). Матрица строится по столбцам, списки строк должны с ней совпасть
+pub fn parse_alist(text: &str) -> Result<BinaryMatrix, String> {
+    let mut numbers = text.split_whitespace().map(|token| {
+        token
+            .parse::<usize>()
+            .map_err(|_| format!("«{token}» - не целое число"))
+    });
+    let mut next = |what: &str| {
+        numbers
+            .next()
+            .unwrap_or_else(|| Err(format!("файл обрывается: ожидалось {what}")))
+    };
+    let n = next("n")?;
+    let m = next("m")?;
+    if n == 0 || m == 0 || n > LdpcParameters::MAX_N || m > LdpcParameters::MAX_N {
+        return Err(format!(
+            "размеры {m}x{n} вне диапазона 1..={}",
+            LdpcParameters::MAX_N
+        ));
+    }
+    let max_column_weight = next("максимальный вес столбца")?;
+    let max_row_weight = next("максимальный вес строки")?;
+    let column_weights = read_weights(&mut next, n, max_column_weight, m, "столбца")?;
+    let row_weights = read_weights(&mut next, m, max_row_weight, n, "строки")?;
+
+    let mut matrix = vec![vec![false; n]; m];
+    for (column, &weight) in column_weights.iter().enumerate() {
+        let checks = read_list(&mut next, weight, max_column_weight, m, "столбца", column)?;
+        for check in checks {
+            matrix[check - 1][column] = true;
+        }
+    }
+    for (row, &weight) in row_weights.iter().enumerate() {
+        let bits = read_list(&mut next, weight, max_row_weight, n, "строки", row)?;
+        let mut cells = vec![false; n];
+        for bit in bits {
+            cells[bit - 1] = true;
+        }
+        if cells != matrix[row] {
+            return Err(format!(
+                "список строки {} не совпадает со списками столбцов",
+                row + 1
+            ));
+        }
+    }
+    Ok(matrix)
+}
+
+/// Веса `count` столбцов или строк: каждый в диапазоне `1..=max_weight` и не больше `bound`
+fn read_weights(
+    next: &mut impl FnMut(&str) -> Result<usize, String>,
+    count: usize,
+    max_weight: usize,
+    bound: usize,
+    what: &str,
+) -> Result<Vec<usize>, String> {
+    (0..count)
+        .map(|index| {
+            let weight = next(&format!("вес {what}"))?;
+            if weight == 0 || weight > max_weight || weight > bound {
+                return Err(format!(
+                    "вес {what} {} равен {weight}, ожидалось 1..={}",
+                    index + 1,
+                    max_weight.min(bound)
+                ));
+            }
+            Ok(weight)
+        })
+        .collect()
+}
+
+/// Список из `weight` различных номеров в диапазоне `1..=bound`, дополненный нулями
+/// до `max_weight` элементов
+fn read_list(
+    next: &mut impl FnMut(&str) -> Result<usize, String>,
+    weight: usize,
+    max_weight: usize,
+    bound: usize,
+    what: &str,
+    index: usize,
+) -> Result<Vec<usize>, String> {
+    let mut list = Vec::with_capacity(weight);
+    for position in 0..max_weight {
+        let value = next(&format!("список {what} {}", index + 1))?;
+        if position >= weight {
+            if value != 0 {
+                return Err(format!(
+                    "в списке {what} {} больше номеров, чем вес {weight}",
+                    index + 1
+                ));
+            }
+        } else if value == 0 || value > bound {
+            return Err(format!(
+                "в списке {what} {} номер {value} вне диапазона 1..={bound}",
+                index + 1
+            ));
+        } else if list.contains(&value) {
+            return Err(format!(
+                "в списке {what} {} номер {value} повторяется",
+                index + 1
+            ));
+        } else {
+            list.push(value);
+        }
+    }
+    Ok(list)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Проверочная матрица кода Хэмминга (7, 4) в формате alist
+    const HAMMING_ALIST: &str = "7 3
+3 4
+2 3 2 2 1 1 1
+4 4 4
+1 3 0
+1 2 3
+1 2 0
+2 3 0
+1 0 0
+2 0 0
+3 0 0
+1 2 3 5
+2 3 4 6
+1 2 4 7
+";
+
+    fn gallager_code() -> LdpcCode {
+        LdpcParameters {
+            n: 96,
+            ..LdpcParameters::default()
+        }
+        .build()
+        .unwrap()
+    }
+
+    fn message(k: usize) -> Vec<bool> {
+        (0..k).map(|i| (i * 5 + 1) % 7 < 3).collect()
+    }
+
+    #[test]
+    fn gallager_matrix_is_regular() {
+        let matrix = gallager_matrix(24, 3, 6, 7).unwrap();
+        assert_eq!(matrix.len(), 12);
+        assert!(
+            matrix
+                .iter()
+                .all(|row| row.iter().filter(|&&b| b).count() == 6)
+        );
+        assert!((0..24).all(|j| matrix.iter().filter(|row| row[j]).count() == 3));
+        assert_eq!(matrix, gallager_matrix(24, 3, 6, 7).unwrap());
+        assert!(gallager_matrix(25, 3, 6, 1).is_err());
+        assert!(gallager_matrix(24, 6, 6, 1).is_err());
+    }
+
+    #[test]
+    fn generator_is_orthogonal_to_parity_check() {
+        let code = gallager_code();
+        assert!(code.linear.k >= code.linear.n - code.checks());
+        assert_eq!(code.ones(), 96 * 3);
+        assert!(code.linear.is_orthogonal());
+    }
+
+    #[test]
+    fn decoders_leave_codewords_unchanged() {
+        let code = gallager_code();
+        let codeword = code.linear.encode(&message(code.linear.k));
+        for decoder in LdpcDecoder::ALL {
+            let decoding = code.decode(&codeword, decoder, 20, 0.05);
+            assert_eq!(decoding.corrected, codeword);
+            assert!(matches!(decoding.info, LdpcErrorInfo::NoError));
+            assert_eq!(decoding.syndrome_weights, vec![0]);
+        }
+    }
+
+    #[test]
+    fn decoders_correct_every_single_error() {
+        let code = gallager_code();
+        let codeword = code.linear.encode(&message(code.linear.k));
+        for decoder in LdpcDecoder::ALL {
+            for position in 0..code.linear.n {
+                let mut received = codeword.clone();
+                received[position] = !received[position];
+                let decoding = code.decode(&received, decoder, 20, 0.05);
+                assert_eq!(
+                    decoding.corrected, codeword,
+                    "{decoder:?}, ошибка в {position}"
+                );
+                assert!(matches!(decoding.info, LdpcErrorInfo::Converged(_)));
+                assert_eq!(decoding.syndrome_weights[0], 3);
+            }
+        }
+    }
+
+    #[test]
+    fn failed_decoding_returns_received_word() {
+        let code = gallager_code();
+        let received: Vec<bool> = (0..code.linear.n).map(|i| i % 2 == 0).collect();
+        let decoding = code.decode(&received, LdpcDecoder::BitFlipping, 0, 0.05);
+        assert!(matches!(decoding.info, LdpcErrorInfo::NotConverged));
+        assert_eq!(decoding.corrected, received);
+    }
+
+    #[test]
+    fn parses_alist() {
+        let matrix = parse_alist(HAMMING_ALIST).unwrap();
+        let expected: BinaryMatrix = ["1110100", "0111010", "1101001"]
+            .iter()
+            .map(|row| row.chars().map(|c| c == '1').collect())
+            .collect();
+        assert_eq!(matrix, expected);
+        let code = LdpcCode::from_parity_check(matrix).unwrap();
+        assert_eq!(code.linear.k, 4);
+    }
+
+    #[test]
+    fn rejects_bad_alist() {
+        assert!(parse_alist("").is_err());
+        assert!(parse_alist("7 x").is_err());
+        assert!(parse_alist("0 3").is_err());
+        assert!(parse_alist(&HAMMING_ALIST.replace("3 0 0\n1 2 3 5", "4 0 0\n1 2 3 5")).is_err());
+        assert!(parse_alist(&HAMMING_ALIST.replace("1 2 4 7", "1 2 5 7")).is_err());
+        assert!(
+            parse_alist(&HAMMING_ALIST.lines().take(8).collect::<Vec<_>>().join("\n")).is_err()
+        );
+        // Списки строк обязательны
+        assert!(
+            parse_alist(
+                &HAMMING_ALIST
+                    .lines()
+                    .take(11)
+                    .collect::<Vec<_>>()
+                    .join("\n")
+            )
+            .is_err()
+        );
+    }
+
+    #[test]
+    fn rejects_alist_weight_mismatch() {
+        // Вес столбца 1 объявлен равным 1, а в списке две проверки
+        assert!(parse_alist(&HAMMING_ALIST.replace("2 3 2 2 1 1 1", "1 3 2 2 1 1 1")).is_err());
+        // Вес больше объявленного максимума
+        assert!(parse_alist(&HAMMING_ALIST.replace("4 4 4", "4 5 4")).is_err());
+        // Ненулевой номер на месте заполнителя
+        assert!(parse_alist(&HAMMING_ALIST.replace("1 0 0", "1 3 0")).is_err());
+        // Повторяющийся номер
+        assert!(parse_alist(&HAMMING_ALIST.replace("1 3 0", "1 1 0")).is_err());
+    }
+
+    #[test]
+    fn rejects_alist_index_out_of_range() {
+        let err = parse_alist(&HAMMING_ALIST.replace("1 3 0", "1 4 0")).unwrap_err();
+        assert!(err.contains("1..=3"), "{err}");
+        let err = parse_alist(&HAMMING_ALIST.replace("1 2 3 5", "1 2 3 8")).unwrap_err();
+        assert!(err.contains("1..=7"), "{err}");
+    }
+}
@@ -0,0 +1,76 @@
+use serde::{Deserialize, Serialize};
+
+/// Схема деления на g(x) на регистре сдвига
+#[derive(PartialEq, Eq, Clone, Copy, Debug, Default, Serialize, Deserialize)]
+pub enum LfsrMode {
+    /// Кодер: биты сообщения подаются на выход регистра (деление `m(x) x^p` на g(x)),
+    /// после k тактов в регистре проверочные разряды
+    #[default]
+    Encoder,
+    /// Вычислитель синдрома: биты принятого слова подаются на вход регистра,
+    /// после n тактов в регистре синдром
+    Syndrome,
+}
+
+impl LfsrMode {
+    pub const ALL: [Self; 2] = [Self::Encoder, Self::Syndrome];
+
+    #[must_use]
+    pub const fn label(self) -> &'static str {
+        match self {
+            Self::Encoder => "Кодирование (остаток m(x)·x^p mod g(x))",
+            Self::Syndrome => "Вычисление синдрома (r(x) mod g(x))",
+        }
+    }
+}
+
+/// Один такт регистра сдвига
+#[derive(Clone, Debug)]
+pub struct LfsrStep {
+    /// Бит, поданный на такте
+    pub input: bool,
+    /// Бит обратной связи, поступающий на сумматоры по модулю 2
+    pub feedback: bool,
+    /// Содержимое ячеек `r0..r(p-1)` после такта
+    pub register: Vec<bool>,
+}
+
+/// Пошаговое деление на g(x): биты `input` подаются старшими степенями вперед
+///
+/// Ячейка `r_i` хранит коэффициент при `x^i`; на вход ячейки `r_i` (i > 0)
+/// поступает `r_(i-1)`, сложенный с обратной связью, если `g_i = 1`
+#[must_use]
+pub fn lfsr_trace(generator: &[bool], input: &[bool], mode: LfsrMode) -> Vec<LfsrStep> {
+    let p = generator.len().saturating_sub(1);
+    let mut register = vec![false; p];
+    input
+        .iter()
+        .map(|&bit| {
+            let output = register.last().copied().unwrap_or(false);
+            let feedback = match mode {
+                LfsrMode::Encoder => bit ^ output,
+                LfsrMode::Syndrome => output,
+            };
+            for i in (1..p).rev() {
+                register[i] = register[i - 1] ^ (feedback && generator[i]);
+            }
+            if let Some(first) = register.first_mut() {
+                *first = match mode {
+                    LfsrMode::Encoder => feedback,
+                    LfsrMode::Syndrome => bit ^ feedback,
+                };
+            }
+            LfsrStep {
+                input: bit,
+                feedback,
+                register: register.clone(),
+            }
+        })
+        .collect()
+}
+
+/// Остаток в регистре, разряды от старшего к младшему (`r(p-1) .. r0`)
+#[must_use]
+pub fn register_remainder(register: &[bool]) -> Vec<bool> {
+    register.iter().rev().copied().collect()
+}
@@ -0,0 +1,187 @@
+use entropy_fx::coding::systematic::{BinaryMatrix, ErrorInfo};
+use serde::{Deserialize, Serialize};
+
+/// Двоичный линейный (n, k)-код, заданный порождающей и проверочной матрицами
+#[derive(Clone, Serialize, Deserialize)]
+pub struct LinearCode {
+    pub k: usize,
+    pub n: usize,
+    /// Порождающая матрица G (k x n)
+    pub generator: BinaryMatrix,
+    /// Проверочная матрица H (`(n - k) x n`)
+    pub parity_check: BinaryMatrix,
+}
+
+impl LinearCode {
+    /// Систематический код с порождающей матрицей `G = [I | P]`
+    pub fn from_parity_part(parity: &BinaryMatrix) -> Result<Self, String> {
+        let k = parity.len();
+        let r = parity.first().map_or(0, Vec::len);
+        if k == 0 || r == 0 {
+            return Err("матрица P пуста".to_string());
+        }
+        let generator = (0..k)
+            .map(|i| {
+                (0..k)
+                    .map(|j| i == j)
+                    .chain(parity[i].iter().copied())
+                    .collect()
+            })
+            .collect();
+        Self::from_generator(&generator)
+    }
+
+    /// Код с произвольной порождающей матрицей G полного ранга
+    ///
+    /// Проверочная матрица строится по приведенной ступенчатой форме G:
+    /// если после перестановки столбцов `G = [I | A]`, то `H = [A^T | I]`
+    pub fn from_generator(generator: &BinaryMatrix) -> Result<Self, String> {
+        let k = generator.len();
+        let n = generator.first().map_or(0, Vec::len);
+        if k == 0 || n == 0 {
+            return Err("матрица G пуста".to_string());
+        }
+        if generator.iter().any(|row| row.len() != n) {
+            return Err("строки матрицы G имеют разную длину".to_string());
+        }
+        if k >= n {
+            return Err(format!(
+                "число столбцов ({n}) должно быть больше числа строк ({k})"
+            ));
+        }
+
+        let (reduced, pivots) = row_reduce(generator);
+        if pivots.len() < k {
+            return Err(format!(
+                "строки матрицы G линейно зависимы (ранг {} < k = {k})",
+                pivots.len()
+            ));
+        }
+
+        let free: Vec<usize> = (0..n).filter(|j| !pivots.contains(j)).collect();
+        let parity_check = free
+            .iter()
+            .map(|&column| {
+                let mut row = vec![false; n];
+                for (i, &pivot) in pivots.iter().enumerate() {
+                    row[pivot] = reduced[i][column];
+                }
+                row[column] = true;
+                row
+            })
+            .collect();
+
+        Ok(Self {
+            k,
+            n,
+            generator: generator.clone(),
+            parity_check,
+        })
+    }
+
+    /// Число проверочных разрядов
+    #[must_use]
+    pub const fn p(&self) -> usize {
+        self.n - self.k
+    }
+
+    /// Кодирование сообщения: `c = m G`
+    #[must_use]
+    pub fn encode(&self, message: &[bool]) -> Vec<bool> {
+        let mut codeword = vec![false; self.n];
+        for (row, _) in self.generator.iter().zip(message).filter(|(_, bit)| **bit) {
+            for (c, &g) in codeword.iter_mut().zip(row) {
+                *c ^= g;
+            }
+        }
+        codeword
+    }
+
+    /// Синдром `s = H r^T`
+    #[must_use]
+    pub fn syndrome(&self, received: &[bool]) -> Vec<bool> {
+        self.parity_check
+            .iter()
+            .map(|row| row.iter().zip(received).filter(|(h, r)| **h && **r).count() % 2 == 1)
+            .collect()
+    }
+
+    /// Исправление одиночной ошибки: синдром совпадает со столбцом H в позиции ошибки
+    #[must_use]
+    pub fn correct_error(&self, received: &[bool]) -> (Vec<bool>, ErrorInfo) {
+        let syndrome = self.syndrome(received);
+        let mut corrected = received.to_vec();
+        if !syndrome.iter().any(|&bit| bit) {
+            return (corrected, ErrorInfo::NoError);
+        }
+        match (0..self.n).find(|&j| self.column(j) == syndrome) {
+            Some(position) => {
+                corrected[position] = !corrected[position];
+                (corrected, ErrorInfo::SingleError(position))
+            }
+            None => (corrected, ErrorInfo::Uncorrectable),
+        }
+    }
+
+    /// Столбец проверочной матрицы
+    #[must_use]
+    pub fn column(&self, j: usize) -> Vec<bool> {
+        self.parity_check.iter().map(|row| row[j]).collect()
+    }
+
+    /// Проверка `G H^T = 0`
+    #[must_use]
+    pub fn is_orthogonal(&self) -> bool {
+        self.generator
+            .iter()
+            .all(|row| !self.syndrome(row).iter().any(|&bit| bit))
+    }
+
+    /// Нарушения условия исправления одиночных ошибок: нулевые и совпадающие столбцы H
+    #[must_use]
+    pub fn single_error_problems(&self) -> Vec<String> {
+        let columns: Vec<Vec<bool>> = (0..self.n).map(|j| self.column(j)).collect();
+        let mut problems = vec![];
+        for (j, column) in columns.iter().enumerate() {
+            if !column.iter().any(|&bit| bit) {
+                problems.push(format!("столбец {} матрицы H нулевой", j + 1));
+            } else if let Some(first) = columns[..j].iter().position(|other| other == column) {
+                problems.push(format!(
+                    "столбцы {} и {} матрицы H совпадают",
+                    first + 1,
+                    j + 1
+                ));
+            }
+        }
+        problems
+    }
+}
+
+/// Приведение матрицы над GF(2) к приведенной ступенчатой форме
+///
+/// Возвращает преобразованную матрицу и номера ведущих столбцов
+fn row_reduce(matrix: &BinaryMatrix) -> (BinaryMatrix, Vec<usize>) {
+    let mut rows = matrix.clone();
+    let n = rows.first().map_or(0, Vec::len);
+    let mut pivots = vec![];
+    for column in 0..n {
+        let rank = pivots.len();
+        let Some(pivot) = (rank..rows.len()).find(|&i| rows[i][column]) else {
+            continue;
+        };
+        rows.swap(rank, pivot);
+        let pivot_row = rows[rank].clone();
+        for (i, row) in rows.iter_mut().enumerate() {
+            if i != rank && row[column] {
+                for (a, &b) in row.iter_mut().zip(&pivot_row) {
+                    *a ^= b;
+                }
+            }
+        }
+        pivots.push(column);
+        if pivots.len() == rows.len() {
+            break;
+        }
+    }
+    (rows, pivots)
+}
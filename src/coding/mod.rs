@@ -0,0 +1,10 @@
+pub mod bch;
+pub mod convolutional;
+pub mod cyclic;
+pub mod gf;
+pub mod golay;
+pub mod ldpc;
+pub mod lfsr;
+pub mod linear;
+pub mod poly;
+pub mod reed_solomon;
@@ -0,0 +1,184 @@
+//! Многочлены над GF(2): коэффициенты хранятся по возрастанию степени (`poly[i]` - при `x^i`)
+
+/// Степень многочлена (`None` для нулевого)
+#[must_use]
+pub fn degree(poly: &[bool]) -> Option<usize> {
+    poly.iter().rposition(|&c| c)
+}
+
+/// Удаление старших нулевых коэффициентов
+#[must_use]
+pub fn trim(mut poly: Vec<bool>) -> Vec<bool> {
+    poly.truncate(degree(&poly).map_or(0, |d| d + 1));
+    poly
+}
+
+/// Остаток от деления `dividend` на `divisor`, дополненный нулями до степени `deg divisor - 1`
+///
+/// # Panics
+/// Если `divisor` нулевой
+#[must_use]
+pub fn poly_mod(dividend: &[bool], divisor: &[bool]) -> Vec<bool> {
+    let d = degree(divisor).expect("деление на нулевой многочлен");
+    let mut remainder = dividend.to_vec();
+    for i in (d..remainder.len()).rev() {
+        if remainder[i] {
+            for (j, &c) in divisor[..=d].iter().enumerate() {
+                remainder[i - d + j] ^= c;
+            }
+        }
+    }
+    remainder.resize(d, false);
+    remainder
+}
+
+/// Произведение многочленов
+#[must_use]
+pub fn poly_mul(a: &[bool], b: &[bool]) -> Vec<bool> {
+    let mut product = vec![false; (a.len() + b.len()).saturating_sub(1)];
+    for (i, _) in a.iter().enumerate().filter(|(_, c)| **c) {
+        for (j, _) in b.iter().enumerate().filter(|(_, c)| **c) {
+            product[i + j] ^= true;
+        }
+    }
+    trim(product)
+}
+
+/// Многочлен `x^n + 1`
+#[must_use]
+pub fn x_pow_n_plus_one(n: usize) -> Vec<bool> {
+    let mut poly = vec![false; n + 1];
+    poly[0] = true;
+    poly[n] = true;
+    poly
+}
+
+/// Делит ли `divisor` многочлен `dividend`
+#[must_use]
+pub fn divides(divisor: &[bool], dividend: &[bool]) -> bool {
+    !poly_mod(dividend, divisor).iter().any(|&c| c)
+}
+
+/// Период многочлена: наименьшее `N`, при котором `g(x)` делит `x^N + 1`
+///
+/// Возвращает `None`, если свободный член равен нулю или период больше `limit`
+#[must_use]
+pub fn period(g: &[bool], limit: usize) -> Option<usize> {
+    let d = degree(g)?;
+    if !g[0] {
+        return None;
+    }
+    if d == 0 {
+        return Some(1);
+    }
+    // power = x^i mod g
+    let mut power = vec![false; d];
+    power[0] = true;
+    for i in 1..=limit {
+        let carry = power[d - 1];
+        power.rotate_right(1);
+        power[0] = false;
+        if carry {
+            for (c, &gc) in power.iter_mut().zip(&g[..d]) {
+                *c ^= gc;
+            }
+        }
+        if power[0] && power[1..].iter().all(|&c| !c) {
+            return Some(i);
+        }
+    }
+    None
+}
+
+/// Неприводим ли многочлен (проверка делением на все многочлены меньшей степени)
+#[must_use]
+pub fn is_irreducible(g: &[bool]) -> bool {
+    let Some(d) = degree(g) else {
+        return false;
+    };
+    if d == 0 {
+        return false;
+    }
+    (1..=d / 2).all(|divisor_degree| {
+        (0..1u64 << divisor_degree).all(|low| {
+            let divisor: Vec<bool> = (0..=divisor_degree)
+                .map(|i| i == divisor_degree || (low >> i) & 1 == 1)
+                .collect();
+            !divides(&divisor, g)
+        })
+    })
+}
+
+/// Примитивен ли многочлен: неприводим и имеет максимальный период `2^d - 1`
+#[must_use]
+pub fn is_primitive(g: &[bool]) -> bool {
+    let Some(d) = degree(g) else {
+        return false;
+    };
+    let order = (1usize << d) - 1;
+    is_irreducible(g) && period(g, order) == Some(order)
+}
+
+/// Многочлен из битовой маски (бит `i` - коэффициент при `x^i`)
+#[must_use]
+pub fn from_mask(mask: u64) -> Vec<bool> {
+    trim((0..64).map(|i| (mask >> i) & 1 == 1).collect())
+}
+
+/// Первый по порядку примитивный многочлен степени `d`
+#[must_use]
+pub fn first_primitive(d: usize) -> Option<Vec<bool>> {
+    (0..1u64 << d.saturating_sub(1))
+        .map(|low| from_mask((1 << d) | (low << 1) | 1))
+        .find(|g| is_primitive(g))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn remainder_and_product() {
+        // (x^3 + x + 1)(x + 1) = x^4 + x^3 + x^2 + 1
+        let product = poly_mul(&from_mask(0b1011), &from_mask(0b11));
+        assert_eq!(product, from_mask(0b11101));
+        assert!(poly_mod(&product, &from_mask(0b1011)).iter().all(|&c| !c));
+        assert_eq!(
+            poly_mod(&from_mask(0b10000), &from_mask(0b10011)),
+            from_mask(0b11)
+                .into_iter()
+                .chain([false, false])
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(degree(&[false, false]), None);
+        assert_eq!(
+            trim(vec![true, false, true, false]),
+            vec![true, false, true]
+        );
+    }
+
+    #[test]
+    fn hamming_generator_divides_x7_plus_1() {
+        assert!(divides(&from_mask(0b1011), &x_pow_n_plus_one(7)));
+        assert!(!divides(&from_mask(0b1011), &x_pow_n_plus_one(6)));
+    }
+
+    #[test]
+    fn periods() {
+        assert_eq!(period(&from_mask(0b10011), 100), Some(15));
+        assert_eq!(period(&from_mask(0b11111), 100), Some(5));
+        assert_eq!(period(&from_mask(0b10011), 10), None);
+        assert_eq!(period(&from_mask(0b110), 100), None);
+    }
+
+    #[test]
+    fn irreducibility_and_primitivity() {
+        assert!(is_primitive(&from_mask(0b10011)));
+        assert!(is_irreducible(&from_mask(0b11111)));
+        assert!(!is_primitive(&from_mask(0b11111)));
+        assert!(!is_irreducible(&from_mask(0b10101)));
+        assert_eq!(first_primitive(3), Some(from_mask(0b1011)));
+        assert_eq!(first_primitive(4), Some(from_mask(0b10011)));
+        assert_eq!(first_primitive(8), Some(from_mask(0b1_0001_1101)));
+    }
+}
@@ -0,0 +1,391 @@
+use crate::coding::gf::{GaloisField, berlekamp_massey};
+use serde::{Deserialize, Serialize};
+
+/// Параметры кода Рида–Соломона, задаваемые пользователем
+#[derive(Clone, Copy, Serialize, Deserialize)]
+#[serde(default)]
+pub struct RsParameters {
+    /// Размер символа в битах, поле GF(2^m)
+    pub m: usize,
+    /// Длина кода в символах (не больше `2^m - 1`)
+    pub n: usize,
+    /// Число информационных символов
+    pub k: usize,
+    /// Число искаженных символов в модели «Одиночная ошибка»
+    pub symbol_errors: usize,
+    /// Число стираний (символов с известным положением) в каждом слове
+    pub erasures: usize,
+}
+
+impl Default for RsParameters {
+    fn default() -> Self {
+        Self {
+            m: 4,
+            n: 15,
+            k: 11,
+            symbol_errors: 2,
+            erasures: 0,
+        }
+    }
+}
+
+impl RsParameters {
+    pub fn build(self) -> Result<ReedSolomonCode, String> {
+        ReedSolomonCode::new(self.m, self.n, self.k)
+    }
+}
+
+/// Результат декодирования кода Рида–Соломона
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub enum RsErrorInfo {
+    NoError,
+    /// Символы (номера с 1), значение которых изменено декодером, включая заполненные
+    /// стирания; стертый символ, значение которого оказалось нулевым, не входит
+    Corrected(Vec<usize>),
+    /// Число ошибок и стираний превышает возможности кода: `2ν + f > n - k`
+    Uncorrectable,
+}
+
+/// Промежуточные величины декодирования для карточки эксперимента
+#[derive(Clone)]
+pub struct RsDecoding {
+    pub corrected: Vec<usize>,
+    pub info: RsErrorInfo,
+    /// Синдромы `S_j = r(α^j)`, `j = 1..n-k`
+    pub syndromes: Vec<usize>,
+    /// Многочлен локаторов стираний Γ(x)
+    pub erasure_locator: Vec<usize>,
+    /// Многочлен локаторов ошибок Λ(x), найденный по синдромам Форни
+    pub error_locator: Vec<usize>,
+    /// Многочлен значений ошибок Ω(x)
+    pub evaluator: Vec<usize>,
+    /// Найденные процедурой Ченя позиции (символы с 0) и значения ошибок по Форни
+    pub errata: Vec<(usize, usize)>,
+}
+
+/// Код Рида–Соломона (n, k) над GF(2^m) с корнями g(x) `α, α^2, ..., α^(n-k)`
+///
+/// Символ `i` кодового слова - коэффициент при `x^(n-1-i)`; при `n < 2^m - 1` код укороченный
+#[derive(Clone)]
+pub struct ReedSolomonCode {
+    pub field: GaloisField,
+    pub n: usize,
+    pub k: usize,
+    /// Порождающий многочлен (коэффициенты по возрастанию степени)
+    pub generator: Vec<usize>,
+}
+
+impl ReedSolomonCode {
+    pub const MIN_M: usize = 3;
+    pub const MAX_M: usize = 8;
+
+    pub fn new(m: usize, n: usize, k: usize) -> Result<Self, String> {
+        if !(Self::MIN_M..=Self::MAX_M).contains(&m) {
+            return Err(format!(
+                "m должно быть от {} до {}",
+                Self::MIN_M,
+                Self::MAX_M
+            ));
+        }
+        let field =
+            GaloisField::new(m).ok_or_else(|| "не удалось построить поле GF(2^m)".to_string())?;
+        if n > field.order() || n < 2 {
+            return Err(format!("n должно быть от 2 до 2^m - 1 = {}", field.order()));
+        }
+        if k == 0 || k >= n {
+            return Err(format!("k должно быть от 1 до n - 1 = {}", n - 1));
+        }
+        let generator = (1..=n - k).fold(vec![1], |poly, j| {
+            field.poly_mul(&poly, &[field.alpha_pow(power_i64(j)), 1])
+        });
+        Ok(Self {
+            field,
+            n,
+            k,
+            generator,
+        })
+    }
+
+    /// Размер символа в битах
+    #[must_use]
+    pub const fn m(&self) -> usize {
+        self.field.m
+    }
+
+    /// Число проверочных символов `n - k`
+    #[must_use]
+    pub const fn parity_symbols(&self) -> usize {
+        self.n - self.k
+    }
+
+    /// Гарантированно исправляемое число ошибок без стираний
+    #[must_use]
+    pub const fn t(&self) -> usize {
+        self.parity_symbols() / 2
+    }
+
+    /// Систематическое кодирование: `c(x) = m(x) x^(n-k) + (m(x) x^(n-k) mod g(x))`
+    #[must_use]
+    pub fn encode_symbols(&self, message: &[usize]) -> Vec<usize> {
+        let parity = self.parity_symbols();
+        // Деление столбиком, старшие коэффициенты вперед; g(x) приведенный
+        let mut remainder = vec![0; parity];
+        for &symbol in message {
+            let feedback = symbol ^ remainder[0];
+            remainder.rotate_left(1);
+            remainder[parity - 1] = 0;
+            for (i, r) in remainder.iter_mut().enumerate() {
+                *r ^= self.field.mul(feedback, self.generator[parity - 1 - i]);
+            }
+        }
+        message.iter().copied().chain(remainder).collect()
+    }
+
+    /// Кодирование битов: каждые m бит сообщения - один символ (старший бит первым)
+    #[must_use]
+    pub fn encode(&self, message: &[bool]) -> Vec<bool> {
+        symbols_to_bits(
+            &self.encode_symbols(&bits_to_symbols(message, self.m())),
+            self.m(),
+        )
+    }
+
+    /// Синдромы `S_j = r(α^j)`, `j = 1..n-k`
+    #[must_use]
+    pub fn syndromes(&self, received: &[usize]) -> Vec<usize> {
+        (1..=self.parity_symbols())
+            .map(|j| {
+                received.iter().fold(0, |value, &symbol| {
+                    self.field.mul(value, self.field.alpha_pow(power_i64(j))) ^ symbol
+                })
+            })
+            .collect()
+    }
+
+    /// Декодирование ошибок и стираний (`erasures` - номера символов с 0)
+    ///
+    /// Λ(x) находится алгоритмом Берлекэмпа–Мэсси по синдромам Форни `Γ(x) S(x)`,
+    /// корни `Ψ(x) = Λ(x) Γ(x)` - процедурой Ченя, значения ошибок - по формуле Форни
+    /// `e = Ω(X^-1) / Ψ'(X^-1)`, где `Ω(x) = S(x) Ψ(x) mod x^(n-k)`
+    #[must_use]
+    pub fn decode_symbols(&self, received: &[usize], erasures: &[usize]) -> RsDecoding {
+        let field = &self.field;
+        let parity = self.parity_symbols();
+        let syndromes = self.syndromes(received);
+        let erasure_locator = erasures.iter().fold(vec![1], |poly, &position| {
+            field.poly_mul(&poly, &[1, self.locator(position)])
+        });
+        let mut decoding = RsDecoding {
+            corrected: received.to_vec(),
+            info: RsErrorInfo::NoError,
+            syndromes,
+            erasure_locator,
+            error_locator: vec![1],
+            evaluator: vec![],
+            errata: vec![],
+        };
+        if decoding.syndromes.iter().all(|&s| s == 0) {
+            return decoding;
+        }
+        if erasures.len() > parity {
+            decoding.info = RsErrorInfo::Uncorrectable;
+            return decoding;
+        }
+
+        let mut forney = field.poly_mul(&decoding.syndromes, &decoding.erasure_locator);
+        forney.truncate(parity);
+        let (error_locator, length) = berlekamp_massey(field, &forney[erasures.len()..]);
+        decoding.error_locator = error_locator;
+
+        let errata_locator = field.poly_mul(&decoding.error_locator, &decoding.erasure_locator);
+        let mut evaluator = field.poly_mul(&decoding.syndromes, &errata_locator);
+        evaluator.truncate(parity);
+        decoding.evaluator = evaluator;
+
+        // Формальная производная в характеристике 2: остаются нечетные степени
+        let derivative: Vec<usize> = errata_locator
+            .iter()
+            .enumerate()
+            .skip(1)
+            .map(|(i, &c)| if i % 2 == 1 { c } else { 0 })
+            .collect();
+        for position in 0..self.n {
+            let inverse = field.div(1, self.locator(position));
+            if field.eval(&errata_locator, inverse) == 0 {
+                let denominator = field.eval(&derivative, inverse);
+                let magnitude = if denominator == 0 {
+                    0
+                } else {
+                    field.div(field.eval(&decoding.evaluator, inverse), denominator)
+                };
+                decoding.errata.push((position, magnitude));
+            }
+        }
+
+        let degree = errata_locator.iter().rposition(|&c| c != 0).unwrap_or(0);
+        if 2 * length + erasures.len() > parity || decoding.errata.len() != degree {
+            decoding.info = RsErrorInfo::Uncorrectable;
+            return decoding;
+        }
+        for &(position, magnitude) in &decoding.errata {
+            decoding.corrected[position] ^= magnitude;
+        }
+        if self.syndromes(&decoding.corrected).iter().any(|&s| s != 0) {
+            decoding.corrected = received.to_vec();
+            decoding.info = RsErrorInfo::Uncorrectable;
+            return decoding;
+        }
+        decoding.info = RsErrorInfo::Corrected(
+            decoding
+                .errata
+                .iter()
+                .filter(|(_, magnitude)| *magnitude != 0)
+                .map(|(position, _)| position + 1)
+                .collect(),
+        );
+        decoding
+    }
+
+    /// Декодирование битов; `erasures` - номера стертых символов (с 1)
+    ///
+    /// # Errors
+    /// Возвращает ошибку, если номер стертого символа вне диапазона `1..=n` или повторяется
+    pub fn decode(
+        &self,
+        received: &[bool],
+        erasures: &[usize],
+    ) -> Result<(Vec<bool>, RsDecoding), String> {
+        let mut positions = Vec::with_capacity(erasures.len());
+        for &position in erasures {
+            if !(1..=self.n).contains(&position) {
+                return Err(format!(
+                    "номер стертого символа {position} вне диапазона 1..={}",
+                    self.n
+                ));
+            }
+            if positions.contains(&(position - 1)) {
+                return Err(format!("символ {position} стерт повторно"));
+            }
+            positions.push(position - 1);
+        }
+        let decoding = self.decode_symbols(&bits_to_symbols(received, self.m()), &positions);
+        Ok((symbols_to_bits(&decoding.corrected, self.m()), decoding))
+    }
+
+    /// Локатор символа: `X = α^(n-1-i)`
+    fn locator(&self, position: usize) -> usize {
+        self.field.alpha_pow(power_i64(self.n - 1 - position))
+    }
+}
+
+/// Разбиение битов на символы по m бит (старший бит первым)
+#[must_use]
+pub fn bits_to_symbols(bits: &[bool], m: usize) -> Vec<usize> {
+    bits.chunks(m)
+        .map(|chunk| {
+            chunk
+                .iter()
+                .fold(0, |value, &bit| (value << 1) | usize::from(bit))
+        })
+        .collect()
+}
+
+/// Представление символов битами (старший бит первым)
+#[must_use]
+pub fn symbols_to_bits(symbols: &[usize], m: usize) -> Vec<bool> {
+    symbols
+        .iter()
+        .flat_map(|&symbol| (0..m).rev().map(move |i| (symbol >> i) & 1 == 1))
+        .collect()
+}
+
+fn power_i64(power: usize) -> i64 {
+    i64::try_from(power).unwrap_or(i64::MAX)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Все подмножества позиций `0..n` мощности не больше `limit`
+    fn subsets(n: usize, limit: usize) -> Vec<Vec<usize>> {
+        (0..1usize << n)
+            .filter(|mask| mask.count_ones() as usize <= limit)
+            .map(|mask| (0..n).filter(|i| mask >> i & 1 == 1).collect())
+            .collect()
+    }
+
+    fn check_errata_within_bound(code: &ReedSolomonCode) {
+        let order = code.field.order();
+        let message: Vec<usize> = (0..code.k).map(|i| (3 * i + 1) % (order + 1)).collect();
+        let codeword = code.encode_symbols(&message);
+        let parity = code.parity_symbols();
+        for erasures in subsets(code.n, parity) {
+            let free: Vec<usize> = (0..code.n).filter(|i| !erasures.contains(i)).collect();
+            for errors in subsets(free.len(), (parity - erasures.len()) / 2) {
+                let mut received = codeword.clone();
+                for (shift, &e) in erasures.iter().enumerate() {
+                    received[e] = shift % (order + 1);
+                }
+                for (shift, &e) in errors.iter().enumerate() {
+                    received[free[e]] ^= 1 + (shift * 5) % order;
+                }
+                let decoding = code.decode_symbols(&received, &erasures);
+                assert_eq!(
+                    decoding.corrected, codeword,
+                    "стирания {erasures:?}, ошибки в {errors:?} из {free:?}"
+                );
+                assert!(!matches!(decoding.info, RsErrorInfo::Uncorrectable));
+            }
+        }
+    }
+
+    #[test]
+    fn generator_has_consecutive_roots() {
+        let code = ReedSolomonCode::new(4, 15, 11).unwrap();
+        assert_eq!(code.generator.len(), 5);
+        for j in 1..=4 {
+            assert_eq!(code.field.eval(&code.generator, code.field.alpha_pow(j)), 0);
+        }
+        let codeword = code.encode_symbols(&[1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11]);
+        assert_eq!(&codeword[..11], &[1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11]);
+        assert!(code.syndromes(&codeword).iter().all(|&s| s == 0));
+    }
+
+    #[test]
+    fn corrects_every_errata_pattern_within_bound() {
+        check_errata_within_bound(&ReedSolomonCode::new(3, 7, 3).unwrap());
+        check_errata_within_bound(&ReedSolomonCode::new(4, 10, 5).unwrap());
+    }
+
+    #[test]
+    fn reports_pattern_beyond_bound() {
+        let code = ReedSolomonCode::new(3, 7, 3).unwrap();
+        let codeword = code.encode_symbols(&[1, 2, 3]);
+        let mut received = codeword.clone();
+        received[0] ^= 1;
+        received[3] ^= 2;
+        received[5] ^= 3;
+        let decoding = code.decode_symbols(&received, &[]);
+        assert_ne!(decoding.corrected, codeword);
+    }
+
+    #[test]
+    fn decode_validates_erasures() {
+        let code = ReedSolomonCode::new(4, 15, 11).unwrap();
+        let bits = code.encode(&[true; 44]);
+        assert!(code.decode(&bits, &[0]).is_err());
+        assert!(code.decode(&bits, &[16]).is_err());
+        assert!(code.decode(&bits, &[3, 3]).is_err());
+        let (corrected, decoding) = code.decode(&bits, &[1, 15]).unwrap();
+        assert_eq!(corrected, bits);
+        assert!(matches!(decoding.info, RsErrorInfo::NoError));
+    }
+
+    #[test]
+    fn bit_symbol_round_trip() {
+        let bits = vec![true, false, true, true, false, false, true, false];
+        assert_eq!(bits_to_symbols(&bits, 4), vec![0b1011, 0b0010]);
+        assert_eq!(symbols_to_bits(&bits_to_symbols(&bits, 4), 4), bits);
+    }
+}
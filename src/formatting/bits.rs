@@ -1,5 +1,53 @@
+use entropy_fx::coding::systematic::BinaryMatrix;
+
 /// Форматирование битового вектора как строки
 #[must_use]
 pub fn format_bits(bits: &[bool]) -> String {
     bits.iter().map(|&b| if b { '1' } else { '0' }).collect()
 }
+
+/// Форматирование символов GF(2^m) шестнадцатеричными числами через пробел
+#[must_use]
+pub fn format_symbols_hex(symbols: &[usize], m: usize) -> String {
+    let width = m.div_ceil(4);
+    symbols
+        .iter()
+        .map(|symbol| format!("{symbol:0width$X}"))
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Форматирование бинарной матрицы: строка из `0` и `1` на каждую строку матрицы
+#[must_use]
+pub fn format_binary_matrix(matrix: &BinaryMatrix) -> String {
+    matrix
+        .iter()
+        .map(|row| format_bits(row))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Разбор матрицы из текста: строки из `0` и `1`, пробелы, запятые и `;` игнорируются
+pub fn parse_binary_matrix(text: &str) -> Result<BinaryMatrix, String> {
+    let matrix: BinaryMatrix = text
+        .lines()
+        .map(|line| {
+            line.chars()
+                .filter(|c| !c.is_whitespace() && *c != ',' && *c != ';')
+                .map(|c| match c {
+                    '0' => Ok(false),
+                    '1' => Ok(true),
+                    _ => Err(format!("недопустимый символ '{c}'")),
+                })
+                .collect::<Result<Vec<_>, _>>()
+        })
+        .filter(|row| row.as_ref().map_or(true, |row| !row.is_empty()))
+        .collect::<Result<_, _>>()?;
+    if matrix.is_empty() {
+        return Err("матрица пуста".to_string());
+    }
+    if matrix.iter().any(|row| row.len() != matrix[0].len()) {
+        return Err("строки матрицы имеют разную длину".to_string());
+    }
+    Ok(matrix)
+}
@@ -0,0 +1,247 @@
+use crate::state::experiments::ExperimentResult;
+use serde::{Deserialize, Serialize};
+use std::fmt::Write;
+
+/// Формат CSV: разделитель полей и десятичный разделитель
+#[derive(PartialEq, Eq, Clone, Copy, Debug, Default, Serialize, Deserialize)]
+pub enum CsvLocale {
+    /// `;` между полями и `,` в дробной части (русская локаль табличных редакторов)
+    #[default]
+    Russian,
+    /// `,` между полями и `.` в дробной части
+    English,
+}
+
+impl CsvLocale {
+    #[must_use]
+    pub const fn label(self) -> &'static str {
+        match self {
+            Self::Russian => "0,5;0,25 (русская локаль)",
+            Self::English => "0.5,0.25 (английская локаль)",
+        }
+    }
+
+    #[must_use]
+    pub const fn delimiter(self) -> char {
+        match self {
+            Self::Russian => ';',
+            Self::English => ',',
+        }
+    }
+
+    #[must_use]
+    pub const fn decimal_separator(self) -> char {
+        match self {
+            Self::Russian => ',',
+            Self::English => '.',
+        }
+    }
+
+    /// Форматирование числа с десятичным разделителем локали
+    #[must_use]
+    pub fn format_number(self, value: f64) -> String {
+        let text = format!("{value}");
+        match self {
+            Self::Russian => text.replace('.', ","),
+            Self::English => text,
+        }
+    }
+
+    /// Разбор числа; точка в дробной части принимается в любой локали
+    #[must_use]
+    pub fn parse_number(self, text: &str) -> Option<f64> {
+        let text = text.trim().trim_matches('"');
+        if text.is_empty() {
+            return None;
+        }
+        let normalized = text.replace(self.decimal_separator(), ".");
+        normalized.parse().ok()
+    }
+}
+
+/// Запись вектора: строка заголовков и строка значений
+pub fn write_vector(
+    out: &mut String,
+    title: &str,
+    prefix: &str,
+    values: &[f64],
+    locale: CsvLocale,
+) {
+    let delimiter = locale.delimiter();
+    let _ = writeln!(out, "{title}");
+    let header: Vec<String> = (1..=values.len()).map(|i| format!("{prefix}{i}")).collect();
+    let _ = writeln!(out, "{}", header.join(&delimiter.to_string()));
+    let row: Vec<String> = values.iter().map(|&v| locale.format_number(v)).collect();
+    let _ = writeln!(out, "{}", row.join(&delimiter.to_string()));
+}
+
+/// Запись матрицы с заголовками строк и столбцов
+pub fn write_matrix(
+    out: &mut String,
+    title: &str,
+    matrix: &[Vec<f64>],
+    row_prefix: &str,
+    col_prefix: &str,
+    locale: CsvLocale,
+) {
+    let delimiter = locale.delimiter().to_string();
+    let columns = matrix.first().map_or(0, Vec::len);
+    let _ = writeln!(out, "{title}");
+
+    let header: Vec<String> = std::iter::once(String::new())
+        .chain((1..=columns).map(|j| format!("{col_prefix}{j}")))
+        .collect();
+    let _ = writeln!(out, "{}", header.join(&delimiter));
+
+    for (i, row) in matrix.iter().enumerate() {
+        let cells: Vec<String> = std::iter::once(format!("{row_prefix}{}", i + 1))
+            .chain(row.iter().map(|&v| locale.format_number(v)))
+            .collect();
+        let _ = writeln!(out, "{}", cells.join(&delimiter));
+    }
+}
+
+/// Экспорт всех векторов и матриц результата эксперимента
+#[must_use]
+pub fn experiment_to_csv(result: &ExperimentResult, locale: CsvLocale) -> String {
+    let mut out = String::new();
+    let _ = writeln!(out, "seed{}{}", locale.delimiter(), result.seed);
+    out.push('\n');
+    write_vector(&mut out, "p(x_i)", "x", &result.input_probs, locale);
+
+    if !result.output_probs.is_empty() {
+        out.push('\n');
+        write_vector(&mut out, "p(y_j)", "y", &result.output_probs, locale);
+    }
+    if !result.transition_matrix.is_empty() {
+        out.push('\n');
+        write_matrix(
+            &mut out,
+            "p(y_j/x_i)",
+            &result.transition_matrix,
+            "x",
+            "y",
+            locale,
+        );
+    }
+    if !result.joint_probs.is_empty() {
+        out.push('\n');
+        write_matrix(
+            &mut out,
+            "p(x_i,y_j)",
+            &result.joint_probs,
+            "x",
+            "y",
+            locale,
+        );
+    }
+    if let Some(ref capacity) = result.channel_capacity {
+        out.push('\n');
+        let _ = writeln!(
+            out,
+            "C{}{}",
+            locale.delimiter(),
+            locale.format_number(capacity.capacity)
+        );
+        write_vector(
+            &mut out,
+            "p*(x_i)",
+            "x",
+            &capacity.input_distribution,
+            locale,
+        );
+    }
+    if !result.symbol_durations.is_empty() {
+        out.push('\n');
+        write_vector(&mut out, "T_i (мкс)", "T", &result.symbol_durations, locale);
+    }
+    out
+}
+
+/// Разбор числовой таблицы
+///
+/// Первая строка и первый столбец пропускаются, если содержат подписи (`x1`, `y1`, ...)
+///
+/// # Errors
+/// Возвращает описание ячейки, которую не удалось разобрать
+pub fn parse_table(text: &str, locale: CsvLocale) -> Result<Vec<Vec<f64>>, String> {
+    let rows: Vec<Vec<&str>> = text
+        .trim_start_matches('\u{feff}')
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty())
+        .map(|line| {
+            let mut cells: Vec<&str> = line.split(locale.delimiter()).map(str::trim).collect();
+            while cells.last().is_some_and(|cell| cell.is_empty()) {
+                cells.pop();
+            }
+            cells
+        })
+        .collect();
+
+    let is_number = |cell: &&str| locale.parse_number(cell).is_some();
+    let skip_row = usize::from(
+        rows.first()
+            .is_some_and(|row| row.iter().any(|cell| !is_number(cell))),
+    );
+    let data = &rows[skip_row.min(rows.len())..];
+    let skip_col = usize::from(
+        !data.is_empty()
+            && data
+                .iter()
+                .all(|row| row.first().is_some_and(|cell| !is_number(cell))),
+    );
+
+    if data.is_empty() {
+        return Err("файл не содержит числовых данных".to_string());
+    }
+
+    data.iter()
+        .enumerate()
+        .map(|(i, row)| {
+            row.iter()
+                .enumerate()
+                .skip(skip_col)
+                .map(|(j, cell)| {
+                    locale.parse_number(cell).ok_or_else(|| {
+                        format!(
+                            "строка {}, столбец {}: «{cell}» не является числом",
+                            i + skip_row + 1,
+                            j + 1
+                        )
+                    })
+                })
+                .collect()
+        })
+        .collect()
+}
+
+/// Разбор вектора, записанного одной строкой или одним столбцом
+///
+/// # Errors
+/// Возвращает ошибку, если таблица не является строкой или столбцом
+pub fn parse_vector(text: &str, locale: CsvLocale) -> Result<Vec<f64>, String> {
+    let table = parse_table(text, locale)?;
+    if table.len() == 1 || table.iter().all(|row| row.len() == 1) {
+        Ok(table.into_iter().flatten().collect())
+    } else {
+        Err("ожидается одна строка или один столбец значений".to_string())
+    }
+}
+
+/// Разбор квадратной матрицы
+///
+/// # Errors
+/// Возвращает ошибку, если матрица не квадратная
+pub fn parse_matrix(text: &str, locale: CsvLocale) -> Result<Vec<Vec<f64>>, String> {
+    let table = parse_table(text, locale)?;
+    let size = table.len();
+    if let Some((i, row)) = table.iter().enumerate().find(|(_, row)| row.len() != size) {
+        return Err(format!(
+            "матрица должна быть квадратной: в строке {} {} значений вместо {size}",
+            i + 1,
+            row.len()
+        ));
+    }
+    Ok(table)
+}
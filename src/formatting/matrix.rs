@@ -2,12 +2,16 @@ use super::probability::format_probability;
 use entropy_fx::coding::systematic::BinaryMatrix;
 
 /// Отображение бинарной матрицы в компактном виде
+///
+/// Разреженная матрица (`sparse`) выводится целиком: для каждой строки - номера
+/// столбцов с единицами, как в формате alist
 pub fn display_binary_matrix_compact(
     ui: &mut egui::Ui,
     matrix: &BinaryMatrix,
     title: &str,
     scroll_id: &str,
     grid_id: &str,
+    sparse: bool,
 ) {
     ui.label(egui::RichText::new(title).strong());
 
@@ -15,6 +19,10 @@ pub fn display_binary_matrix_compact(
     if n == 0 {
         return;
     }
+    if sparse {
+        display_sparse_rows(ui, matrix, scroll_id, grid_id);
+        return;
+    }
     let m = matrix[0].len();
     let show_rows = (n / 8).max(1);
     let show_cols = (m / 8).max(1);
@@ -63,6 +71,34 @@ pub fn display_binary_matrix_compact(
         });
 }
 
+/// Строки разреженной матрицы как списки позиций единиц (с 1)
+fn display_sparse_rows(ui: &mut egui::Ui, matrix: &BinaryMatrix, scroll_id: &str, grid_id: &str) {
+    egui::ScrollArea::vertical()
+        .id_salt(scroll_id)
+        .max_height(300.0)
+        .show(ui, |ui| {
+            egui::Grid::new(grid_id).striped(true).show(ui, |ui| {
+                ui.label(egui::RichText::new("").strong());
+                ui.label(egui::RichText::new("Вес").strong());
+                ui.label(egui::RichText::new("Столбцы с единицами").strong());
+                ui.end_row();
+
+                for (i, row) in matrix.iter().enumerate() {
+                    let ones: Vec<String> = row
+                        .iter()
+                        .enumerate()
+                        .filter(|(_, value)| **value)
+                        .map(|(j, _)| (j + 1).to_string())
+                        .collect();
+                    ui.label(egui::RichText::new(format!("{}", i + 1)).strong());
+                    ui.label(ones.len().to_string());
+                    ui.label(ones.join(", "));
+                    ui.end_row();
+                }
+            });
+        });
+}
+
 /// Отображение бинарной матрицы в полном виде
 pub fn display_binary_matrix_full(
     ui: &mut egui::Ui,
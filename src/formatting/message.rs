@@ -0,0 +1,135 @@
+use serde::{Deserialize, Serialize};
+
+/// Формат ввода сообщения для передачи через код
+#[derive(PartialEq, Eq, Clone, Copy, Debug, Default, Serialize, Deserialize)]
+pub enum MessageFormat {
+    /// Строка из `0` и `1`
+    Bits,
+    /// Шестнадцатеричные цифры
+    Hex,
+    /// Текст в кодировке UTF-8
+    #[default]
+    Text,
+}
+
+impl MessageFormat {
+    pub const ALL: [Self; 3] = [Self::Bits, Self::Hex, Self::Text];
+
+    #[must_use]
+    pub const fn label(self) -> &'static str {
+        match self {
+            Self::Bits => "Двоичная строка",
+            Self::Hex => "Шестнадцатеричная строка",
+            Self::Text => "Текст (UTF-8)",
+        }
+    }
+}
+
+/// Разбор введенного сообщения в последовательность битов
+pub fn parse_message(input: &str, format: MessageFormat) -> Result<Vec<bool>, String> {
+    let bits: Vec<bool> = match format {
+        MessageFormat::Bits => input
+            .chars()
+            .filter(|c| !c.is_whitespace())
+            .map(|c| match c {
+                '0' => Ok(false),
+                '1' => Ok(true),
+                _ => Err(format!("недопустимый символ '{c}' в двоичной строке")),
+            })
+            .collect::<Result<_, _>>()?,
+        MessageFormat::Hex => {
+            let digits: String = input.chars().filter(|c| !c.is_whitespace()).collect();
+            let digits = digits
+                .strip_prefix("0x")
+                .or_else(|| digits.strip_prefix("0X"))
+                .unwrap_or(&digits);
+            let mut bits = Vec::with_capacity(digits.len() * 4);
+            for c in digits.chars() {
+                let value = c.to_digit(16).ok_or_else(|| {
+                    format!("недопустимый символ '{c}' в шестнадцатеричной строке")
+                })?;
+                bits.extend((0..4).rev().map(|shift| (value >> shift) & 1 == 1));
+            }
+            bits
+        }
+        MessageFormat::Text => bytes_to_bits(input.as_bytes()),
+    };
+
+    if bits.is_empty() {
+        return Err("сообщение пусто".to_string());
+    }
+    Ok(bits)
+}
+
+/// Байты в биты (старший бит первым)
+#[must_use]
+pub fn bytes_to_bits(bytes: &[u8]) -> Vec<bool> {
+    bytes
+        .iter()
+        .flat_map(|&byte| (0..8).rev().map(move |shift| (byte >> shift) & 1 == 1))
+        .collect()
+}
+
+/// Биты в байты (старший бит первым); неполный последний байт дополняется нулями
+#[must_use]
+pub fn bits_to_bytes(bits: &[bool]) -> Vec<u8> {
+    bits.chunks(8)
+        .map(|chunk| {
+            chunk
+                .iter()
+                .enumerate()
+                .fold(0u8, |byte, (i, &bit)| byte | (u8::from(bit) << (7 - i)))
+        })
+        .collect()
+}
+
+/// Разбиение декодированного сообщения на отображаемые фрагменты
+///
+/// Фрагмент - бит, шестнадцатеричная цифра или символ текста; флаг отмечает фрагменты,
+/// которые отличаются от исходного сообщения
+#[must_use]
+pub fn message_segments(
+    decoded: &[bool],
+    original: &[bool],
+    format: MessageFormat,
+) -> Vec<(String, bool)> {
+    let differs = |from: usize, to: usize| (from..to).any(|i| decoded.get(i) != original.get(i));
+
+    match format {
+        MessageFormat::Bits => decoded
+            .iter()
+            .enumerate()
+            .map(|(i, &bit)| (if bit { "1" } else { "0" }.to_string(), differs(i, i + 1)))
+            .collect(),
+        MessageFormat::Hex => decoded
+            .chunks(4)
+            .enumerate()
+            .map(|(i, nibble)| {
+                let value = nibble
+                    .iter()
+                    .fold(0u32, |value, &bit| (value << 1) | u32::from(bit));
+                let digit = char::from_digit(value, 16)
+                    .unwrap_or('?')
+                    .to_ascii_uppercase();
+                (digit.to_string(), differs(i * 4, i * 4 + nibble.len()))
+            })
+            .collect(),
+        MessageFormat::Text => {
+            let bytes = bits_to_bytes(decoded);
+            let mut segments = vec![];
+            let mut offset = 0;
+            for chunk in bytes.utf8_chunks() {
+                for c in chunk.valid().chars() {
+                    let len = c.len_utf8();
+                    segments.push((c.to_string(), differs(offset * 8, (offset + len) * 8)));
+                    offset += len;
+                }
+                if !chunk.invalid().is_empty() {
+                    segments.push((char::REPLACEMENT_CHARACTER.to_string(), true));
+                    offset += chunk.invalid().len();
+                }
+            }
+            segments
+        }
+    }
+}
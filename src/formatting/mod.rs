@@ -1,5 +1,8 @@
 pub mod bits;
+pub mod csv;
 pub mod matrix;
+pub mod message;
+pub mod polynomial;
 pub mod probability;
 
 pub use bits::*;
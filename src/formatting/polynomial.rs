@@ -0,0 +1,139 @@
+use crate::coding::poly::trim;
+use serde::{Deserialize, Serialize};
+
+/// Запись многочлена в виде `x^3 + x + 1`
+#[must_use]
+pub fn format_polynomial(poly: &[bool]) -> String {
+    let terms: Vec<String> = (0..poly.len())
+        .rev()
+        .filter(|&i| poly[i])
+        .map(|i| match i {
+            0 => "1".to_string(),
+            1 => "x".to_string(),
+            _ => format!("x^{i}"),
+        })
+        .collect();
+    if terms.is_empty() {
+        "0".to_string()
+    } else {
+        terms.join(" + ")
+    }
+}
+
+/// Двоичная запись коэффициентов от старшего к младшему
+#[must_use]
+pub fn format_binary(poly: &[bool]) -> String {
+    let poly = trim(poly.to_vec());
+    if poly.is_empty() {
+        return "0".to_string();
+    }
+    poly.iter()
+        .rev()
+        .map(|&c| if c { '1' } else { '0' })
+        .collect()
+}
+
+/// Восьмеричная запись коэффициентов от старшего к младшему
+#[must_use]
+pub fn format_octal(poly: &[bool]) -> String {
+    let poly = trim(poly.to_vec());
+    if poly.is_empty() {
+        return "0".to_string();
+    }
+    poly.chunks(3)
+        .rev()
+        .map(|digit| {
+            let value = digit
+                .iter()
+                .enumerate()
+                .fold(0u32, |value, (i, &c)| value | (u32::from(c) << i));
+            char::from_digit(value, 8).unwrap_or('?')
+        })
+        .collect()
+}
+
+/// Способ записи многочлена
+#[derive(PartialEq, Eq, Clone, Copy, Debug, Default, Serialize, Deserialize)]
+pub enum PolynomialNotation {
+    /// Двоичная: `1011`
+    Binary,
+    /// Восьмеричная: `13`
+    Octal,
+    /// Алгебраическая: `x^3 + x + 1`
+    #[default]
+    Algebraic,
+}
+
+impl PolynomialNotation {
+    pub const ALL: [Self; 3] = [Self::Binary, Self::Octal, Self::Algebraic];
+
+    #[must_use]
+    pub const fn label(self) -> &'static str {
+        match self {
+            Self::Binary => "Двоичная (1011)",
+            Self::Octal => "Восьмеричная (13)",
+            Self::Algebraic => "Многочлен (x^3 + x + 1)",
+        }
+    }
+}
+
+/// Наибольшая степень слагаемого в алгебраической записи
+pub const MAX_PARSED_DEGREE: usize = 64;
+
+/// Разбор многочлена в заданной записи
+pub fn parse_polynomial(text: &str, notation: PolynomialNotation) -> Result<Vec<bool>, String> {
+    let text: String = text.chars().filter(|c| !c.is_whitespace()).collect();
+    if text.is_empty() {
+        return Err("многочлен не задан".to_string());
+    }
+    let poly = match notation {
+        PolynomialNotation::Binary => text
+            .chars()
+            .rev()
+            .map(|c| match c {
+                '0' => Ok(false),
+                '1' => Ok(true),
+                _ => Err(format!("недопустимый символ '{c}' в двоичной записи")),
+            })
+            .collect::<Result<Vec<_>, _>>()?,
+        PolynomialNotation::Octal => {
+            let mut poly = vec![];
+            for c in text.chars().rev() {
+                let digit = c
+                    .to_digit(8)
+                    .ok_or_else(|| format!("недопустимый символ '{c}' в восьмеричной записи"))?;
+                poly.extend((0..3).map(|i| (digit >> i) & 1 == 1));
+            }
+            poly
+        }
+        PolynomialNotation::Algebraic => {
+            let mut poly = vec![];
+            for term in text.split('+') {
+                let power = match term {
+                    "1" => 0,
+                    "x" | "X" => 1,
+                    _ => term
+                        .strip_prefix("x^")
+                        .or_else(|| term.strip_prefix("X^"))
+                        .and_then(|power| power.parse::<usize>().ok())
+                        .ok_or_else(|| format!("не удалось разобрать слагаемое '{term}'"))?,
+                };
+                if power > MAX_PARSED_DEGREE {
+                    return Err(format!(
+                        "степень слагаемого '{term}' больше {MAX_PARSED_DEGREE}"
+                    ));
+                }
+                if poly.len() <= power {
+                    poly.resize(power + 1, false);
+                }
+                poly[power] ^= true;
+            }
+            poly
+        }
+    };
+    let poly = trim(poly);
+    if poly.is_empty() {
+        return Err("многочлен равен нулю".to_string());
+    }
+    Ok(poly)
+}
@@ -1,5 +1,7 @@
 mod app;
+mod coding;
 mod formatting;
+mod session;
 mod state;
 mod ui;
 mod utils;
@@ -14,11 +16,12 @@ fn main() -> eframe::Result<()> {
             .with_visible(true)
             .with_resizable(true)
             .with_decorations(true),
+        persist_window: true,
         ..Default::default()
     };
     eframe::run_native(
         "Info Entropy Simulation",
         native_options,
-        Box::new(|_| Ok(Box::<InfoEntropyApp>::default())),
+        Box::new(|cc| Ok(Box::new(InfoEntropyApp::new(cc)))),
     )
 }
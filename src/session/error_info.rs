@@ -0,0 +1,100 @@
+use crate::coding::bch::BchErrorInfo;
+use crate::coding::convolutional::ViterbiErrorInfo;
+use crate::coding::golay::GolayErrorInfo;
+use crate::coding::ldpc::LdpcReport;
+use crate::coding::reed_solomon::RsErrorInfo;
+use crate::state::ErrorInfoType;
+use entropy_fx::coding::cyclic::CyclicErrorInfo;
+use entropy_fx::coding::hamming::HammingErrorInfo;
+use entropy_fx::coding::systematic::ErrorInfo;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+/// Сериализуемое представление результата декодирования
+#[derive(Serialize, Deserialize)]
+enum ErrorInfoRepr {
+    Systematic(Outcome),
+    Hamming(Outcome),
+    Cyclic(Outcome),
+    Bch(BchErrorInfo),
+    ReedSolomon(RsErrorInfo),
+    Convolutional(ViterbiErrorInfo),
+    Golay(GolayErrorInfo),
+    Ldpc(LdpcReport),
+}
+
+#[derive(Serialize, Deserialize)]
+enum Outcome {
+    NoError,
+    SingleError(usize),
+    DoubleError,
+    Uncorrectable,
+}
+
+impl From<&ErrorInfoType> for ErrorInfoRepr {
+    fn from(info: &ErrorInfoType) -> Self {
+        match info {
+            ErrorInfoType::Systematic(info) => Self::Systematic(match info {
+                ErrorInfo::NoError => Outcome::NoError,
+                ErrorInfo::SingleError(pos) => Outcome::SingleError(*pos),
+                ErrorInfo::Uncorrectable => Outcome::Uncorrectable,
+            }),
+            ErrorInfoType::Hamming(info) => Self::Hamming(match info {
+                HammingErrorInfo::NoError => Outcome::NoError,
+                HammingErrorInfo::SingleError(pos) => Outcome::SingleError(*pos),
+                HammingErrorInfo::DoubleError => Outcome::DoubleError,
+            }),
+            ErrorInfoType::Cyclic(info) => Self::Cyclic(match info {
+                CyclicErrorInfo::NoError => Outcome::NoError,
+                CyclicErrorInfo::SingleError(pos) => Outcome::SingleError(*pos),
+                CyclicErrorInfo::Uncorrectable => Outcome::Uncorrectable,
+            }),
+            ErrorInfoType::Bch(info) => Self::Bch(info.clone()),
+            ErrorInfoType::ReedSolomon(info) => Self::ReedSolomon(info.clone()),
+            ErrorInfoType::Convolutional(info) => Self::Convolutional(info.clone()),
+            ErrorInfoType::Golay(info) => Self::Golay(info.clone()),
+            ErrorInfoType::Ldpc(info) => Self::Ldpc(info.clone()),
+        }
+    }
+}
+
+impl From<ErrorInfoRepr> for ErrorInfoType {
+    fn from(repr: ErrorInfoRepr) -> Self {
+        match repr {
+            ErrorInfoRepr::Systematic(outcome) => Self::Systematic(match outcome {
+                Outcome::NoError => ErrorInfo::NoError,
+                Outcome::SingleError(pos) => ErrorInfo::SingleError(pos),
+                Outcome::DoubleError | Outcome::Uncorrectable => ErrorInfo::Uncorrectable,
+            }),
+            ErrorInfoRepr::Hamming(outcome) => Self::Hamming(match outcome {
+                Outcome::NoError => HammingErrorInfo::NoError,
+                Outcome::SingleError(pos) => HammingErrorInfo::SingleError(pos),
+                Outcome::DoubleError | Outcome::Uncorrectable => HammingErrorInfo::DoubleError,
+            }),
+            ErrorInfoRepr::Cyclic(outcome) => Self::Cyclic(match outcome {
+                Outcome::NoError => CyclicErrorInfo::NoError,
+                Outcome::SingleError(pos) => CyclicErrorInfo::SingleError(pos),
+                Outcome::DoubleError | Outcome::Uncorrectable => CyclicErrorInfo::Uncorrectable,
+            }),
+            ErrorInfoRepr::Bch(info) => Self::Bch(info),
+            ErrorInfoRepr::ReedSolomon(info) => Self::ReedSolomon(info),
+            ErrorInfoRepr::Convolutional(info) => Self::Convolutional(info),
+            ErrorInfoRepr::Golay(info) => Self::Golay(info),
+            ErrorInfoRepr::Ldpc(info) => Self::Ldpc(info),
+        }
+    }
+}
+
+/// Сериализация `ErrorInfoType` (типы `entropy_fx` не реализуют serde)
+pub fn serialize_error_info<S: Serializer>(
+    info: &ErrorInfoType,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    ErrorInfoRepr::from(info).serialize(serializer)
+}
+
+/// Десериализация `ErrorInfoType`
+pub fn deserialize_error_info<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<ErrorInfoType, D::Error> {
+    ErrorInfoRepr::deserialize(deserializer).map(Into::into)
+}
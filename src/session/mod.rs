@@ -0,0 +1,114 @@
+mod error_info;
+mod settings;
+mod snapshot;
+
+pub use error_info::{deserialize_error_info, serialize_error_info};
+pub use settings::*;
+pub use snapshot::*;
+
+use crate::app::LabMode;
+use crate::state::{Labs1To3State, Labs4To6ExperimentResult};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::fmt;
+use std::fs;
+use std::path::Path;
+
+/// Текущая версия схемы файла сессии
+pub const SESSION_VERSION: u64 = 1;
+
+/// Расширение файла сессии
+pub const SESSION_EXTENSION: &str = "json";
+
+/// Миграции схемы: `MIGRATIONS[i]` переводит сессию из версии `i + 1` в `i + 2`
+const MIGRATIONS: &[fn(&mut Value)] = &[];
+
+/// Файл сессии: режим, параметры, сгенерированные коды и результаты экспериментов
+#[derive(Serialize, Deserialize)]
+pub struct Session {
+    pub version: u64,
+    pub lab_mode: LabMode,
+    pub labs1to3_state: Labs1To3State,
+    pub code_config: CodeConfigSnapshot,
+    pub labs4to6_results: Vec<Labs4To6ExperimentResult>,
+}
+
+/// Ошибка чтения или записи сессии
+#[derive(Debug)]
+pub enum SessionError {
+    Io(std::io::Error),
+    Format(serde_json::Error),
+    MissingVersion,
+    UnsupportedVersion(u64),
+    /// Сохраненный код не проходит проверку
+    InvalidCode(String),
+}
+
+impl fmt::Display for SessionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Io(err) => write!(f, "ошибка ввода-вывода: {err}"),
+            Self::Format(err) => write!(f, "некорректный формат файла: {err}"),
+            Self::MissingVersion => write!(f, "в файле не указана версия сессии"),
+            Self::UnsupportedVersion(version) => write!(
+                f,
+                "версия сессии {version} новее поддерживаемой ({SESSION_VERSION})"
+            ),
+            Self::InvalidCode(err) => write!(f, "некорректный код в сессии: {err}"),
+        }
+    }
+}
+
+impl std::error::Error for SessionError {}
+
+impl From<std::io::Error> for SessionError {
+    fn from(err: std::io::Error) -> Self {
+        Self::Io(err)
+    }
+}
+
+impl From<serde_json::Error> for SessionError {
+    fn from(err: serde_json::Error) -> Self {
+        Self::Format(err)
+    }
+}
+
+/// Сохранение сессии в файл
+///
+/// # Errors
+/// Возвращает ошибку, если файл не удалось записать
+pub fn save_session(path: &Path, session: &Session) -> Result<(), SessionError> {
+    let text = serde_json::to_string_pretty(session)?;
+    fs::write(path, text)?;
+    Ok(())
+}
+
+/// Загрузка сессии из файла с миграцией старых версий схемы
+///
+/// # Errors
+/// Возвращает ошибку, если файл не удалось прочитать, он поврежден
+/// или создан более новой версией приложения
+pub fn load_session(path: &Path) -> Result<Session, SessionError> {
+    let text = fs::read_to_string(path)?;
+    let mut value: Value = serde_json::from_str(&text)?;
+    migrate(&mut value)?;
+    Ok(serde_json::from_value(value)?)
+}
+
+/// Последовательное применение миграций до текущей версии
+fn migrate(value: &mut Value) -> Result<(), SessionError> {
+    let version = value
+        .get("version")
+        .and_then(Value::as_u64)
+        .ok_or(SessionError::MissingVersion)?;
+    if version == 0 || version > SESSION_VERSION {
+        return Err(SessionError::UnsupportedVersion(version));
+    }
+
+    #[allow(clippy::cast_possible_truncation)]
+    for migration in &MIGRATIONS[(version - 1) as usize..] {
+        migration(value);
+    }
+    value["version"] = Value::from(SESSION_VERSION);
+    Ok(())
+}
@@ -0,0 +1,26 @@
+use super::CodeConfigSnapshot;
+use crate::app::LabMode;
+use crate::state::Labs1To3State;
+use serde::{Deserialize, Serialize};
+
+/// Настройки интерфейса, сохраняемые между запусками в хранилище eframe
+///
+/// В отличие от файла сессии здесь нет результатов экспериментов и сгенерированных кодов:
+/// восстанавливаются только режим работы, параметры и переключатели вида
+#[derive(Serialize, Deserialize)]
+#[serde(default)]
+pub struct UiSettings {
+    pub lab_mode: LabMode,
+    pub labs1to3_state: Labs1To3State,
+    pub code_config: Option<CodeConfigSnapshot>,
+}
+
+impl Default for UiSettings {
+    fn default() -> Self {
+        Self {
+            lab_mode: LabMode::Labs1To3,
+            labs1to3_state: Labs1To3State::default(),
+            code_config: None,
+        }
+    }
+}
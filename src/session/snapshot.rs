@@ -0,0 +1,177 @@
+use crate::coding::bch::BchParameters;
+use crate::coding::convolutional::ConvolutionalParameters;
+use crate::coding::cyclic::{CyclicCode, CyclicDecoder};
+use crate::coding::ldpc::LdpcParameters;
+use crate::coding::linear::LinearCode;
+use crate::coding::reed_solomon::RsParameters;
+use crate::state::channel::ChannelSettings;
+use crate::state::custom_code::{GeneratorEditor, PolynomialEditor};
+use crate::state::message::MessageInput;
+use crate::state::{CodeConfig, SelectedCodeType};
+use crate::utils::ber::{BerPoint, BerSettings};
+use entropy_fx::coding::systematic::{BinaryMatrix, SystematicCode};
+use serde::{Deserialize, Serialize};
+
+/// Сохраняемое состояние конфигурации кода
+#[derive(Serialize, Deserialize)]
+pub struct CodeConfigSnapshot {
+    pub code_type: SelectedCodeType,
+    pub k: usize,
+    pub experiments: usize,
+    pub error_probability: f64,
+    pub compact_view: bool,
+    pub seed: u64,
+    pub randomize_seed: bool,
+    #[serde(default)]
+    pub channel: ChannelSettings,
+    #[serde(default)]
+    pub message_input: MessageInput,
+    #[serde(default)]
+    pub ber_settings: BerSettings,
+    #[serde(default)]
+    pub ber_results: Vec<BerPoint>,
+    #[serde(default)]
+    pub generator_editor: GeneratorEditor,
+    #[serde(default)]
+    pub custom_code: Option<LinearCode>,
+    #[serde(default)]
+    pub polynomial_editor: PolynomialEditor,
+    #[serde(default)]
+    pub cyclic_generator: Option<Vec<bool>>,
+    #[serde(default)]
+    pub cyclic_decoder: CyclicDecoder,
+    #[serde(default)]
+    pub bch_parameters: BchParameters,
+    #[serde(default)]
+    pub rs_parameters: RsParameters,
+    #[serde(default)]
+    pub convolutional_parameters: ConvolutionalParameters,
+    #[serde(default)]
+    pub ldpc_parameters: LdpcParameters,
+    pub systematic_code: Option<SystematicCodeSnapshot>,
+    pub cyclic_code: Option<CyclicCodeSnapshot>,
+}
+
+/// Матрицы систематического кода
+#[derive(Serialize, Deserialize)]
+pub struct SystematicCodeSnapshot {
+    pub k: usize,
+    pub n: usize,
+    pub p: usize,
+    pub generator: BinaryMatrix,
+    pub parity_check: BinaryMatrix,
+}
+
+/// Порождающий многочлен циклического кода
+///
+/// При загрузке код строится заново через [`CyclicCode::new`], таблица синдромов
+/// вычисляется по g(x). Если многочлен не сохранен (сессия до выбора g(x)), код
+/// строится по k и выбранному порождающему многочлену
+#[derive(Serialize, Deserialize)]
+pub struct CyclicCodeSnapshot {
+    pub n: usize,
+    pub p: usize,
+    #[serde(default)]
+    pub generator: Vec<bool>,
+}
+
+impl From<&CodeConfig> for CodeConfigSnapshot {
+    fn from(config: &CodeConfig) -> Self {
+        Self {
+            code_type: config.code_type,
+            k: config.k,
+            experiments: config.experiments,
+            error_probability: config.error_probability,
+            compact_view: config.compact_view,
+            seed: config.seed,
+            randomize_seed: config.randomize_seed,
+            channel: config.channel.clone(),
+            message_input: config.message_input.clone(),
+            ber_settings: config.ber_settings.clone(),
+            ber_results: config.ber_results.clone(),
+            generator_editor: config.generator_editor.clone(),
+            custom_code: config.custom_code.clone(),
+            polynomial_editor: config.polynomial_editor.clone(),
+            cyclic_generator: config.cyclic_generator.clone(),
+            cyclic_decoder: config.cyclic_decoder,
+            bch_parameters: config.bch_parameters,
+            rs_parameters: config.rs_parameters,
+            convolutional_parameters: config.convolutional_parameters.clone(),
+            ldpc_parameters: config.ldpc_parameters.clone(),
+            systematic_code: config
+                .systematic_code
+                .as_ref()
+                .map(|code| SystematicCodeSnapshot {
+                    k: code.k,
+                    n: code.n,
+                    p: code.p,
+                    generator: code.generator.clone(),
+                    parity_check: code.parity_check.clone(),
+                }),
+            cyclic_code: config.cyclic_code.as_ref().map(|code| CyclicCodeSnapshot {
+                n: code.n,
+                p: code.p,
+                generator: code.generator.clone(),
+            }),
+        }
+    }
+}
+
+impl TryFrom<CodeConfigSnapshot> for CodeConfig {
+    type Error = String;
+
+    /// Восстановление конфигурации; сохраненный циклический код проверяется заново
+    fn try_from(snapshot: CodeConfigSnapshot) -> Result<Self, Self::Error> {
+        let mut config = Self::new(snapshot.k);
+        config.bch_parameters = snapshot.bch_parameters;
+        config.rs_parameters = snapshot.rs_parameters;
+        config.convolutional_parameters = snapshot.convolutional_parameters;
+        config.ldpc_parameters = snapshot.ldpc_parameters;
+        config.set_code_type(snapshot.code_type);
+        config.update_n_and_p();
+        config.experiments = snapshot.experiments;
+        config.error_probability = snapshot.error_probability;
+        config.compact_view = snapshot.compact_view;
+        config.seed = snapshot.seed;
+        config.randomize_seed = snapshot.randomize_seed;
+        config.channel = snapshot.channel;
+        config.message_input = snapshot.message_input;
+        config.ber_settings = snapshot.ber_settings;
+        config.ber_results = snapshot.ber_results;
+
+        if snapshot.code_type == SelectedCodeType::Systematic
+            && let Some(code) = snapshot.systematic_code
+        {
+            config.systematic_code = Some(SystematicCode {
+                k: code.k,
+                n: code.n,
+                p: code.p,
+                generator: code.generator,
+                parity_check: code.parity_check,
+            });
+        }
+        config.generator_editor = snapshot.generator_editor;
+        if snapshot.code_type == SelectedCodeType::Systematic
+            && let Some(code) = snapshot.custom_code
+        {
+            config.set_custom_code(code);
+        }
+        config.polynomial_editor = snapshot.polynomial_editor;
+        config.cyclic_generator = snapshot.cyclic_generator;
+        config.cyclic_decoder = snapshot.cyclic_decoder;
+        if snapshot.code_type == SelectedCodeType::Cyclic
+            && let Some(code) = snapshot.cyclic_code
+            && !code.generator.is_empty()
+        {
+            if code.n != config.n || code.p != config.p {
+                return Err(format!(
+                    "циклический код с n = {}, p = {} не соответствует k = {} (n = {}, p = {})",
+                    code.n, code.p, config.k, config.n, config.p
+                ));
+            }
+            config.cyclic_code = Some(CyclicCode::new(config.k, code.n, code.generator)?);
+        }
+        config.ensure_code_initialized();
+        Ok(config)
+    }
+}
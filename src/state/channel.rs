@@ -0,0 +1,108 @@
+use serde::{Deserialize, Serialize};
+
+/// Модель канала для экспериментов Labs 4-6
+#[derive(PartialEq, Eq, Clone, Copy, Debug, Default, Serialize, Deserialize)]
+pub enum ChannelModel {
+    /// Одиночная ошибка с заданной вероятностью (для кода Хэмминга - случайная кратность 0-2)
+    #[default]
+    Classic,
+    /// Двоичный симметричный канал: каждый бит инвертируется независимо
+    Bsc,
+    /// Пакет ошибок заданной длины
+    Burst,
+    /// Двухсостоянный канал Гилберта–Эллиота
+    GilbertElliott,
+}
+
+impl ChannelModel {
+    pub const ALL: [Self; 4] = [Self::Classic, Self::Bsc, Self::Burst, Self::GilbertElliott];
+
+    #[must_use]
+    pub const fn label(self) -> &'static str {
+        match self {
+            Self::Classic => "Одиночная ошибка",
+            Self::Bsc => "Двоичный симметричный канал",
+            Self::Burst => "Пакет ошибок",
+            Self::GilbertElliott => "Канал Гилберта–Эллиота",
+        }
+    }
+}
+
+/// Настройки канала для экспериментов Labs 4-6
+#[derive(Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ChannelSettings {
+    pub model: ChannelModel,
+    /// Вероятность инверсии бита в двоичном симметричном канале
+    pub bit_error_probability: f64,
+    /// Длина пакета ошибок b
+    pub burst_length: usize,
+    /// Случайная позиция начала пакета
+    pub random_burst_start: bool,
+    /// Позиция начала пакета (с 1)
+    pub burst_start: usize,
+    pub gilbert_elliott: GilbertElliott,
+}
+
+impl Default for ChannelSettings {
+    fn default() -> Self {
+        Self {
+            model: ChannelModel::Classic,
+            bit_error_probability: 0.02,
+            burst_length: 3,
+            random_burst_start: true,
+            burst_start: 1,
+            gilbert_elliott: GilbertElliott::default(),
+        }
+    }
+}
+
+/// Параметры канала Гилберта–Эллиота
+///
+/// В «хорошем» состоянии ошибки редки, в «плохом» - часты, поэтому ошибки группируются в пакеты
+#[derive(Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GilbertElliott {
+    /// Вероятность перехода из хорошего состояния в плохое
+    pub good_to_bad: f64,
+    /// Вероятность перехода из плохого состояния в хорошее
+    pub bad_to_good: f64,
+    /// Вероятность ошибки в хорошем состоянии
+    pub good_error_probability: f64,
+    /// Вероятность ошибки в плохом состоянии
+    pub bad_error_probability: f64,
+}
+
+impl Default for GilbertElliott {
+    fn default() -> Self {
+        Self {
+            good_to_bad: 0.01,
+            bad_to_good: 0.3,
+            good_error_probability: 0.001,
+            bad_error_probability: 0.5,
+        }
+    }
+}
+
+impl GilbertElliott {
+    /// Стационарная вероятность плохого состояния
+    #[must_use]
+    pub fn bad_state_probability(&self) -> f64 {
+        let total = self.good_to_bad + self.bad_to_good;
+        if total > 0.0 {
+            self.good_to_bad / total
+        } else {
+            0.0
+        }
+    }
+
+    /// Средняя вероятность ошибки на бит
+    #[must_use]
+    pub fn average_error_probability(&self) -> f64 {
+        let bad = self.bad_state_probability();
+        bad.mul_add(
+            self.bad_error_probability,
+            (1.0 - bad) * self.good_error_probability,
+        )
+    }
+}
@@ -1,13 +1,93 @@
-use entropy_fx::coding::cyclic::CyclicCode;
-use entropy_fx::coding::hamming::HammingCode;
-use entropy_fx::coding::systematic::SystematicCode;
+use crate::coding::bch::{BchCode, BchErrorInfo, BchParameters};
+use crate::coding::convolutional::{ConvolutionalCode, ConvolutionalParameters, ViterbiErrorInfo};
+use crate::coding::cyclic::{CyclicCode, CyclicDecoder};
+use crate::coding::golay::{GolayCode, GolayErrorInfo};
+use crate::coding::ldpc::{LdpcCode, LdpcDecoding, LdpcErrorInfo, LdpcParameters, LdpcReport};
+use crate::coding::linear::LinearCode;
+use crate::coding::poly::degree;
+use crate::coding::reed_solomon::{
+    ReedSolomonCode, RsErrorInfo, RsParameters, bits_to_symbols, symbols_to_bits,
+};
+use crate::state::channel::{ChannelModel, ChannelSettings};
+use crate::state::custom_code::{GeneratorEditor, PolynomialEditor};
+use crate::state::lfsr::LfsrAnimation;
+use crate::state::message::{FileTransfer, MessageInput};
+use crate::utils::ber::{BerJob, BerPoint, BerSettings};
+use crate::utils::code_analysis::CodeAnalysis;
+use crate::utils::coverage::CoverageReport;
+use crate::utils::random::{
+    erase_symbols, generate_error_multiplicity, inject_burst, inject_errors, inject_single_error,
+    inject_symbol_errors, transmit_bsc, transmit_gilbert_elliott,
+};
+use crate::utils::transmission::select_bits;
+use entropy_fx::coding::hamming::{
+    HammingCode, add_parity_bit, compute_syndrome_hamming, decode_hamming, encode_hamming,
+};
+use entropy_fx::coding::systematic::{
+    SystematicCode, compute_syndrome, correct_error, encode_message,
+};
+use rand::Rng;
+use serde::{Deserialize, Serialize};
 
 /// Тип выбранного кода
-#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+#[derive(PartialEq, Eq, Clone, Copy, Debug, Serialize, Deserialize)]
 pub enum SelectedCodeType {
     Systematic,
     Hamming,
     Cyclic,
+    Bch,
+    ReedSolomon,
+    Convolutional,
+    Golay,
+    ExtendedGolay,
+    Ldpc,
+}
+
+impl SelectedCodeType {
+    pub const ALL: [Self; 9] = [
+        Self::Systematic,
+        Self::Hamming,
+        Self::Cyclic,
+        Self::Bch,
+        Self::ReedSolomon,
+        Self::Convolutional,
+        Self::Golay,
+        Self::ExtendedGolay,
+        Self::Ldpc,
+    ];
+
+    pub const fn label(self) -> &'static str {
+        match self {
+            Self::Systematic => "Систематический код",
+            Self::Hamming => "Код Хемминга",
+            Self::Cyclic => "Циклический код",
+            Self::Bch => "Код БЧХ",
+            Self::ReedSolomon => "Код Рида–Соломона",
+            Self::Convolutional => "Сверточный код",
+            Self::Golay => "Код Голея (23, 12)",
+            Self::ExtendedGolay => "Расширенный код Голея (24, 12)",
+            Self::Ldpc => "LDPC-код",
+        }
+    }
+
+    /// n и p вычисляются по собственным параметрам кода (для всех кодов, кроме
+    /// сверточного, - и k)
+    pub const fn has_own_parameters(self) -> bool {
+        matches!(
+            self,
+            Self::Bch
+                | Self::ReedSolomon
+                | Self::Convolutional
+                | Self::Golay
+                | Self::ExtendedGolay
+                | Self::Ldpc
+        )
+    }
+
+    /// Код Голея: обычный или расширенный
+    pub const fn is_golay(self) -> bool {
+        matches!(self, Self::Golay | Self::ExtendedGolay)
+    }
 }
 
 /// Конфигурация кода для Labs 4-6
@@ -19,9 +99,47 @@ pub struct CodeConfig {
     pub experiments: usize,
     pub error_probability: f64,
     pub compact_view: bool,
+    pub seed: u64,
+    pub randomize_seed: bool,
+    pub channel: ChannelSettings,
+    pub ber_settings: BerSettings,
+    pub ber_results: Vec<BerPoint>,
+    /// Идущее в фоновом потоке моделирование BER/WER
+    pub ber_job: Option<BerJob>,
+    pub manual_injection: ManualInjection,
+    pub lfsr: LfsrAnimation,
+    pub message_input: MessageInput,
+    pub file_transfer: FileTransfer,
+    /// Число случайных кодовых слов для анализа при большом `k`
+    pub analysis_samples: usize,
+    /// Результат анализа весов и тип кода, для которого он получен
+    pub analysis: Option<(SelectedCodeType, CodeAnalysis)>,
+    /// Включать тройные ошибки в отчет о покрытии
+    pub coverage_weight3: bool,
+    /// Отчет о покрытии шаблонов ошибок и тип кода, для которого он получен
+    pub coverage: Option<(SelectedCodeType, CoverageReport)>,
+    pub generator_editor: GeneratorEditor,
+    /// Систематический код с порождающей матрицей, заданной пользователем
+    pub custom_code: Option<LinearCode>,
+    pub polynomial_editor: PolynomialEditor,
+    /// Порождающий многочлен циклического кода, выбранный пользователем
+    pub cyclic_generator: Option<Vec<bool>>,
+    pub cyclic_decoder: CyclicDecoder,
+    pub bch_parameters: BchParameters,
+    pub rs_parameters: RsParameters,
+    pub convolutional_parameters: ConvolutionalParameters,
+    pub ldpc_parameters: LdpcParameters,
+    /// Итог последней загрузки матрицы H из файла alist
+    pub ldpc_status: Option<Result<String, String>>,
     pub systematic_code: Option<SystematicCode>,
     pub hamming_code: Option<HammingCode>,
     pub cyclic_code: Option<CyclicCode>,
+    pub bch_code: Option<BchCode>,
+    pub rs_code: Option<ReedSolomonCode>,
+    pub convolutional_code: Option<ConvolutionalCode>,
+    /// Код Голея выбранного вида (обычный или расширенный)
+    pub golay_code: Option<GolayCode>,
+    pub ldpc_code: Option<LdpcCode>,
 }
 
 impl CodeConfig {
@@ -35,37 +153,114 @@ impl CodeConfig {
             experiments: 6,
             error_probability: 0.5,
             compact_view: false,
+            seed: 0,
+            randomize_seed: true,
+            channel: ChannelSettings::default(),
+            ber_settings: BerSettings::default(),
+            ber_results: vec![],
+            ber_job: None,
+            manual_injection: ManualInjection::default(),
+            lfsr: LfsrAnimation::default(),
+            message_input: MessageInput::default(),
+            file_transfer: FileTransfer::default(),
+            analysis_samples: 100_000,
+            analysis: None,
+            coverage_weight3: false,
+            coverage: None,
+            generator_editor: GeneratorEditor::default(),
+            custom_code: None,
+            polynomial_editor: PolynomialEditor::default(),
+            cyclic_generator: None,
+            cyclic_decoder: CyclicDecoder::default(),
+            bch_parameters: BchParameters::default(),
+            rs_parameters: RsParameters::default(),
+            convolutional_parameters: ConvolutionalParameters::default(),
+            ldpc_parameters: LdpcParameters::default(),
+            ldpc_status: None,
             systematic_code: None,
             hamming_code: None,
             cyclic_code: None,
+            bch_code: None,
+            rs_code: None,
+            convolutional_code: None,
+            golay_code: None,
+            ldpc_code: None,
         }
     }
 
     /// Установить тип кода и очистить другие типы
     pub fn set_code_type(&mut self, code_type: SelectedCodeType) {
+        // Параметры кодов БЧХ и Рида–Соломона задаются отдельно, поэтому n, k и p пересчитываются
+        let own_parameters = self.code_type != code_type
+            && (self.code_type.has_own_parameters() || code_type.has_own_parameters());
         self.code_type = code_type;
-        match code_type {
-            SelectedCodeType::Systematic => {
-                self.hamming_code = None;
-                self.cyclic_code = None;
-            }
-            SelectedCodeType::Hamming => {
-                self.systematic_code = None;
-                self.cyclic_code = None;
-            }
-            SelectedCodeType::Cyclic => {
-                self.systematic_code = None;
-                self.hamming_code = None;
-            }
+        if own_parameters || code_type != SelectedCodeType::Systematic && self.custom_code.is_some()
+        {
+            self.update_n_and_p();
+        }
+        if code_type != SelectedCodeType::Systematic {
+            self.systematic_code = None;
+        }
+        if code_type != SelectedCodeType::Hamming {
+            self.hamming_code = None;
+        }
+        if code_type != SelectedCodeType::Cyclic {
+            self.cyclic_code = None;
+        }
+        if code_type != SelectedCodeType::Bch {
+            self.bch_code = None;
+        }
+        if code_type != SelectedCodeType::ReedSolomon {
+            self.rs_code = None;
+        }
+        if code_type != SelectedCodeType::Convolutional {
+            self.convolutional_code = None;
+        }
+        if !code_type.is_golay() {
+            self.golay_code = None;
+        }
+        if code_type != SelectedCodeType::Ldpc {
+            self.ldpc_code = None;
         }
     }
 
     /// Проверить, можно ли запустить эксперименты
     pub const fn can_run_experiments(&self) -> bool {
         match self.code_type {
-            SelectedCodeType::Systematic => self.systematic_code.is_some(),
+            SelectedCodeType::Systematic => {
+                self.systematic_code.is_some() || self.custom_code.is_some()
+            }
             SelectedCodeType::Hamming => self.hamming_code.is_some(),
             SelectedCodeType::Cyclic => self.cyclic_code.is_some(),
+            SelectedCodeType::Bch => self.bch_code.is_some(),
+            SelectedCodeType::ReedSolomon => self.rs_code.is_some(),
+            SelectedCodeType::Convolutional => self.convolutional_code.is_some(),
+            SelectedCodeType::Golay | SelectedCodeType::ExtendedGolay => self.golay_code.is_some(),
+            SelectedCodeType::Ldpc => self.ldpc_code.is_some(),
+        }
+    }
+
+    /// Число ошибок в блоке, которое выбранный код исправляет гарантированно
+    ///
+    /// Для кода Рида–Соломона - число символов, то есть не меньше стольких же битовых ошибок.
+    /// `None` для кодов без известного блочного t: сверточного (исправляемые ошибки
+    /// определяются расстоянием по решетке, а не блоком) и LDPC (итеративный декодер),
+    /// а также для неинициализированного кода БЧХ или Рида–Соломона
+    pub const fn correctable_errors(&self) -> Option<usize> {
+        match self.code_type {
+            SelectedCodeType::Bch => match self.bch_code {
+                Some(ref code) => Some(code.t),
+                None => None,
+            },
+            SelectedCodeType::ReedSolomon => match self.rs_code {
+                Some(ref code) => Some(code.t()),
+                None => None,
+            },
+            SelectedCodeType::Convolutional | SelectedCodeType::Ldpc => None,
+            SelectedCodeType::Golay | SelectedCodeType::ExtendedGolay => Some(GolayCode::T),
+            SelectedCodeType::Systematic | SelectedCodeType::Hamming | SelectedCodeType::Cyclic => {
+                Some(1)
+            }
         }
     }
 
@@ -78,21 +273,483 @@ impl CodeConfig {
             SelectedCodeType::Cyclic | SelectedCodeType::Systematic => {
                 entropy_fx::coding::common::compute_n_from_k(self.k)
             }
+            SelectedCodeType::Bch => match self.bch_parameters.build() {
+                Ok(code) => {
+                    self.k = code.cyclic.k;
+                    (code.cyclic.n, code.cyclic.p)
+                }
+                Err(_) => (self.n, self.p),
+            },
+            SelectedCodeType::ReedSolomon => match self.rs_parameters.build() {
+                Ok(code) => {
+                    self.k = code.k * code.m();
+                    (code.n * code.m(), code.parity_symbols() * code.m())
+                }
+                Err(_) => (self.n, self.p),
+            },
+            SelectedCodeType::Convolutional => match self.convolutional_parameters.build() {
+                Ok(code) => {
+                    let n = code.encoded_length(self.k);
+                    (n, n - self.k)
+                }
+                Err(_) => (self.n, self.p),
+            },
+            SelectedCodeType::Golay | SelectedCodeType::ExtendedGolay => {
+                self.k = GolayCode::K;
+                let n = if self.code_type == SelectedCodeType::ExtendedGolay {
+                    24
+                } else {
+                    23
+                };
+                (n, n - GolayCode::K)
+            }
+            SelectedCodeType::Ldpc => match self.ldpc_parameters.build() {
+                Ok(code) => {
+                    self.k = code.linear.k;
+                    (code.linear.n, code.linear.p())
+                }
+                Err(_) => (self.n, self.p),
+            },
         };
         self.n = n;
         self.p = p;
+        self.custom_code = None;
+        if self.cyclic_generator.as_deref().and_then(degree) != Some(self.p) {
+            self.cyclic_generator = None;
+        }
         // Инвалидировать коды при изменении параметров
         match self.code_type {
             SelectedCodeType::Systematic => self.systematic_code = None,
             SelectedCodeType::Hamming => self.hamming_code = None,
             SelectedCodeType::Cyclic => self.cyclic_code = None,
+            SelectedCodeType::Bch => self.bch_code = None,
+            SelectedCodeType::ReedSolomon => self.rs_code = None,
+            SelectedCodeType::Convolutional => self.convolutional_code = None,
+            SelectedCodeType::Golay | SelectedCodeType::ExtendedGolay => self.golay_code = None,
+            SelectedCodeType::Ldpc => self.ldpc_code = None,
+        }
+    }
+
+    /// Использовать порождающую матрицу, заданную пользователем
+    pub fn set_custom_code(&mut self, code: LinearCode) {
+        self.k = code.k;
+        self.n = code.n;
+        self.p = code.p();
+        self.custom_code = Some(code);
+        self.analysis = None;
+        self.coverage = None;
+    }
+
+    /// Вернуться к сгенерированной порождающей матрице
+    pub fn clear_custom_code(&mut self) {
+        self.update_n_and_p();
+        self.analysis = None;
+        self.coverage = None;
+    }
+
+    /// Использовать порождающий многочлен циклического кода (`None` - многочлен по умолчанию)
+    pub fn set_cyclic_generator(&mut self, generator: Option<Vec<bool>>) {
+        self.cyclic_generator = generator;
+        self.cyclic_code = None;
+        self.analysis = None;
+        self.coverage = None;
+        self.ensure_code_initialized();
+    }
+
+    /// Кодирование сообщения выбранным кодом
+    ///
+    /// Возвращает передаваемое в канал слово: для кода Хэмминга - вместе с parity bit
+    pub fn encode(&self, message: &[bool]) -> Option<Vec<bool>> {
+        match self.code_type {
+            SelectedCodeType::Systematic => {
+                if let Some(ref code) = self.custom_code {
+                    return Some(code.encode(message));
+                }
+                self.systematic_code
+                    .as_ref()
+                    .map(|code| encode_message(message, code))
+            }
+            SelectedCodeType::Hamming => self
+                .hamming_code
+                .as_ref()
+                .map(|code| add_parity_bit(&encode_hamming(message, code))),
+            SelectedCodeType::Cyclic => self.cyclic_code.as_ref().map(|code| code.encode(message)),
+            SelectedCodeType::Bch => self
+                .bch_code
+                .as_ref()
+                .map(|code| code.cyclic.encode(message)),
+            SelectedCodeType::ReedSolomon => self.rs_code.as_ref().map(|code| code.encode(message)),
+            SelectedCodeType::Convolutional => self
+                .convolutional_code
+                .as_ref()
+                .map(|code| code.encode(message)),
+            SelectedCodeType::Golay | SelectedCodeType::ExtendedGolay => self
+                .golay_code
+                .as_ref()
+                .map(|code| code.linear.encode(message)),
+            SelectedCodeType::Ldpc => self
+                .ldpc_code
+                .as_ref()
+                .map(|code| code.linear.encode(message)),
+        }
+    }
+
+    /// Передача слова через выбранную модель канала
+    ///
+    /// Возвращает принятое слово и позиции ошибок (с 1)
+    pub fn transmit<R: Rng>(&self, codeword: &[bool], rng: &mut R) -> (Vec<bool>, Vec<usize>) {
+        match self.channel.model {
+            ChannelModel::Classic if self.code_type == SelectedCodeType::Hamming => {
+                let multiplicity = generate_error_multiplicity(rng);
+                inject_errors(codeword, multiplicity, rng)
+            }
+            // Для кода Голея кратность 0..=3, для расширенного - до 4 (обнаруживаемая ошибка)
+            ChannelModel::Classic if self.code_type.is_golay() => {
+                let extended = usize::from(self.code_type == SelectedCodeType::ExtendedGolay);
+                let multiplicity = rng.random_range(0..=GolayCode::T + extended);
+                inject_errors(codeword, multiplicity, rng)
+            }
+            ChannelModel::Classic if self.code_type == SelectedCodeType::ReedSolomon => {
+                // Позиции ошибок - все искаженные разряды искаженных символов
+                let m = self
+                    .rs_code
+                    .as_ref()
+                    .map_or(self.rs_parameters.m, ReedSolomonCode::m);
+                let (received, _) =
+                    inject_symbol_errors(codeword, m, self.rs_parameters.symbol_errors, rng);
+                let positions = (1..=codeword.len())
+                    .filter(|&i| received[i - 1] != codeword[i - 1])
+                    .collect();
+                (received, positions)
+            }
+            ChannelModel::Classic => {
+                let (received, position) =
+                    inject_single_error(codeword, self.error_probability, rng);
+                (received, position.map(|p| vec![p + 1]).unwrap_or_default())
+            }
+            ChannelModel::Bsc => {
+                let (received, positions) =
+                    transmit_bsc(codeword, self.channel.bit_error_probability, rng);
+                (received, positions.into_iter().map(|p| p + 1).collect())
+            }
+            ChannelModel::Burst => {
+                let start = (!self.channel.random_burst_start)
+                    .then(|| self.channel.burst_start.saturating_sub(1));
+                let (received, positions) =
+                    inject_burst(codeword, self.channel.burst_length, start, rng);
+                (received, positions.into_iter().map(|p| p + 1).collect())
+            }
+            ChannelModel::GilbertElliott => {
+                let (received, positions) =
+                    transmit_gilbert_elliott(codeword, &self.channel.gilbert_elliott, rng);
+                (received, positions.into_iter().map(|p| p + 1).collect())
+            }
         }
     }
 
+    /// Стирание символов принятого слова для кода Рида–Соломона
+    ///
+    /// Возвращает номера стертых символов (с 1); для остальных кодов стираний нет
+    pub fn erase<R: Rng>(&self, received: &mut [bool], rng: &mut R) -> Vec<usize> {
+        match (self.code_type, &self.rs_code) {
+            (SelectedCodeType::ReedSolomon, Some(code)) => {
+                erase_symbols(received, code.m(), self.rs_parameters.erasures, rng)
+                    .into_iter()
+                    .map(|p| p + 1)
+                    .collect()
+            }
+            _ => vec![],
+        }
+    }
+
+    /// Декодирование принятого слова выбранным кодом
+    pub fn decode(&self, received: &[bool]) -> Option<(Vec<bool>, ErrorInfoType)> {
+        self.decode_with_erasures(received, &[])
+    }
+
+    /// Декодирование с известными позициями стертых символов (с 1)
+    ///
+    /// Стирания учитывает только код Рида–Соломона; `None`, если код не инициализирован
+    /// или номера стертых символов некорректны
+    pub fn decode_with_erasures(
+        &self,
+        received: &[bool],
+        erasures: &[usize],
+    ) -> Option<(Vec<bool>, ErrorInfoType)> {
+        match self.code_type {
+            SelectedCodeType::Systematic => {
+                let (corrected, info) = match self.custom_code {
+                    Some(ref code) => code.correct_error(received),
+                    None => correct_error(&self.systematic_code.as_ref()?.parity_check, received),
+                };
+                Some((corrected, ErrorInfoType::Systematic(info)))
+            }
+            SelectedCodeType::Hamming => self.hamming_code.as_ref().map(|code| {
+                let (corrected, info) = decode_hamming(received, code, true);
+                (corrected, ErrorInfoType::Hamming(info))
+            }),
+            SelectedCodeType::Cyclic => self.cyclic_code.as_ref().map(|code| {
+                let (corrected, info) = code.decode_with(received, self.cyclic_decoder);
+                (corrected, ErrorInfoType::Cyclic(info))
+            }),
+            SelectedCodeType::Bch => self.bch_code.as_ref().map(|code| {
+                let decoding = code.decode(received);
+                (decoding.corrected, ErrorInfoType::Bch(decoding.info))
+            }),
+            SelectedCodeType::ReedSolomon => {
+                let (corrected, decoding) =
+                    self.rs_code.as_ref()?.decode(received, erasures).ok()?;
+                Some((corrected, ErrorInfoType::ReedSolomon(decoding.info)))
+            }
+            // Скорректированное слово - выживший путь, закодированный заново
+            SelectedCodeType::Convolutional => self.convolutional_code.as_ref().map(|code| {
+                let decoding = code.decode(received);
+                let info = match decoding.distance {
+                    0 => ViterbiErrorInfo::NoError,
+                    distance => ViterbiErrorInfo::Corrected(distance),
+                };
+                (
+                    code.encode(&decoding.message),
+                    ErrorInfoType::Convolutional(info),
+                )
+            }),
+            SelectedCodeType::Golay | SelectedCodeType::ExtendedGolay => {
+                self.golay_code.as_ref().map(|code| {
+                    let (corrected, info) = code.decode(received);
+                    (corrected, ErrorInfoType::Golay(info))
+                })
+            }
+            SelectedCodeType::Ldpc => self.ldpc_code.as_ref().map(|code| {
+                let decoding = self.decode_ldpc(code, received);
+                let report = LdpcReport {
+                    info: decoding.info,
+                    decoder: self.ldpc_parameters.decoder,
+                    syndrome_weights: decoding.syndrome_weights,
+                };
+                (decoding.corrected, ErrorInfoType::Ldpc(report))
+            }),
+        }
+    }
+
+    /// Декодирование LDPC-кода с текущими настройками декодера
+    pub fn decode_ldpc(&self, code: &LdpcCode, received: &[bool]) -> LdpcDecoding {
+        let parameters = &self.ldpc_parameters;
+        code.decode(
+            received,
+            parameters.decoder,
+            parameters.max_iterations,
+            parameters.crossover_probability,
+        )
+    }
+
+    /// Базис кода: передаваемые слова для единичных сообщений (строки порождающей матрицы)
+    pub fn basis(&self) -> Option<Vec<Vec<bool>>> {
+        (0..self.k)
+            .map(|i| {
+                let mut message = vec![false; self.k];
+                message[i] = true;
+                self.encode(&message)
+            })
+            .collect()
+    }
+
+    /// Позиции информационных разрядов в передаваемом слове
+    ///
+    /// Информационный разряд `i` - позиция, в которой единица стоит
+    /// только в `i`-й строке базиса
+    pub fn information_positions(&self) -> Option<Vec<usize>> {
+        let words = self.basis()?;
+        let length = words.first()?.len();
+        (0..self.k)
+            .map(|i| (0..length).find(|&j| words.iter().enumerate().all(|(r, w)| w[j] == (r == i))))
+            .collect()
+    }
+
+    /// Сообщение, восстановленное декодером из принятого слова `received`
+    ///
+    /// Для блочных кодов - разряды исправленного слова `corrected` на информационных
+    /// позициях `positions` (см. [`Self::information_positions`]), для сверточного кода,
+    /// у которого информационных позиций нет, - сообщение на выжившем пути декодера Витерби
+    pub fn decoded_message(
+        &self,
+        received: &[bool],
+        corrected: &[bool],
+        positions: Option<&[usize]>,
+    ) -> Option<Vec<bool>> {
+        match self.code_type {
+            SelectedCodeType::Convolutional => {
+                let code = self.convolutional_code.as_ref()?;
+                Some(code.decode(received).message)
+            }
+            _ => positions.map(|positions| select_bits(corrected, positions)),
+        }
+    }
+
+    /// Синдром и декодирование принятого слова для карточки эксперимента
+    ///
+    /// Позиции ошибок (с 1) определяются сравнением принятого слова с переданным
+    pub fn evaluate(
+        &self,
+        seed: u64,
+        message: Vec<bool>,
+        received: Vec<bool>,
+    ) -> Option<Labs4To6ExperimentResult> {
+        self.evaluate_with_erasures(seed, message, received, vec![])
+    }
+
+    /// То же, что [`Self::evaluate`], с номерами стертых символов (с 1)
+    #[allow(clippy::too_many_lines)]
+    pub fn evaluate_with_erasures(
+        &self,
+        seed: u64,
+        message: Vec<bool>,
+        received: Vec<bool>,
+        erasures: Vec<usize>,
+    ) -> Option<Labs4To6ExperimentResult> {
+        let (code_type, codeword, codeword_with_parity, syndrome, overall_parity) =
+            match self.code_type {
+                SelectedCodeType::Hamming => {
+                    let code = self.hamming_code.as_ref()?;
+                    let codeword = encode_hamming(&message, code);
+                    let codeword_with_parity = add_parity_bit(&codeword);
+                    let (syndrome, computed_parity) =
+                        compute_syndrome_hamming(&received[..code.n], code);
+                    let overall_parity = received[code.n] == computed_parity;
+                    (
+                        CodeType::Hamming,
+                        codeword,
+                        Some(codeword_with_parity),
+                        syndrome,
+                        Some(overall_parity),
+                    )
+                }
+                SelectedCodeType::Systematic => {
+                    let (codeword, syndrome) = if let Some(ref code) = self.custom_code {
+                        (code.encode(&message), code.syndrome(&received))
+                    } else {
+                        let code = self.systematic_code.as_ref()?;
+                        (
+                            encode_message(&message, code),
+                            compute_syndrome(&code.parity_check, &received),
+                        )
+                    };
+                    (CodeType::Systematic, codeword, None, syndrome, None)
+                }
+                SelectedCodeType::Cyclic => {
+                    let code = self.cyclic_code.as_ref()?;
+                    (
+                        CodeType::Cyclic,
+                        code.encode(&message),
+                        None,
+                        code.syndrome(&received),
+                        None,
+                    )
+                }
+                SelectedCodeType::Bch => {
+                    let code = &self.bch_code.as_ref()?.cyclic;
+                    (
+                        CodeType::Bch,
+                        code.encode(&message),
+                        None,
+                        code.syndrome(&received),
+                        None,
+                    )
+                }
+                SelectedCodeType::ReedSolomon => {
+                    let code = self.rs_code.as_ref()?;
+                    // Синдромы S_j в двоичном виде по m бит, S_1 первым
+                    let syndromes = code.syndromes(&bits_to_symbols(&received, code.m()));
+                    (
+                        CodeType::ReedSolomon,
+                        code.encode(&message),
+                        None,
+                        symbols_to_bits(&syndromes, code.m()),
+                        None,
+                    )
+                }
+                SelectedCodeType::Convolutional => {
+                    // Синдромом служат расхождения принятой последовательности с выжившим путем
+                    let code = self.convolutional_code.as_ref()?;
+                    let survivor = code.encode(&code.decode(&received).message);
+                    let syndrome = received
+                        .iter()
+                        .zip(&survivor)
+                        .map(|(a, b)| a != b)
+                        .collect();
+                    (
+                        CodeType::Convolutional,
+                        code.encode(&message),
+                        None,
+                        syndrome,
+                        None,
+                    )
+                }
+                SelectedCodeType::Golay | SelectedCodeType::ExtendedGolay => {
+                    let code = self.golay_code.as_ref()?;
+                    (
+                        if code.extended {
+                            CodeType::ExtendedGolay
+                        } else {
+                            CodeType::Golay
+                        },
+                        code.linear.encode(&message),
+                        None,
+                        code.linear.syndrome(&received),
+                        None,
+                    )
+                }
+                SelectedCodeType::Ldpc => {
+                    let code = self.ldpc_code.as_ref()?;
+                    (
+                        CodeType::Ldpc,
+                        code.linear.encode(&message),
+                        None,
+                        code.linear.syndrome(&received),
+                        None,
+                    )
+                }
+            };
+        let (corrected, mut error_info) = self.decode_with_erasures(&received, &erasures)?;
+        // Витерби не отличает исправление от ложного пути - сверяем с переданным словом
+        if let ErrorInfoType::Convolutional(ViterbiErrorInfo::Corrected(distance)) = error_info
+            && corrected != codeword
+        {
+            error_info = ErrorInfoType::Convolutional(ViterbiErrorInfo::Miscorrected(distance));
+        }
+
+        let transmitted = codeword_with_parity.as_deref().unwrap_or(&codeword);
+        let error_positions: Vec<usize> = transmitted
+            .iter()
+            .zip(&received)
+            .enumerate()
+            .filter(|(_, (a, b))| a != b)
+            .map(|(i, _)| i + 1)
+            .collect();
+
+        Some(Labs4To6ExperimentResult {
+            seed,
+            code_type,
+            message,
+            codeword,
+            codeword_with_parity,
+            error_multiplicity: error_positions.len(),
+            error_positions,
+            received,
+            syndrome,
+            overall_parity,
+            corrected,
+            error_info,
+            erasures,
+            cyclic_decoder: (code_type == CodeType::Cyclic).then_some(self.cyclic_decoder),
+        })
+    }
+
     /// Инициализировать код, если он еще не создан
     pub fn ensure_code_initialized(&mut self) {
         match self.code_type {
-            SelectedCodeType::Systematic if self.systematic_code.is_none() => {
+            SelectedCodeType::Systematic
+                if self.systematic_code.is_none() && self.custom_code.is_none() =>
+            {
                 self.systematic_code = Some(
                     entropy_fx::coding::systematic::build_generator_matrix(self.k, self.n),
                 );
@@ -105,16 +762,62 @@ impl CodeConfig {
                 });
             }
             SelectedCodeType::Cyclic if self.cyclic_code.is_none() => {
-                self.cyclic_code = Some(entropy_fx::coding::cyclic::create_cyclic_code(self.k));
+                self.cyclic_code = self
+                    .cyclic_generator
+                    .clone()
+                    .and_then(|generator| CyclicCode::new(self.k, self.n, generator).ok())
+                    .or_else(|| CyclicCode::with_default_generator(self.k, self.n));
+            }
+            SelectedCodeType::Bch if self.bch_code.is_none() => {
+                self.bch_code = self.bch_parameters.build().ok();
+            }
+            SelectedCodeType::ReedSolomon if self.rs_code.is_none() => {
+                self.rs_code = self.rs_parameters.build().ok();
+            }
+            SelectedCodeType::Convolutional if self.convolutional_code.is_none() => {
+                self.convolutional_code = self.convolutional_parameters.build().ok();
+            }
+            SelectedCodeType::Golay | SelectedCodeType::ExtendedGolay
+                if self.golay_code.is_none() =>
+            {
+                let extended = self.code_type == SelectedCodeType::ExtendedGolay;
+                self.golay_code = GolayCode::new(extended).ok();
+            }
+            SelectedCodeType::Ldpc if self.ldpc_code.is_none() => {
+                self.ldpc_code = self.ldpc_parameters.build().ok();
             }
             _ => {}
         }
     }
 }
 
+/// Состояние окна ручного внесения ошибок
+#[derive(Clone, Default)]
+pub struct ManualInjection {
+    pub open: bool,
+    pub message: Vec<bool>,
+    /// Инвертированные разряды передаваемого слова
+    pub error_mask: Vec<bool>,
+}
+
+impl ManualInjection {
+    /// Загрузить сообщение и ошибки из результата эксперимента
+    pub fn load(&mut self, result: &Labs4To6ExperimentResult) {
+        self.open = true;
+        self.message.clone_from(&result.message);
+        self.error_mask = result
+            .received
+            .iter()
+            .zip(result.transmitted())
+            .map(|(a, b)| a != b)
+            .collect();
+    }
+}
+
 /// Результат эксперимента для Labs 4-6
-#[derive(Clone)]
+#[derive(Clone, Serialize, Deserialize)]
 pub struct Labs4To6ExperimentResult {
+    pub seed: u64,
     pub code_type: CodeType,
     pub message: Vec<bool>,
     pub codeword: Vec<bool>,
@@ -125,14 +828,106 @@ pub struct Labs4To6ExperimentResult {
     pub syndrome: Vec<bool>,
     pub overall_parity: Option<bool>,
     pub corrected: Vec<bool>,
+    #[serde(
+        serialize_with = "crate::session::serialize_error_info",
+        deserialize_with = "crate::session::deserialize_error_info"
+    )]
     pub error_info: ErrorInfoType,
+    /// Стертые символы (с 1), известные декодеру
+    #[serde(default)]
+    pub erasures: Vec<usize>,
+    /// Декодер, которым декодирован циклический код
+    #[serde(default)]
+    pub cyclic_decoder: Option<CyclicDecoder>,
 }
 
+impl Labs4To6ExperimentResult {
+    /// Слово, переданное в канал (для кода Хэмминга - с parity bit)
+    pub fn transmitted(&self) -> &[bool] {
+        self.codeword_with_parity
+            .as_deref()
+            .unwrap_or(&self.codeword)
+    }
+
+    /// Совпадает ли скорректированное слово с переданным
+    pub fn is_decoded_correctly(&self) -> bool {
+        self.corrected
+            .iter()
+            .zip(self.transmitted())
+            .all(|(a, b)| a == b)
+    }
+
+    /// Длина пакета ошибок: расстояние от первой до последней ошибки включительно
+    pub fn burst_length(&self) -> usize {
+        match (self.error_positions.first(), self.error_positions.last()) {
+            (Some(first), Some(last)) => last - first + 1,
+            _ => 0,
+        }
+    }
+
+    /// Итог передачи с точки зрения получателя
+    pub fn outcome(&self) -> DecodingOutcome {
+        let detected = self.syndrome.iter().any(|&bit| bit) || self.overall_parity == Some(false);
+        if self.error_positions.is_empty() {
+            DecodingOutcome::NoError
+        } else if self.is_decoded_correctly() {
+            DecodingOutcome::Corrected
+        } else if !detected {
+            DecodingOutcome::Undetected
+        } else if self.error_info.is_uncorrectable() {
+            DecodingOutcome::Detected
+        } else {
+            DecodingOutcome::Miscorrected
+        }
+    }
+}
+
+/// Итог декодирования принятого слова
 #[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub enum DecodingOutcome {
+    /// Ошибок в канале не было
+    NoError,
+    /// Ошибки исправлены
+    Corrected,
+    /// Ошибка обнаружена, но не может быть исправлена
+    Detected,
+    /// Декодер «исправил» слово в другое кодовое слово
+    Miscorrected,
+    /// Ошибка не обнаружена (синдром нулевой)
+    Undetected,
+}
+
+impl DecodingOutcome {
+    pub const ALL: [Self; 5] = [
+        Self::NoError,
+        Self::Corrected,
+        Self::Detected,
+        Self::Miscorrected,
+        Self::Undetected,
+    ];
+
+    pub const fn label(self) -> &'static str {
+        match self {
+            Self::NoError => "без ошибок",
+            Self::Corrected => "исправлено",
+            Self::Detected => "обнаружено",
+            Self::Miscorrected => "ложно исправлено",
+            Self::Undetected => "пропущено",
+        }
+    }
+}
+
+#[derive(PartialEq, Eq, Clone, Copy, Debug, Serialize, Deserialize)]
 pub enum CodeType {
     Systematic,
     Hamming,
     Cyclic,
+    Bch,
+    ReedSolomon,
+    Convolutional,
+    Golay,
+    ExtendedGolay,
+    Ldpc,
 }
 
 #[derive(Clone)]
@@ -140,4 +935,28 @@ pub enum ErrorInfoType {
     Systematic(entropy_fx::coding::systematic::ErrorInfo),
     Hamming(entropy_fx::coding::hamming::HammingErrorInfo),
     Cyclic(entropy_fx::coding::cyclic::CyclicErrorInfo),
+    Bch(BchErrorInfo),
+    ReedSolomon(RsErrorInfo),
+    Convolutional(ViterbiErrorInfo),
+    Golay(GolayErrorInfo),
+    Ldpc(LdpcReport),
+}
+
+impl ErrorInfoType {
+    /// Декодер обнаружил ошибку, которую не может исправить
+    pub const fn is_uncorrectable(&self) -> bool {
+        matches!(
+            self,
+            Self::Systematic(entropy_fx::coding::systematic::ErrorInfo::Uncorrectable)
+                | Self::Hamming(entropy_fx::coding::hamming::HammingErrorInfo::DoubleError)
+                | Self::Cyclic(entropy_fx::coding::cyclic::CyclicErrorInfo::Uncorrectable)
+                | Self::Bch(BchErrorInfo::Uncorrectable)
+                | Self::ReedSolomon(RsErrorInfo::Uncorrectable)
+                | Self::Golay(GolayErrorInfo::Uncorrectable)
+                | Self::Ldpc(LdpcReport {
+                    info: LdpcErrorInfo::NotConverged,
+                    ..
+                })
+        )
+    }
 }
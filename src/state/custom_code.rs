@@ -0,0 +1,55 @@
+use crate::coding::cyclic::GeneratorCandidate;
+use crate::formatting::polynomial::PolynomialNotation;
+use entropy_fx::coding::systematic::BinaryMatrix;
+use serde::{Deserialize, Serialize};
+
+/// Что задает пользователь: проверочную часть P или всю порождающую матрицу G
+#[derive(PartialEq, Eq, Clone, Copy, Debug, Default, Serialize, Deserialize)]
+pub enum GeneratorInput {
+    /// `G = [I | P]`, задается P (k x p)
+    #[default]
+    ParityPart,
+    /// Произвольная порождающая матрица G (k x n)
+    Full,
+}
+
+impl GeneratorInput {
+    pub const ALL: [Self; 2] = [Self::ParityPart, Self::Full];
+
+    #[must_use]
+    pub const fn label(self) -> &'static str {
+        match self {
+            Self::ParityPart => "Проверочная часть P (G = [I | P])",
+            Self::Full => "Порождающая матрица G",
+        }
+    }
+}
+
+/// Редактор собственной порождающей матрицы систематического кода
+#[derive(Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GeneratorEditor {
+    pub input: GeneratorInput,
+    /// Редактируемая матрица
+    pub draft: BinaryMatrix,
+    /// Текст для вставки матрицы
+    pub text: String,
+    #[serde(skip)]
+    pub status: Option<Result<String, String>>,
+}
+
+/// Редактор порождающего многочлена циклического кода
+#[derive(Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct PolynomialEditor {
+    pub notation: PolynomialNotation,
+    /// Многочлен, введенный вручную
+    pub text: String,
+    /// Разрешить g(x), не делящий `x^n + 1` (укороченный код)
+    pub allow_shortened: bool,
+    #[serde(skip)]
+    pub status: Option<Result<String, String>>,
+    /// Кандидаты для (p, n), для которых они перечислены
+    #[serde(skip)]
+    pub candidates: Option<((usize, usize), Vec<GeneratorCandidate>)>,
+}
@@ -1,6 +1,12 @@
+use crate::formatting::csv::CsvLocale;
+use crate::state::sweep::{SweepConfig, SweepPoint};
+use crate::utils::capacity::CapacityResult;
+use serde::{Deserialize, Serialize};
+
 /// Результат эксперимента для Labs 1-3
-#[derive(Clone)]
+#[derive(Clone, Serialize, Deserialize)]
 pub struct ExperimentResult {
+    pub seed: u64,
     pub input_probs: Vec<f64>,
     pub transition_matrix: Vec<Vec<f64>>,
     pub output_probs: Vec<f64>,
@@ -15,16 +21,107 @@ pub struct ExperimentResult {
     pub capacity_no_noise: f64,
     pub information_rate_with_noise: f64,
     pub capacity_with_noise: f64,
+    // Пропускная способность по алгоритму Блахута–Аримото
+    #[serde(default)]
+    pub channel_capacity: Option<CapacityResult>,
+}
+
+/// Показатель эксперимента для графиков и статистики
+#[derive(PartialEq, Eq, Clone, Copy, Debug, Default, Serialize, Deserialize)]
+pub enum ExperimentMetric {
+    InputEntropy,
+    ConditionalEntropy,
+    #[default]
+    MutualInformation,
+    ChannelCapacity,
+    RateNoNoise,
+    RateWithNoise,
+    CapacityNoNoise,
+    CapacityWithNoise,
+}
+
+impl ExperimentMetric {
+    pub const ALL: [Self; 8] = [
+        Self::InputEntropy,
+        Self::ConditionalEntropy,
+        Self::MutualInformation,
+        Self::ChannelCapacity,
+        Self::RateNoNoise,
+        Self::RateWithNoise,
+        Self::CapacityNoNoise,
+        Self::CapacityWithNoise,
+    ];
+
+    #[must_use]
+    pub const fn label(self) -> &'static str {
+        match self {
+            Self::InputEntropy => "H(X)",
+            Self::ConditionalEntropy => "H(X/Y)",
+            Self::MutualInformation => "I(X,Y)",
+            Self::ChannelCapacity => "C (Блахут–Аримото)",
+            Self::RateNoNoise => "Скорость (без помех)",
+            Self::RateWithNoise => "Скорость (с помехами)",
+            Self::CapacityNoNoise => "Пропускная способность (без помех)",
+            Self::CapacityWithNoise => "Пропускная способность (с помехами)",
+        }
+    }
+
+    /// Значение показателя в результате эксперимента
+    #[must_use]
+    pub fn value(self, result: &ExperimentResult) -> f64 {
+        match self {
+            Self::InputEntropy => result.input_entropy,
+            Self::ConditionalEntropy => result.conditional_entropy,
+            Self::MutualInformation => result.mutual_information,
+            Self::ChannelCapacity => result
+                .channel_capacity
+                .as_ref()
+                .map_or(0.0, |capacity| capacity.capacity),
+            Self::RateNoNoise => result.information_rate_no_noise,
+            Self::RateWithNoise => result.information_rate_with_noise,
+            Self::CapacityNoNoise => result.capacity_no_noise,
+            Self::CapacityWithNoise => result.capacity_with_noise,
+        }
+    }
+
+    /// Доступен ли показатель при текущих режимах
+    #[must_use]
+    pub const fn is_available(self, with_noise: bool, with_duration: bool) -> bool {
+        match self {
+            Self::InputEntropy | Self::MutualInformation => true,
+            Self::ConditionalEntropy | Self::ChannelCapacity => with_noise,
+            Self::RateNoNoise | Self::CapacityNoNoise => with_duration,
+            Self::RateWithNoise | Self::CapacityWithNoise => with_noise && with_duration,
+        }
+    }
 }
 
 /// Состояние для Labs 1-3
+#[allow(clippy::struct_excessive_bools)]
+#[derive(Clone, Serialize, Deserialize)]
+#[serde(default)]
 pub struct Labs1To3State {
     pub experiments: usize,
     pub signals: usize,
     pub with_noise: bool,
     pub with_duration: bool,
     pub min_threshold: f64,
+    pub duration_scale: f64,
     pub compact_view: bool,
+    pub seed: u64,
+    pub randomize_seed: bool,
+    pub manual_input: bool,
+    pub manual_input_probs: Vec<f64>,
+    pub manual_transition_matrix: Vec<Vec<f64>>,
+    pub capacity_tolerance: f64,
+    pub capacity_max_iterations: usize,
+    pub csv_locale: CsvLocale,
+    pub histogram_metric: ExperimentMetric,
+    pub histogram_bins: usize,
+    pub sweep: SweepConfig,
+    pub sweep_results: Vec<SweepPoint>,
+    #[serde(skip)]
+    pub csv_status: Option<String>,
     pub results: Vec<ExperimentResult>,
 }
 
@@ -36,8 +133,49 @@ impl Default for Labs1To3State {
             with_noise: false,
             with_duration: false,
             min_threshold: 0.7,
+            duration_scale: 1.0,
             compact_view: false,
+            seed: 0,
+            randomize_seed: true,
+            manual_input: false,
+            manual_input_probs: vec![],
+            manual_transition_matrix: vec![],
+            capacity_tolerance: 1e-6,
+            capacity_max_iterations: 1000,
+            csv_locale: CsvLocale::default(),
+            histogram_metric: ExperimentMetric::default(),
+            histogram_bins: 10,
+            sweep: SweepConfig::default(),
+            sweep_results: vec![],
+            csv_status: None,
             results: vec![],
         }
     }
 }
+
+impl Labs1To3State {
+    /// Привести размеры вручную введенных данных к текущему числу сигналов
+    ///
+    /// Новые сообщения получают нулевую вероятность, новые строки матрицы -
+    /// безошибочную передачу (единица на диагонали)
+    pub fn resize_manual_input(&mut self) {
+        let signals = self.signals;
+        if self.manual_input_probs.is_empty() {
+            #[allow(clippy::cast_precision_loss)]
+            let uniform = 1.0 / signals as f64;
+            self.manual_input_probs = vec![uniform; signals];
+        } else {
+            self.manual_input_probs.resize(signals, 0.0);
+        }
+
+        self.manual_transition_matrix.truncate(signals);
+        for row in &mut self.manual_transition_matrix {
+            row.resize(signals, 0.0);
+        }
+        for i in self.manual_transition_matrix.len()..signals {
+            let mut row = vec![0.0; signals];
+            row[i] = 1.0;
+            self.manual_transition_matrix.push(row);
+        }
+    }
+}
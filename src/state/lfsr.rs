@@ -0,0 +1,58 @@
+use crate::coding::lfsr::LfsrMode;
+use crate::state::code_config::Labs4To6ExperimentResult;
+
+/// Состояние окна пошаговой работы регистра сдвига циклического кода
+#[derive(Clone)]
+pub struct LfsrAnimation {
+    pub open: bool,
+    pub mode: LfsrMode,
+    pub message: Vec<bool>,
+    /// Принятое слово для вычисления синдрома
+    pub received: Vec<bool>,
+    /// Число выполненных тактов
+    pub step: usize,
+    pub playing: bool,
+    /// Скорость воспроизведения, тактов в секунду
+    pub steps_per_second: f64,
+    /// Время последнего такта при воспроизведении
+    pub last_tick: f64,
+}
+
+impl Default for LfsrAnimation {
+    fn default() -> Self {
+        Self {
+            open: false,
+            mode: LfsrMode::default(),
+            message: vec![],
+            received: vec![],
+            step: 0,
+            playing: false,
+            steps_per_second: 2.0,
+            last_tick: 0.0,
+        }
+    }
+}
+
+impl LfsrAnimation {
+    /// Загрузить сообщение и принятое слово из результата эксперимента
+    pub fn load(&mut self, result: &Labs4To6ExperimentResult) {
+        self.open = true;
+        self.message.clone_from(&result.message);
+        self.received.clone_from(&result.received);
+        self.rewind();
+    }
+
+    /// Вернуться к нулевому такту
+    pub const fn rewind(&mut self) {
+        self.step = 0;
+        self.playing = false;
+    }
+
+    /// Биты, подаваемые в регистр в выбранном режиме
+    pub fn input(&self) -> &[bool] {
+        match self.mode {
+            LfsrMode::Encoder => &self.message,
+            LfsrMode::Syndrome => &self.received,
+        }
+    }
+}
@@ -0,0 +1,29 @@
+use crate::formatting::message::MessageFormat;
+use crate::utils::transmission::{FileJob, FileReport, Transmission};
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+
+/// Сообщение, введенное пользователем для передачи через код
+#[derive(Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct MessageInput {
+    pub format: MessageFormat,
+    pub text: String,
+    #[serde(skip)]
+    pub error: Option<String>,
+    /// Результат передачи и формат, в котором было введено сообщение
+    #[serde(skip)]
+    pub transmission: Option<(MessageFormat, Transmission)>,
+}
+
+/// Передача файла через код
+#[derive(Default)]
+pub struct FileTransfer {
+    /// Исходный файл
+    pub source: Option<PathBuf>,
+    /// Отчет о последней передаче и seed, с которым она выполнена
+    pub report: Option<(u64, FileReport)>,
+    pub status: Option<String>,
+    /// Идущая передача
+    pub job: Option<FileJob>,
+}
@@ -1,5 +1,10 @@
+pub mod channel;
 pub mod code_config;
+pub mod custom_code;
 pub mod experiments;
+pub mod lfsr;
+pub mod message;
+pub mod sweep;
 
 pub use code_config::*;
 pub use experiments::*;
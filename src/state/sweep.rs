@@ -0,0 +1,180 @@
+use crate::state::experiments::ExperimentMetric;
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::mpsc::{self, Receiver, TryRecvError};
+use std::thread;
+
+/// Параметр, перебираемый в режиме развертки Labs 1-3
+#[derive(PartialEq, Eq, Clone, Copy, Debug, Default, Serialize, Deserialize)]
+pub enum SweepParameter {
+    #[default]
+    Signals,
+    MinThreshold,
+    DurationScale,
+}
+
+impl SweepParameter {
+    pub const ALL: [Self; 3] = [Self::Signals, Self::MinThreshold, Self::DurationScale];
+
+    #[must_use]
+    pub const fn label(self) -> &'static str {
+        match self {
+            Self::Signals => "Количество сообщений N",
+            Self::MinThreshold => "Порог достоверности",
+            Self::DurationScale => "Масштаб длительностей",
+        }
+    }
+
+    /// Допустимый диапазон значений параметра
+    #[must_use]
+    pub const fn bounds(self) -> (f64, f64) {
+        match self {
+            Self::Signals => (2.0, 128.0),
+            Self::MinThreshold => (0.0, 1.0),
+            Self::DurationScale => (0.01, 100.0),
+        }
+    }
+}
+
+/// Настройки перебора параметра
+#[derive(Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SweepConfig {
+    pub parameter: SweepParameter,
+    pub from: f64,
+    pub to: f64,
+    pub points: usize,
+    pub experiments_per_point: usize,
+}
+
+impl Default for SweepConfig {
+    fn default() -> Self {
+        Self {
+            parameter: SweepParameter::Signals,
+            from: 2.0,
+            to: 32.0,
+            points: 16,
+            experiments_per_point: 10,
+        }
+    }
+}
+
+impl SweepConfig {
+    /// Значения параметра в точках перебора
+    #[must_use]
+    #[allow(clippy::cast_precision_loss)]
+    pub fn values(&self) -> Vec<f64> {
+        let (min, max) = self.parameter.bounds();
+        let from = self.from.clamp(min, max);
+        let to = self.to.clamp(min, max);
+        let points = self.points.max(1);
+
+        let mut values: Vec<f64> = (0..points)
+            .map(|i| {
+                if points == 1 {
+                    from
+                } else {
+                    from + (to - from) * i as f64 / (points - 1) as f64
+                }
+            })
+            .map(|value| {
+                if self.parameter == SweepParameter::Signals {
+                    value.round()
+                } else {
+                    value
+                }
+            })
+            .collect();
+        values.dedup_by(|a, b| (*a - *b).abs() < f64::EPSILON);
+        values
+    }
+}
+
+/// Статистика показателя в точке перебора
+#[derive(Clone, Copy, Serialize, Deserialize)]
+pub struct MetricStats {
+    pub mean: f64,
+    pub min: f64,
+    pub max: f64,
+}
+
+/// Результат одной точки перебора
+#[derive(Clone, Serialize, Deserialize)]
+pub struct SweepPoint {
+    pub value: f64,
+    pub stats: Vec<(ExperimentMetric, MetricStats)>,
+}
+
+impl SweepPoint {
+    #[must_use]
+    pub fn get(&self, metric: ExperimentMetric) -> Option<MetricStats> {
+        self.stats
+            .iter()
+            .find(|(m, _)| *m == metric)
+            .map(|(_, stats)| *stats)
+    }
+}
+
+/// Перебор параметра, запущенный в фоновом потоке
+pub struct SweepJob {
+    receiver: Receiver<SweepPoint>,
+    cancel: Arc<AtomicBool>,
+    /// Число точек перебора
+    pub total: usize,
+    /// Поток завершился
+    pub finished: bool,
+}
+
+impl SweepJob {
+    /// Запуск перебора: `compute_point(i, value)` рассчитывает точку `i` со значением `value`
+    #[must_use]
+    pub fn spawn(
+        values: Vec<f64>,
+        compute_point: impl Fn(usize, f64) -> SweepPoint + Send + 'static,
+    ) -> Self {
+        let (sender, receiver) = mpsc::channel();
+        let cancel = Arc::new(AtomicBool::new(false));
+        let total = values.len();
+
+        let worker_cancel = Arc::clone(&cancel);
+        thread::spawn(move || {
+            for (i, value) in values.into_iter().enumerate() {
+                if worker_cancel.load(Ordering::Relaxed)
+                    || sender.send(compute_point(i, value)).is_err()
+                {
+                    return;
+                }
+            }
+        });
+
+        Self {
+            receiver,
+            cancel,
+            total,
+            finished: false,
+        }
+    }
+
+    /// Перенос готовых точек в `results`
+    pub fn poll(&mut self, results: &mut Vec<SweepPoint>) {
+        while !self.finished {
+            match self.receiver.try_recv() {
+                Ok(point) => results.push(point),
+                Err(TryRecvError::Empty) => return,
+                Err(TryRecvError::Disconnected) => self.finished = true,
+            }
+        }
+    }
+
+    /// Остановка перебора после текущей точки
+    pub fn cancel(&self) {
+        self.cancel.store(true, Ordering::Relaxed);
+    }
+}
+
+impl Drop for SweepJob {
+    fn drop(&mut self) {
+        self.cancel();
+    }
+}
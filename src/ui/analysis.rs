@@ -0,0 +1,167 @@
+use crate::formatting::format_bits;
+use crate::state::code_config::CodeConfig;
+use crate::ui::widgets::add_label;
+use crate::utils::code_analysis::{
+    CodeAnalysis, EXHAUSTIVE_MAX_K, analyze_code, hamming_bound, is_perfect, plotkin_bound,
+    singleton_bound,
+};
+use egui_plot::{Bar, BarChart, Plot};
+
+/// Рендеринг панели анализа минимального расстояния и распределения весов
+pub fn render_code_analysis_panel(ui: &mut egui::Ui, config: &mut CodeConfig) {
+    if config.k > EXHAUSTIVE_MAX_K {
+        ui.horizontal(|ui| {
+            ui.label(format!(
+                "k > {EXHAUSTIVE_MAX_K}: полный перебор невозможен, случайных слов в выборке:"
+            ));
+            ui.add(
+                egui::DragValue::new(&mut config.analysis_samples)
+                    .range(1000..=1_000_000)
+                    .speed(1000),
+            );
+        });
+    }
+
+    if ui
+        .add_enabled(
+            config.can_run_experiments(),
+            egui::Button::new("Анализировать"),
+        )
+        .clicked()
+        && let Some(basis) = config.basis()
+    {
+        config.analysis = Some((
+            config.code_type,
+            analyze_code(&basis, config.analysis_samples, config.seed),
+        ));
+    }
+
+    let Some((code_type, ref analysis)) = config.analysis else {
+        return;
+    };
+    if code_type != config.code_type || analysis.k != config.k {
+        return;
+    }
+
+    let (n, k) = (analysis.n, analysis.k);
+    ui.label(if analysis.exhaustive {
+        format!(
+            "Перебраны все {} кодовых слов ({n}, {k})-кода",
+            analysis.examined
+        )
+    } else {
+        format!(
+            "({n}, {k})-код: суммы 1-3 строк базиса и {} случайных слов (оценка сверху)",
+            analysis.examined
+        )
+    });
+
+    let d = analysis.min_distance;
+    add_label(
+        ui,
+        &format!(
+            "Минимальное расстояние d_min {} {d}",
+            if analysis.exhaustive { "=" } else { "≤" }
+        ),
+    );
+    ui.label(format!(
+        "Гарантированно обнаруживается ошибок: {}, исправляется: {}",
+        analysis.detectable(),
+        analysis.correctable()
+    ));
+    ui.label(format!(
+        "Слово минимального веса: {}",
+        format_bits(&analysis.min_weight_word)
+    ));
+
+    add_label(ui, "Границы для d_min:");
+    let t = hamming_bound(n, k);
+    egui::Grid::new("labs4to6_analysis_bounds")
+        .striped(true)
+        .num_columns(3)
+        .show(ui, |ui| {
+            for (name, bound, note) in [
+                (
+                    "Синглтона",
+                    singleton_bound(n, k),
+                    "d ≤ n - k + 1".to_string(),
+                ),
+                (
+                    "Хэмминга",
+                    2 * t + 2,
+                    format!(
+                        "исправляется не более t = {t} ошибок{}",
+                        if is_perfect(n, k, t) && analysis.correctable() == t {
+                            ", код совершенный"
+                        } else {
+                            ""
+                        }
+                    ),
+                ),
+                (
+                    "Плоткина",
+                    plotkin_bound(n, k),
+                    "d ≤ n·2^(k-1) / (2^k - 1)".to_string(),
+                ),
+            ] {
+                ui.label(name);
+                ui.label(format!("d ≤ {bound}"));
+                ui.label(note);
+                ui.end_row();
+            }
+        });
+
+    render_weight_distribution(ui, analysis);
+}
+
+/// Таблица и диаграмма распределения весов
+fn render_weight_distribution(ui: &mut egui::Ui, analysis: &CodeAnalysis) {
+    add_label(
+        ui,
+        if analysis.exhaustive {
+            "Распределение весов A_w:"
+        } else {
+            "Распределение весов в случайной выборке:"
+        },
+    );
+    egui::ScrollArea::horizontal()
+        .id_salt("labs4to6_analysis_weights_scroll")
+        .show(ui, |ui| {
+            egui::Grid::new("labs4to6_analysis_weights")
+                .striped(true)
+                .show(ui, |ui| {
+                    let nonzero: Vec<(usize, u64)> = analysis
+                        .weight_distribution
+                        .iter()
+                        .copied()
+                        .enumerate()
+                        .filter(|&(_, count)| count > 0)
+                        .collect();
+                    ui.label(egui::RichText::new("w").strong());
+                    for (w, _) in &nonzero {
+                        ui.label(w.to_string());
+                    }
+                    ui.end_row();
+                    ui.label(egui::RichText::new("A_w").strong());
+                    for (_, count) in &nonzero {
+                        ui.label(count.to_string());
+                    }
+                    ui.end_row();
+                });
+        });
+
+    #[allow(clippy::cast_precision_loss)]
+    let bars: Vec<Bar> = analysis
+        .weight_distribution
+        .iter()
+        .enumerate()
+        .map(|(w, &count)| Bar::new(w as f64, count as f64).width(0.8))
+        .collect();
+    Plot::new("labs4to6_analysis_plot")
+        .height(200.0)
+        .x_axis_label("Вес w")
+        .y_axis_label("Число слов")
+        .show(ui, |plot_ui| {
+            plot_ui.bar_chart(BarChart::new("A_w", bars));
+        });
+}
@@ -0,0 +1,55 @@
+use crate::coding::bch::BchCode;
+use crate::ui::widgets::add_label;
+
+/// Синдромы, многочлен локаторов и корни, найденные процедурой Ченя
+pub fn render_bch_decoding(ui: &mut egui::Ui, code: &BchCode, received: &[bool]) {
+    let decoding = code.decode(received);
+    let field = &code.field;
+
+    add_label(ui, "Синдромы S_j = r(α^j):");
+    let syndromes: Vec<String> = decoding
+        .syndromes
+        .iter()
+        .enumerate()
+        .map(|(j, &s)| format!("S{} = {}", j + 1, field.format(s)))
+        .collect();
+    ui.label(syndromes.join(", "));
+
+    if decoding.syndromes.iter().all(|&s| s == 0) {
+        return;
+    }
+
+    add_label(ui, "Многочлен локаторов ошибок (Берлекэмп–Мэсси):");
+    ui.label(format!("Λ(x) = {}", field.format_poly(&decoding.locator)));
+
+    add_label(ui, "Процедура Ченя:");
+    if decoding.error_powers.is_empty() {
+        ui.label("корней Λ(x) не найдено");
+    } else {
+        let roots: Vec<String> = decoding
+            .error_powers
+            .iter()
+            .map(|&e| {
+                format!(
+                    "Λ({}) = 0 → x^{e}, разряд {}",
+                    field.format(field.alpha_pow(-i64::try_from(e).unwrap_or(0))),
+                    code.cyclic.n - e
+                )
+            })
+            .collect();
+        for root in roots {
+            ui.label(root);
+        }
+    }
+    let degree = decoding.locator.len() - 1;
+    if decoding.error_powers.len() != degree {
+        ui.colored_label(
+            egui::Color32::RED,
+            format!(
+                "Найдено корней: {}, степень Λ(x): {degree} - ошибок больше t = {}",
+                decoding.error_powers.len(),
+                code.t
+            ),
+        );
+    }
+}
@@ -0,0 +1,183 @@
+use crate::state::{CodeConfig, SelectedCodeType};
+use crate::utils::ber::{BerJob, BerPoint};
+use crate::utils::random::random_seed;
+use egui_plot::{GridMark, Legend, Line, LineStyle, Plot, PlotPoints, Points};
+use std::ops::RangeInclusive;
+
+/// Подпись деления логарифмической оси
+fn format_log_mark(mark: GridMark, _range: &RangeInclusive<f64>) -> String {
+    format!("{:.0e}", 10f64.powf(mark.value))
+}
+
+/// Точки в логарифмическом масштабе (нулевые значения не отображаются)
+fn log_points(points: impl Iterator<Item = (f64, f64)>) -> PlotPoints<'static> {
+    points
+        .filter(|(x, y)| *x > 0.0 && *y > 0.0)
+        .map(|(x, y)| [x.log10(), y.log10()])
+        .collect()
+}
+
+/// Рендеринг панели моделирования BER/WER
+pub fn render_ber_panel(ui: &mut egui::Ui, config: &mut CodeConfig) {
+    let settings = &mut config.ber_settings;
+    ui.horizontal(|ui| {
+        ui.label("Вероятность ошибки в канале от");
+        ui.add(
+            egui::Slider::new(&mut settings.min_probability, 1e-6..=0.5)
+                .logarithmic(true)
+                .custom_formatter(|value, _| format!("{value:.0e}")),
+        );
+        ui.label("до");
+        ui.add(
+            egui::Slider::new(&mut settings.max_probability, 1e-6..=0.5)
+                .logarithmic(true)
+                .custom_formatter(|value, _| format!("{value:.0e}")),
+        );
+    });
+    ui.horizontal(|ui| {
+        ui.label("Точек:");
+        ui.add(egui::DragValue::new(&mut settings.points).range(2..=40));
+        ui.label("Кодовых слов в точке:");
+        ui.add(
+            egui::DragValue::new(&mut settings.codewords_per_point)
+                .range(100..=100_000)
+                .speed(100),
+        );
+    });
+
+    render_ber_job(ui, config);
+
+    if config.ber_results.is_empty() {
+        return;
+    }
+
+    let results = &config.ber_results;
+    let bound_label = |name: &str| match config.correctable_errors() {
+        Some(t) if config.code_type == SelectedCodeType::ReedSolomon => {
+            format!("Оценка {name} (t = {t} симв.)")
+        }
+        Some(t) => format!("Оценка {name} (t = {t})"),
+        None => format!("Оценка {name}"),
+    };
+    let has_bound = results.iter().any(|r| r.bound_ber.is_some());
+    Plot::new("labs4to6_ber_plot")
+        .height(300.0)
+        .legend(Legend::default())
+        .x_axis_label("Вероятность ошибки в канале p")
+        .y_axis_label("Вероятность ошибки")
+        .x_axis_formatter(format_log_mark)
+        .y_axis_formatter(format_log_mark)
+        .show(ui, |plot_ui| {
+            plot_ui.line(
+                Line::new(
+                    "Без кодирования",
+                    log_points(results.iter().map(|r| (r.raw_ber, r.raw_ber))),
+                )
+                .style(LineStyle::dashed_dense()),
+            );
+            if has_bound {
+                let bound = |value: fn(&BerPoint) -> Option<f64>| {
+                    log_points(
+                        results
+                            .iter()
+                            .filter_map(|r| value(r).map(|bound| (r.raw_ber, bound))),
+                    )
+                };
+                plot_ui.line(
+                    Line::new(bound_label("BER"), bound(|r| r.bound_ber))
+                        .style(LineStyle::dotted_dense()),
+                );
+                plot_ui.line(
+                    Line::new(bound_label("WER"), bound(|r| r.bound_wer))
+                        .style(LineStyle::dotted_dense()),
+                );
+            }
+
+            let decoded_ber = || log_points(results.iter().map(|r| (r.raw_ber, r.decoded_ber)));
+            plot_ui.line(Line::new("BER после декодирования", decoded_ber()).width(2.0));
+            plot_ui.points(Points::new("BER после декодирования", decoded_ber()).radius(3.0));
+
+            let wer = || log_points(results.iter().map(|r| (r.raw_ber, r.word_error_rate)));
+            plot_ui.line(Line::new("WER", wer()).width(2.0));
+            plot_ui.points(Points::new("WER", wer()).radius(3.0));
+        });
+
+    render_ber_table(ui, results);
+}
+
+/// Запуск, ход и остановка моделирования в фоновом потоке
+fn render_ber_job(ui: &mut egui::Ui, config: &mut CodeConfig) {
+    if let Some(ref mut job) = config.ber_job {
+        job.poll(&mut config.ber_results);
+    }
+
+    match config.ber_job {
+        Some(ref job) if !job.finished => {
+            let mut stop = false;
+            ui.horizontal(|ui| {
+                ui.spinner();
+                ui.label(format!(
+                    "Моделирование: {} из {} точек",
+                    config.ber_results.len(),
+                    job.total
+                ));
+                stop = ui.button("Остановить").clicked();
+            });
+            if stop {
+                job.cancel();
+            }
+        }
+        ref job => {
+            if let Some(error) = job.as_ref().and_then(|job| job.error.as_ref()) {
+                ui.colored_label(egui::Color32::RED, format!("Ошибка: {error}"));
+            }
+            if ui
+                .add_enabled(
+                    config.can_run_experiments(),
+                    egui::Button::new("Запустить моделирование"),
+                )
+                .clicked()
+            {
+                if config.randomize_seed {
+                    config.seed = random_seed();
+                }
+                config.ber_results.clear();
+                config.ber_job = Some(BerJob::spawn(
+                    config,
+                    config.ber_settings.clone(),
+                    config.seed,
+                ));
+            }
+        }
+    }
+}
+
+/// Таблица результатов моделирования BER/WER
+fn render_ber_table(ui: &mut egui::Ui, results: &[BerPoint]) {
+    egui::ScrollArea::vertical()
+        .id_salt("labs4to6_ber_table_scroll")
+        .max_height(200.0)
+        .show(ui, |ui| {
+            egui::Grid::new("labs4to6_ber_table")
+                .striped(true)
+                .num_columns(6)
+                .show(ui, |ui| {
+                    for header in ["p", "BER", "WER", "Обнаружено", "Оценка BER", "Оценка WER"]
+                    {
+                        ui.label(egui::RichText::new(header).strong());
+                    }
+                    ui.end_row();
+
+                    for point in results {
+                        ui.label(format!("{:.2e}", point.raw_ber));
+                        ui.label(format!("{:.2e}", point.decoded_ber));
+                        ui.label(format!("{:.2e}", point.word_error_rate));
+                        ui.label(format!("{:.2e}", point.detected_rate));
+                        for bound in [point.bound_ber, point.bound_wer] {
+                            ui.label(bound.map_or_else(|| "-".to_string(), |b| format!("{b:.2e}")));
+                        }
+                        ui.end_row();
+                    }
+                });
+        });
+}
@@ -0,0 +1,189 @@
+use crate::coding::convolutional::{ConvolutionalCode, ViterbiDecoding};
+use crate::formatting::format_bits;
+use crate::state::code_config::{CodeConfig, Labs4To6ExperimentResult};
+use crate::ui::widgets::add_label;
+
+const STEP_WIDTH: f32 = 44.0;
+/// Отступ слева под номера состояний
+const STATE_MARGIN: f32 = 40.0;
+/// Отступ сверху под принятые символы
+const SYMBOL_MARGIN: f32 = 28.0;
+/// Метрики подписываются у узлов только в небольших решетках
+const MAX_LABELLED_STATES: usize = 8;
+
+/// Рендеринг параметров сверточного кода: K и порождающие многочлены (восьмеричные)
+pub fn render_convolutional_parameters(ui: &mut egui::Ui, config: &mut CodeConfig) {
+    let parameters = &mut config.convolutional_parameters;
+    let mut changed = false;
+    ui.horizontal(|ui| {
+        ui.label("K (длина кодового ограничения):");
+        changed |= ui
+            .add(
+                egui::DragValue::new(&mut parameters.constraint_length)
+                    .range(ConvolutionalCode::MIN_K..=ConvolutionalCode::MAX_K),
+            )
+            .changed();
+        ui.label("Порождающие многочлены (восьм.):");
+        changed |= ui
+            .add(
+                egui::TextEdit::singleline(&mut parameters.generators)
+                    .desired_width(120.0)
+                    .font(egui::TextStyle::Monospace),
+            )
+            .changed();
+    });
+    if changed {
+        config.update_n_and_p();
+        config.analysis = None;
+        config.coverage = None;
+    }
+
+    match config.convolutional_parameters.build() {
+        Ok(code) => {
+            ui.label(format!(
+                "Скорость 1/{}, {} состояний решетки, d_free = {}; к сообщению добавляется {} \
+                 нулевых бита для возврата в нулевое состояние",
+                code.rate_denominator(),
+                code.states(),
+                code.free_distance,
+                code.constraint_length - 1
+            ));
+        }
+        Err(err) => {
+            ui.colored_label(egui::Color32::RED, format!("Ошибка: {err}"));
+        }
+    }
+}
+
+/// Решетка декодера Витерби с выделенным выжившим путем
+///
+/// Над каждым шагом - принятые биты; символы, расходящиеся с выжившим путем, выделены красным
+pub fn render_trellis(
+    ui: &mut egui::Ui,
+    code: &ConvolutionalCode,
+    result: &Labs4To6ExperimentResult,
+) {
+    let decoding = code.decode(&result.received);
+    add_label(ui, "Декодированное сообщение:");
+    ui.label(format_bits(&decoding.message));
+
+    add_label(
+        ui,
+        &format!(
+            "Решетка декодера Витерби (выживший путь выделен, метрика {}):",
+            decoding.distance
+        ),
+    );
+    egui::ScrollArea::horizontal()
+        .id_salt(("trellis_scroll", result.seed))
+        .show(ui, |ui| draw_trellis(ui, code, &decoding, &result.received));
+}
+
+/// Рисование решетки: узлы с метриками, выжившие ветви и выживший путь
+fn draw_trellis(
+    ui: &mut egui::Ui,
+    code: &ConvolutionalCode,
+    decoding: &ViterbiDecoding,
+    received: &[bool],
+) {
+    let survivor = code.encode(&decoding.message);
+    let steps = decoding.path.len() - 1;
+    let states = code.states();
+    let state_height = if states > MAX_LABELLED_STATES {
+        12.0
+    } else {
+        30.0
+    };
+
+    #[allow(clippy::cast_precision_loss)]
+    let size = egui::vec2(
+        (steps as f32).mul_add(STEP_WIDTH, STATE_MARGIN + 20.0),
+        (states as f32).mul_add(state_height, SYMBOL_MARGIN + 10.0),
+    );
+    let (response, painter) = ui.allocate_painter(size, egui::Sense::hover());
+    let origin = response.rect.min;
+    #[allow(clippy::cast_precision_loss)]
+    let node = |t: usize, state: usize| {
+        egui::pos2(
+            (t as f32).mul_add(STEP_WIDTH, origin.x + STATE_MARGIN),
+            (state as f32).mul_add(state_height, origin.y + SYMBOL_MARGIN),
+        )
+    };
+    let text_color = ui.visuals().text_color();
+    let faint = ui.visuals().weak_text_color();
+
+    for state in 0..states {
+        painter.text(
+            node(0, state) - egui::vec2(STATE_MARGIN - 4.0, 0.0),
+            egui::Align2::LEFT_CENTER,
+            format!("{state:0width$b}", width = code.constraint_length - 1),
+            egui::FontId::monospace(10.0),
+            faint,
+        );
+    }
+
+    let r = code.rate_denominator();
+    for (t, symbol) in received.chunks_exact(r).enumerate() {
+        let differs = symbol != &survivor[t * r..(t + 1) * r];
+        painter.text(
+            egui::pos2(
+                f32::midpoint(node(t, 0).x, node(t + 1, 0).x),
+                origin.y + 8.0,
+            ),
+            egui::Align2::CENTER_CENTER,
+            format_bits(symbol),
+            egui::FontId::monospace(11.0),
+            if differs {
+                egui::Color32::RED
+            } else {
+                text_color
+            },
+        );
+    }
+
+    // Выжившие ветви всех состояний: вход 0 - сплошная, вход 1 - голубая линия
+    for t in 1..=steps {
+        for (state, survivor) in decoding.survivors[t].iter().enumerate() {
+            if let Some((previous, input)) = *survivor {
+                let color = if input {
+                    egui::Color32::from_rgb(120, 170, 230)
+                } else {
+                    faint
+                };
+                painter.line_segment(
+                    [node(t - 1, previous), node(t, state)],
+                    egui::Stroke::new(1.0, color),
+                );
+            }
+        }
+    }
+    for (t, pair) in decoding.path.windows(2).enumerate() {
+        painter.line_segment(
+            [node(t, pair[0]), node(t + 1, pair[1])],
+            egui::Stroke::new(3.0, egui::Color32::from_rgb(40, 160, 60)),
+        );
+    }
+
+    for (t, metrics) in decoding.metrics.iter().enumerate() {
+        for (state, metric) in metrics.iter().enumerate() {
+            let Some(metric) = metric else {
+                continue;
+            };
+            let on_path = decoding.path[t] == state;
+            painter.circle_filled(
+                node(t, state),
+                if on_path { 4.0 } else { 2.5 },
+                if on_path { text_color } else { faint },
+            );
+            if states <= MAX_LABELLED_STATES {
+                painter.text(
+                    node(t, state) + egui::vec2(0.0, -4.0),
+                    egui::Align2::CENTER_BOTTOM,
+                    metric.to_string(),
+                    egui::FontId::proportional(9.0),
+                    faint,
+                );
+            }
+        }
+    }
+}
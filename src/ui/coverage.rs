@@ -0,0 +1,115 @@
+use crate::state::code_config::{CodeConfig, DecodingOutcome, SelectedCodeType};
+use crate::ui::labs4to6::outcome_color;
+use crate::ui::widgets::add_label;
+use crate::utils::coverage::{CoverageReport, MAX_PATTERNS, error_pattern_coverage, pattern_count};
+
+/// Рендеринг панели перебора всех шаблонов ошибок малого веса
+pub fn render_coverage_panel(ui: &mut egui::Ui, config: &mut CodeConfig) {
+    let length = config.n + usize::from(config.code_type == SelectedCodeType::Hamming);
+    let max_weight = if config.coverage_weight3 { 3 } else { 2 };
+
+    let patterns = pattern_count(length, max_weight);
+    ui.horizontal(|ui| {
+        ui.checkbox(&mut config.coverage_weight3, "Включая тройные ошибки");
+        ui.label(format!("(шаблонов: {patterns})"));
+    });
+    if patterns > MAX_PATTERNS {
+        ui.colored_label(
+            egui::Color32::from_rgb(200, 120, 0),
+            format!("Перебор доступен не более чем для {MAX_PATTERNS} шаблонов"),
+        );
+    }
+
+    if ui
+        .add_enabled(
+            config.can_run_experiments() && patterns <= MAX_PATTERNS,
+            egui::Button::new("Перебрать шаблоны"),
+        )
+        .clicked()
+    {
+        config.coverage = error_pattern_coverage(config, max_weight, config.seed)
+            .map(|report| (config.code_type, report));
+    }
+
+    let Some((code_type, ref report)) = config.coverage else {
+        return;
+    };
+    if code_type != config.code_type || report.length != length {
+        return;
+    }
+
+    render_coverage_table(ui, report);
+
+    if code_type == SelectedCodeType::Hamming {
+        let secded = report.is_secded();
+        ui.colored_label(
+            if secded {
+                egui::Color32::DARK_GREEN
+            } else {
+                egui::Color32::RED
+            },
+            if secded {
+                "SECDED подтвержден: все одиночные ошибки исправлены, все двойные обнаружены"
+            } else {
+                "SECDED не выполняется"
+            },
+        );
+    } else if let Some(row) = report.row(1) {
+        ui.label(if row.all(DecodingOutcome::Corrected) {
+            "Все одиночные ошибки исправляются"
+        } else {
+            "Исправляются не все одиночные ошибки"
+        });
+    }
+
+    for row in &report.rows {
+        if row.failures.is_empty() {
+            continue;
+        }
+        add_label(
+            ui,
+            &format!(
+                "Примеры ложно исправленных и пропущенных шаблонов веса {}:",
+                row.weight
+            ),
+        );
+        for (outcome, positions) in &row.failures {
+            ui.colored_label(
+                outcome_color(*outcome),
+                format!("{}: разряды {positions:?}", outcome.label()),
+            );
+        }
+    }
+}
+
+/// Таблица итогов по весам шаблонов
+fn render_coverage_table(ui: &mut egui::Ui, report: &CoverageReport) {
+    let outcomes = [
+        DecodingOutcome::Corrected,
+        DecodingOutcome::Detected,
+        DecodingOutcome::Miscorrected,
+        DecodingOutcome::Undetected,
+    ];
+    egui::Grid::new("labs4to6_coverage_table")
+        .striped(true)
+        .num_columns(outcomes.len() + 2)
+        .show(ui, |ui| {
+            ui.label(egui::RichText::new("Вес").strong());
+            ui.label(egui::RichText::new("Шаблонов").strong());
+            for outcome in outcomes {
+                ui.colored_label(outcome_color(outcome), outcome.label());
+            }
+            ui.end_row();
+
+            for row in &report.rows {
+                ui.label(row.weight.to_string());
+                ui.label(row.total.to_string());
+                for outcome in outcomes {
+                    #[allow(clippy::cast_precision_loss)]
+                    let share = row.count(outcome) as f64 / row.total.max(1) as f64 * 100.0;
+                    ui.label(format!("{} ({share:.1}%)", row.count(outcome)));
+                }
+                ui.end_row();
+            }
+        });
+}
@@ -0,0 +1,176 @@
+use crate::coding::linear::LinearCode;
+use crate::formatting::{format_binary_matrix, parse_binary_matrix};
+use crate::state::code_config::CodeConfig;
+use crate::state::custom_code::GeneratorInput;
+
+/// Рендеринг редактора собственной порождающей матрицы систематического кода
+pub fn render_generator_editor(ui: &mut egui::Ui, config: &mut CodeConfig) {
+    if let Some((n, k)) = config.custom_code.as_ref().map(|code| (code.n, code.k)) {
+        let mut reset = false;
+        ui.horizontal(|ui| {
+            ui.label(format!("Используется собственная матрица: ({n}, {k})-код"));
+            reset = ui.button("Вернуться к сгенерированной матрице").clicked();
+        });
+        if reset {
+            config.clear_custom_code();
+            config.ensure_code_initialized();
+        }
+    }
+
+    let mut fill = false;
+    let editor = &mut config.generator_editor;
+    ui.horizontal(|ui| {
+        ui.label("Задается:");
+        egui::ComboBox::from_id_salt("generator_input")
+            .selected_text(editor.input.label())
+            .show_ui(ui, |ui| {
+                for input in GeneratorInput::ALL {
+                    ui.selectable_value(&mut editor.input, input, input.label());
+                }
+            });
+        fill = ui.button("Заполнить из текущего кода").clicked();
+    });
+    if fill || config.generator_editor.draft.is_empty() {
+        config.generator_editor.draft =
+            current_matrix(config, config.generator_editor.input).unwrap_or_default();
+    }
+
+    render_draft_grid(ui, config);
+
+    let editor = &mut config.generator_editor;
+    ui.label("Матрица в виде текста (по строке из 0 и 1 на каждую строку матрицы):");
+    ui.add(
+        egui::TextEdit::multiline(&mut editor.text)
+            .desired_rows(4)
+            .desired_width(f32::INFINITY)
+            .font(egui::TextStyle::Monospace),
+    );
+    let mut apply = false;
+    ui.horizontal(|ui| {
+        if ui.button("Загрузить из текста").clicked() {
+            match parse_binary_matrix(&editor.text) {
+                Ok(matrix) => {
+                    editor.draft = matrix;
+                    editor.status = None;
+                }
+                Err(err) => editor.status = Some(Err(err)),
+            }
+        }
+        if ui.button("Записать в текст").clicked() {
+            editor.text = format_binary_matrix(&editor.draft);
+        }
+        apply = ui.button("Применить").clicked();
+    });
+    if apply {
+        let editor = &mut config.generator_editor;
+        match build_code(&editor.draft, editor.input) {
+            Ok(code) => {
+                editor.status = Some(Ok(format!(
+                    "Применен ({}, {})-код: G·Hᵀ = 0, столбцы H различны и ненулевые",
+                    code.n, code.k
+                )));
+                config.set_custom_code(code);
+            }
+            Err(err) => editor.status = Some(Err(err)),
+        }
+    }
+
+    match config.generator_editor.status {
+        Some(Ok(ref message)) => {
+            ui.colored_label(egui::Color32::DARK_GREEN, message);
+        }
+        Some(Err(ref error)) => {
+            ui.colored_label(egui::Color32::RED, format!("Ошибка: {error}"));
+        }
+        None => {}
+    }
+}
+
+/// Размеры и ячейки редактируемой матрицы
+fn render_draft_grid(ui: &mut egui::Ui, config: &mut CodeConfig) {
+    let draft = &mut config.generator_editor.draft;
+    let mut rows = draft.len();
+    let mut columns = draft.first().map_or(0, Vec::len);
+    ui.horizontal(|ui| {
+        ui.label("Строк (k):");
+        ui.add(egui::DragValue::new(&mut rows).range(1..=200));
+        ui.label("Столбцов:");
+        ui.add(egui::DragValue::new(&mut columns).range(1..=256));
+    });
+    draft.resize(rows, vec![]);
+    for row in draft.iter_mut() {
+        row.resize(columns, false);
+    }
+
+    egui::ScrollArea::both()
+        .id_salt("generator_editor_scroll")
+        .max_height(300.0)
+        .show(ui, |ui| {
+            egui::Grid::new("generator_editor_grid")
+                .spacing(egui::vec2(2.0, 2.0))
+                .show(ui, |ui| {
+                    for row in draft.iter_mut() {
+                        for cell in row.iter_mut() {
+                            let text =
+                                egui::RichText::new(if *cell { "1" } else { "0" }).monospace();
+                            if ui
+                                .add(
+                                    egui::Button::new(text)
+                                        .selected(*cell)
+                                        .min_size(egui::vec2(18.0, 18.0)),
+                                )
+                                .clicked()
+                            {
+                                *cell = !*cell;
+                            }
+                        }
+                        ui.end_row();
+                    }
+                });
+        });
+}
+
+/// Матрица текущего кода в выбранном виде
+///
+/// G восстанавливается кодированием единичных сообщений, P - ее проверочные столбцы
+fn current_matrix(config: &CodeConfig, input: GeneratorInput) -> Option<Vec<Vec<bool>>> {
+    let basis = config.basis()?;
+    match input {
+        GeneratorInput::Full => Some(basis),
+        GeneratorInput::ParityPart => {
+            let information = config.information_positions()?;
+            Some(
+                basis
+                    .iter()
+                    .map(|row| {
+                        row.iter()
+                            .enumerate()
+                            .filter(|(j, _)| !information.contains(j))
+                            .map(|(_, &bit)| bit)
+                            .collect()
+                    })
+                    .collect(),
+            )
+        }
+    }
+}
+
+/// Построение кода по матрице и проверка исправления одиночных ошибок
+fn build_code(matrix: &[Vec<bool>], input: GeneratorInput) -> Result<LinearCode, String> {
+    let matrix = matrix.to_vec();
+    let code = match input {
+        GeneratorInput::ParityPart => LinearCode::from_parity_part(&matrix)?,
+        GeneratorInput::Full => LinearCode::from_generator(&matrix)?,
+    };
+    if !code.is_orthogonal() {
+        return Err("G·Hᵀ ≠ 0".to_string());
+    }
+    let problems = code.single_error_problems();
+    if !problems.is_empty() {
+        return Err(format!(
+            "код не исправляет одиночные ошибки: {}",
+            problems.join("; ")
+        ));
+    }
+    Ok(code)
+}
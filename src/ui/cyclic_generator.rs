@@ -0,0 +1,210 @@
+use crate::coding::cyclic::{CyclicCode, MAX_CANDIDATE_DEGREE, generator_candidates};
+use crate::formatting::polynomial::{
+    PolynomialNotation, format_binary, format_octal, format_polynomial, parse_polynomial,
+};
+use crate::state::code_config::CodeConfig;
+
+/// Рендеринг выбора порождающего многочлена циклического кода
+pub fn render_polynomial_selector(ui: &mut egui::Ui, config: &mut CodeConfig) {
+    let Some(code) = config.cyclic_code.clone() else {
+        return;
+    };
+
+    ui.label(
+        egui::RichText::new(format!(
+            "Текущий g(x) = {} (двоичная {}, восьмеричная {})",
+            format_polynomial(&code.generator),
+            format_binary(&code.generator),
+            format_octal(&code.generator)
+        ))
+        .strong(),
+    );
+    render_generator_properties(ui, &code);
+    if config.cyclic_generator.is_some() && ui.button("Вернуться к g(x) по умолчанию").clicked()
+    {
+        config.polynomial_editor.status = None;
+        config.set_cyclic_generator(None);
+    }
+
+    ui.separator();
+    if let Some(generator) = render_candidates(ui, config, &code) {
+        config.polynomial_editor.status = None;
+        config.set_cyclic_generator(Some(generator));
+    }
+
+    ui.separator();
+    render_free_entry(ui, config);
+}
+
+/// Делимость `x^n + 1`, период и исправление одиночных ошибок для g(x)
+fn render_generator_properties(ui: &mut egui::Ui, code: &CyclicCode) {
+    if code.divides_x_n_plus_one() {
+        ui.colored_label(
+            egui::Color32::DARK_GREEN,
+            format!("g(x) делит x^{} + 1: код циклический", code.n),
+        );
+    } else {
+        let period = code
+            .generator_period()
+            .map_or_else(|| "не определен".to_string(), |period| period.to_string());
+        ui.colored_label(
+            egui::Color32::from_rgb(200, 120, 0),
+            format!(
+                "g(x) не делит x^{} + 1: код укороченный, период g(x) равен {period}",
+                code.n
+            ),
+        );
+    }
+    if !code.corrects_single_errors() {
+        ui.colored_label(
+            egui::Color32::RED,
+            format!(
+                "Период g(x) меньше n = {}: синдромы одиночных ошибок повторяются, \
+                 исправление однозначно невозможно",
+                code.n
+            ),
+        );
+    }
+}
+
+/// Таблица неприводимых многочленов и делителей `x^n + 1` степени p
+///
+/// Возвращает выбранный многочлен
+fn render_candidates(
+    ui: &mut egui::Ui,
+    config: &mut CodeConfig,
+    code: &CyclicCode,
+) -> Option<Vec<bool>> {
+    if config.p > MAX_CANDIDATE_DEGREE {
+        ui.label(format!(
+            "Перечисление кандидатов доступно при p ≤ {MAX_CANDIDATE_DEGREE}; \
+             введите многочлен вручную"
+        ));
+        return None;
+    }
+    let key = (config.p, config.n);
+    let editor = &mut config.polynomial_editor;
+    if editor
+        .candidates
+        .as_ref()
+        .is_none_or(|(cached, _)| *cached != key)
+    {
+        editor.candidates = Some((key, generator_candidates(config.p, config.n)));
+    }
+    let allow_shortened = editor.allow_shortened;
+    let candidates = &editor.candidates.as_ref()?.1;
+
+    ui.label(format!(
+        "Неприводимые многочлены степени {} и делители x^{} + 1:",
+        config.p, config.n
+    ));
+    let mut selected = None;
+    egui::ScrollArea::vertical()
+        .id_salt("cyclic_generator_candidates")
+        .max_height(250.0)
+        .show(ui, |ui| {
+            egui::Grid::new("cyclic_generator_candidates_grid")
+                .striped(true)
+                .show(ui, |ui| {
+                    for header in [
+                        "g(x)",
+                        "Двоичная",
+                        "Восьм.",
+                        "Неприводимый",
+                        "Примитивный",
+                        "Период",
+                        "Делит xⁿ + 1",
+                        "",
+                    ] {
+                        ui.label(egui::RichText::new(header).strong());
+                    }
+                    ui.end_row();
+
+                    let flag = |value: bool| if value { "да" } else { "нет" };
+                    for candidate in candidates {
+                        ui.label(format_polynomial(&candidate.polynomial));
+                        ui.monospace(format_binary(&candidate.polynomial));
+                        ui.monospace(format_octal(&candidate.polynomial));
+                        ui.label(flag(candidate.irreducible));
+                        ui.label(flag(candidate.primitive));
+                        ui.label(
+                            candidate
+                                .period
+                                .map_or_else(|| "-".to_string(), |period| period.to_string()),
+                        );
+                        ui.label(flag(candidate.divides));
+                        if candidate.polynomial == code.generator {
+                            ui.label("выбран");
+                        } else if ui
+                            .add_enabled(
+                                candidate.divides || allow_shortened,
+                                egui::Button::new("Выбрать"),
+                            )
+                            .on_disabled_hover_text(
+                                "g(x) не делит xⁿ + 1: разрешите укороченный код",
+                            )
+                            .clicked()
+                        {
+                            selected = Some(candidate.polynomial.clone());
+                        }
+                        ui.end_row();
+                    }
+                });
+        });
+    selected
+}
+
+/// Ввод многочлена в двоичной, восьмеричной или алгебраической записи
+fn render_free_entry(ui: &mut egui::Ui, config: &mut CodeConfig) {
+    let editor = &mut config.polynomial_editor;
+    let mut apply = false;
+    ui.horizontal(|ui| {
+        ui.label("Свой g(x):");
+        egui::ComboBox::from_id_salt("polynomial_notation")
+            .selected_text(editor.notation.label())
+            .show_ui(ui, |ui| {
+                for notation in PolynomialNotation::ALL {
+                    ui.selectable_value(&mut editor.notation, notation, notation.label());
+                }
+            });
+        ui.add(
+            egui::TextEdit::singleline(&mut editor.text)
+                .desired_width(200.0)
+                .font(egui::TextStyle::Monospace),
+        );
+        apply = ui.button("Применить").clicked();
+    });
+    ui.checkbox(
+        &mut editor.allow_shortened,
+        "Разрешить укороченный код (g(x) не делит xⁿ + 1)",
+    );
+
+    if apply {
+        let editor = &config.polynomial_editor;
+        let result = parse_polynomial(&editor.text, editor.notation).and_then(|generator| {
+            CyclicCode::with_generator(config.k, config.n, generator, editor.allow_shortened)
+        });
+        match result {
+            Ok(code) => {
+                config.polynomial_editor.status = Some(Ok(format!(
+                    "Применен g(x) = {} для ({}, {})-кода",
+                    format_polynomial(&code.generator),
+                    code.n,
+                    code.k
+                )));
+                config.set_cyclic_generator(Some(code.generator));
+            }
+            Err(err) => config.polynomial_editor.status = Some(Err(err)),
+        }
+    }
+
+    match config.polynomial_editor.status {
+        Some(Ok(ref message)) => {
+            ui.colored_label(egui::Color32::DARK_GREEN, message);
+        }
+        Some(Err(ref error)) => {
+            ui.colored_label(egui::Color32::RED, format!("Ошибка: {error}"));
+        }
+        None => {}
+    }
+}
@@ -0,0 +1,95 @@
+use crate::utils::validation::SUM_TOLERANCE;
+
+/// Цвет для подсветки некорректных значений
+const INVALID_COLOR: egui::Color32 = egui::Color32::from_rgb(220, 50, 50);
+
+/// Поле ввода одной вероятности
+fn probability_drag_value(value: &mut f64) -> egui::DragValue<'_> {
+    egui::DragValue::new(value)
+        .range(0.0..=1.0)
+        .speed(0.001)
+        .max_decimals(6)
+}
+
+/// Редактирование вектора вероятностей в виде сетки
+pub fn edit_probability_vector(
+    ui: &mut egui::Ui,
+    values: &mut [f64],
+    prefix: &str,
+    grid_id: &str,
+    items_per_row: usize,
+) {
+    egui::Grid::new(grid_id)
+        .num_columns(items_per_row * 2)
+        .show(ui, |ui| {
+            for (i, value) in values.iter_mut().enumerate() {
+                ui.label(format!("{prefix}{} =", i + 1));
+                ui.add(probability_drag_value(value));
+                if (i + 1) % items_per_row == 0 {
+                    ui.end_row();
+                }
+            }
+        });
+
+    let sum: f64 = values.iter().sum();
+    let text = egui::RichText::new(format!("Σ = {sum:.6}"));
+    ui.label(if (sum - 1.0).abs() > SUM_TOLERANCE {
+        text.color(INVALID_COLOR)
+    } else {
+        text
+    });
+}
+
+/// Редактирование матрицы вероятностей с контролем сумм строк
+pub fn edit_probability_matrix(
+    ui: &mut egui::Ui,
+    matrix: &mut [Vec<f64>],
+    title: &str,
+    scroll_id: &str,
+    grid_id: &str,
+) {
+    ui.label(egui::RichText::new(title).strong());
+
+    let columns = matrix.first().map_or(0, Vec::len);
+
+    egui::ScrollArea::horizontal()
+        .id_salt(scroll_id)
+        .max_width(ui.available_width())
+        .show(ui, |ui| {
+            egui::Grid::new(grid_id)
+                .num_columns(columns + 2)
+                .show(ui, |ui| {
+                    // Заголовки столбцов
+                    ui.label(egui::RichText::new("").strong());
+                    for j in 0..columns {
+                        ui.label(egui::RichText::new(format!("y{}", j + 1)).strong());
+                    }
+                    ui.label(egui::RichText::new("Σ").strong());
+                    ui.end_row();
+
+                    // Строки матрицы
+                    for (i, row) in matrix.iter_mut().enumerate() {
+                        ui.label(egui::RichText::new(format!("x{}", i + 1)).strong());
+                        for value in row.iter_mut() {
+                            ui.add(probability_drag_value(value));
+                        }
+
+                        let sum: f64 = row.iter().sum();
+                        let text = egui::RichText::new(format!("{sum:.5}"));
+                        ui.label(if (sum - 1.0).abs() > SUM_TOLERANCE {
+                            text.color(INVALID_COLOR)
+                        } else {
+                            text
+                        });
+                        ui.end_row();
+                    }
+                });
+        });
+}
+
+/// Вывод списка ошибок проверки
+pub fn show_validation_errors(ui: &mut egui::Ui, errors: &[String]) {
+    for error in errors {
+        ui.colored_label(INVALID_COLOR, error);
+    }
+}
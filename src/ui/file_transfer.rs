@@ -0,0 +1,170 @@
+use crate::state::code_config::{CodeConfig, DecodingOutcome};
+use crate::ui::labs4to6::outcome_color;
+use crate::utils::random::random_seed;
+use crate::utils::transmission::{FileJob, FileReport};
+use std::fs;
+use std::path::Path;
+
+/// Максимальный размер передаваемого файла
+const MAX_FILE_SIZE: u64 = 4 * 1024 * 1024;
+
+/// Рендеринг панели передачи файла
+pub fn render_file_transfer_panel(ui: &mut egui::Ui, config: &mut CodeConfig) {
+    ui.horizontal(|ui| {
+        if ui.button("Выбрать файл...").clicked()
+            && let Some(path) = rfd::FileDialog::new().pick_file()
+        {
+            config.file_transfer.source = Some(path);
+            config.file_transfer.report = None;
+            config.file_transfer.status = None;
+        }
+        match config.file_transfer.source {
+            Some(ref path) => ui.label(path.display().to_string()),
+            None => ui.label("Файл не выбран"),
+        };
+    });
+
+    render_file_job(ui, config);
+
+    if let Some(ref status) = config.file_transfer.status {
+        ui.label(status);
+    }
+
+    let Some((seed, ref report)) = config.file_transfer.report else {
+        return;
+    };
+
+    egui::Grid::new("labs4to6_file_report")
+        .striped(true)
+        .num_columns(2)
+        .show(ui, |ui| {
+            ui.label("Размер файла:");
+            ui.label(format!("{} байт", report.bytes));
+            ui.end_row();
+            ui.label("Блоков:");
+            ui.label(report.outcomes.len().to_string());
+            ui.end_row();
+            for outcome in DecodingOutcome::ALL {
+                ui.colored_label(
+                    outcome_color(outcome),
+                    format!("Блоков {}:", outcome.label()),
+                );
+                ui.label(report.count(outcome).to_string());
+                ui.end_row();
+            }
+            if let Some(raw_bit_errors) = report.raw_bit_errors {
+                ui.label("Ошибочных битов без исправления:");
+                ui.label(raw_bit_errors.to_string());
+                ui.end_row();
+            }
+            ui.label("Ошибочных битов после декодирования:");
+            ui.label(report.residual_bit_errors.to_string());
+            ui.end_row();
+            ui.label("Искаженных байтов после декодирования:");
+            ui.label(report.corrupted_bytes.to_string());
+            ui.end_row();
+        });
+
+    if ui.button("Сохранить отчет...").clicked()
+        && let Some(path) = rfd::FileDialog::new()
+            .add_filter("Текст", &["txt"])
+            .set_file_name("report.txt")
+            .save_file()
+    {
+        let text = report.to_text(config, seed);
+        config.file_transfer.status = Some(match fs::write(&path, text) {
+            Ok(()) => format!("Отчет сохранен: {}", path.display()),
+            Err(err) => format!("Не удалось сохранить отчет: {err}"),
+        });
+    }
+}
+
+/// Запуск, ход и остановка передачи в фоновом потоке
+fn render_file_job(ui: &mut egui::Ui, config: &mut CodeConfig) {
+    if let Some(ref job) = config.file_transfer.job {
+        let Some(result) = job.poll() else {
+            let mut stop = false;
+            ui.horizontal(|ui| {
+                ui.spinner();
+                ui.label(format!(
+                    "Передача: {} из {} блоков",
+                    job.progress(),
+                    job.total
+                ));
+                stop = ui.button("Остановить").clicked();
+            });
+            if stop {
+                job.cancel();
+            }
+            return;
+        };
+        let (source, seed) = (job.source.clone(), job.seed);
+        config.file_transfer.job = None;
+        let status = result.and_then(|(recovered, report)| {
+            save_recovered(config, &source, seed, recovered, report)
+        });
+        config.file_transfer.status = Some(status.unwrap_or_else(|err| format!("Ошибка: {err}")));
+    }
+
+    let can_run = config.can_run_experiments() && config.file_transfer.source.is_some();
+    if ui
+        .add_enabled(
+            can_run,
+            egui::Button::new("Передать и сохранить результат..."),
+        )
+        .clicked()
+        && let Some(path) = config.file_transfer.source.clone()
+    {
+        if config.randomize_seed {
+            config.seed = random_seed();
+        }
+        match read_source(&path) {
+            Ok(data) => {
+                config.file_transfer.status = None;
+                config.file_transfer.job = Some(FileJob::spawn(config, path, data, config.seed));
+            }
+            Err(err) => config.file_transfer.status = Some(format!("Ошибка: {err}")),
+        }
+    }
+}
+
+/// Чтение передаваемого файла с проверкой размера
+fn read_source(path: &Path) -> Result<Vec<u8>, String> {
+    let size = fs::metadata(path).map_err(|err| err.to_string())?.len();
+    if size > MAX_FILE_SIZE {
+        return Err(format!(
+            "файл слишком большой ({size} байт, допускается не более {MAX_FILE_SIZE})"
+        ));
+    }
+    fs::read(path).map_err(|err| err.to_string())
+}
+
+/// Сохранение отчета и запись восстановленного файла
+fn save_recovered(
+    config: &mut CodeConfig,
+    path: &Path,
+    seed: u64,
+    recovered: Vec<u8>,
+    report: FileReport,
+) -> Result<String, String> {
+    config.file_transfer.report = Some((seed, report));
+
+    let file_name = path.file_stem().map_or_else(
+        || "decoded".to_string(),
+        |stem| {
+            let extension = path
+                .extension()
+                .map(|ext| format!(".{}", ext.to_string_lossy()))
+                .unwrap_or_default();
+            format!("{}_decoded{extension}", stem.to_string_lossy())
+        },
+    );
+    let Some(output) = rfd::FileDialog::new().set_file_name(file_name).save_file() else {
+        return Ok("Передача выполнена, восстановленный файл не сохранен".to_string());
+    };
+    fs::write(&output, recovered).map_err(|err| err.to_string())?;
+    Ok(format!(
+        "Восстановленный файл сохранен: {}",
+        output.display()
+    ))
+}
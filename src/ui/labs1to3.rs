@@ -1,19 +1,37 @@
+use crate::formatting::csv::{CsvLocale, experiment_to_csv, parse_matrix, parse_vector};
 use crate::formatting::{display_matrix_compact, display_matrix_full};
 use crate::state::experiments::{ExperimentResult, Labs1To3State};
-use crate::ui::widgets::format_probabilities_row;
-use crate::utils::statistics::calculate_average;
+use crate::state::sweep::SweepJob;
+use crate::ui::editors::{
+    edit_probability_matrix, edit_probability_vector, show_validation_errors,
+};
+use crate::ui::plots::render_labs1to3_plots;
+use crate::ui::sweep::render_sweep_panel;
+use crate::ui::widgets::{format_probabilities_row, render_seed_controls};
+use crate::utils::capacity::blahut_arimoto;
+use crate::utils::random::{
+    experiment_seed, generate_error_probability_matrix, generate_probabilities,
+    generate_symbol_durations, generate_transition_matrix, random_seed, rng_from_seed,
+};
+use crate::utils::statistics::{calculate_average, calculate_min_max};
+use crate::utils::validation::{normalize, validate_distribution, validate_transition_matrix};
 use entropy_fx::{
     calc_entropy, calculate_average_duration, calculate_capacity_no_noise,
     calculate_capacity_with_noise, calculate_conditional_entropy,
     calculate_information_rate_no_noise, calculate_information_rate_with_noise,
     calculate_joint_probabilities, calculate_mutual_information, calculate_output_probabilities,
-    format_rate, generate_error_probability_matrix, generate_probabilities,
-    generate_symbol_durations, generate_transition_matrix, max_entropy,
+    format_rate, max_entropy,
 };
+use std::fs;
+use std::path::PathBuf;
 
 /// Рендеринг UI для Labs 1-3
 #[allow(clippy::too_many_lines)]
-pub fn render_labs1to3_ui(ui: &mut egui::Ui, state: &mut Labs1To3State) {
+pub fn render_labs1to3_ui(
+    ui: &mut egui::Ui,
+    state: &mut Labs1To3State,
+    sweep_job: &mut Option<SweepJob>,
+) {
     let title = if state.with_duration {
         "Обобщенные характеристики сигналов и каналов"
     } else {
@@ -24,14 +42,43 @@ pub fn render_labs1to3_ui(ui: &mut egui::Ui, state: &mut Labs1To3State) {
     render_experiment_parameters(ui, state);
     ui.separator();
 
-    if ui.button("Запустить эксперименты").clicked() {
+    let input_errors = if state.manual_input {
+        render_manual_input(ui, state);
+        ui.separator();
+        manual_input_errors(state)
+    } else {
+        vec![]
+    };
+
+    if ui
+        .add_enabled(
+            input_errors.is_empty(),
+            egui::Button::new("Запустить эксперименты"),
+        )
+        .clicked()
+    {
         run_experiments(state);
     }
 
+    if let Some(ref status) = state.csv_status {
+        ui.label(status);
+    }
+
+    egui::CollapsingHeader::new("Перебор параметра")
+        .id_salt("labs1to3_sweep")
+        .show(ui, |ui| {
+            render_sweep_panel(ui, state, sweep_job);
+        });
+
     ui.separator();
 
     if !state.results.is_empty() {
         render_statistics(ui, state);
+        egui::CollapsingHeader::new("Графики")
+            .id_salt("labs1to3_plots")
+            .show(ui, |ui| {
+                render_labs1to3_plots(ui, state);
+            });
         ui.separator();
         render_experiment_results(ui, state);
     }
@@ -39,10 +86,12 @@ pub fn render_labs1to3_ui(ui: &mut egui::Ui, state: &mut Labs1To3State) {
 
 /// Рендеринг параметров эксперимента
 fn render_experiment_parameters(ui: &mut egui::Ui, state: &mut Labs1To3State) {
-    ui.horizontal(|ui| {
-        ui.label("Количество экспериментов:");
-        ui.add(egui::DragValue::new(&mut state.experiments).range(1..=100));
-    });
+    if !state.manual_input {
+        ui.horizontal(|ui| {
+            ui.label("Количество экспериментов:");
+            ui.add(egui::DragValue::new(&mut state.experiments).range(1..=100));
+        });
+    }
 
     ui.horizontal(|ui| {
         ui.label("Количество дискретных сообщений (p_i):");
@@ -58,7 +107,47 @@ fn render_experiment_parameters(ui: &mut egui::Ui, state: &mut Labs1To3State) {
         ui.checkbox(&mut state.compact_view, "Сокращенный вид матриц");
     });
 
-    if state.with_noise && !state.with_duration {
+    ui.checkbox(
+        &mut state.manual_input,
+        "Ручной ввод вероятностей p_i и матрицы переходов",
+    );
+
+    ui.horizontal(|ui| {
+        ui.label("Формат CSV:");
+        egui::ComboBox::from_id_salt("csv_locale")
+            .selected_text(state.csv_locale.label())
+            .show_ui(ui, |ui| {
+                for locale in [CsvLocale::Russian, CsvLocale::English] {
+                    ui.selectable_value(&mut state.csv_locale, locale, locale.label());
+                }
+            });
+    });
+
+    if state.with_noise {
+        ui.horizontal(|ui| {
+            ui.label("Блахут–Аримото: точность");
+            ui.add(
+                egui::Slider::new(&mut state.capacity_tolerance, 1e-12..=1e-2)
+                    .logarithmic(true)
+                    .custom_formatter(|value, _| format!("{value:.0e}")),
+            );
+            ui.label("макс. итераций");
+            ui.add(egui::DragValue::new(&mut state.capacity_max_iterations).range(1..=100_000));
+        });
+    }
+
+    if state.with_duration {
+        ui.horizontal(|ui| {
+            ui.label("Масштаб длительностей символов:");
+            ui.add(
+                egui::DragValue::new(&mut state.duration_scale)
+                    .range(0.01..=100.0)
+                    .speed(0.01),
+            );
+        });
+    }
+
+    if state.with_noise && !state.with_duration && !state.manual_input {
         ui.horizontal(|ui| {
             ui.label("Минимальный порог достоверности:");
             ui.add(
@@ -68,97 +157,313 @@ fn render_experiment_parameters(ui: &mut egui::Ui, state: &mut Labs1To3State) {
             );
         });
     }
+
+    render_seed_controls(ui, &mut state.seed, &mut state.randomize_seed);
+}
+
+/// Рендеринг ручного ввода вероятностей и матрицы переходов
+fn render_manual_input(ui: &mut egui::Ui, state: &mut Labs1To3State) {
+    state.resize_manual_input();
+
+    egui::ScrollArea::vertical()
+        .id_salt("manual_input_scroll")
+        .max_height(ui.available_height() * 0.4)
+        .show(ui, |ui| {
+            ui.label(egui::RichText::new("Вероятности сообщений на входе (p_i):").strong());
+            edit_probability_vector(
+                ui,
+                &mut state.manual_input_probs,
+                "x",
+                "grid_manual_input_probs",
+                4,
+            );
+            ui.horizontal(|ui| {
+                if ui.button("Нормировать p_i").clicked() {
+                    normalize(&mut state.manual_input_probs);
+                }
+                if ui.button("Импорт p_i из CSV...").clicked() {
+                    import_input_probs(state);
+                }
+            });
+
+            if state.with_noise {
+                ui.add_space(4.0);
+                edit_probability_matrix(
+                    ui,
+                    &mut state.manual_transition_matrix,
+                    "Матрица переходов p(y_j/x_i):",
+                    "scroll_manual_transition",
+                    "grid_manual_transition",
+                );
+                ui.horizontal(|ui| {
+                    if ui.button("Нормировать строки матрицы").clicked() {
+                        for row in &mut state.manual_transition_matrix {
+                            normalize(row);
+                        }
+                    }
+                    if ui.button("Импорт матрицы из CSV...").clicked() {
+                        import_transition_matrix(state);
+                    }
+                });
+            }
+        });
+
+    show_validation_errors(ui, &manual_input_errors(state));
+}
+
+/// Выбор CSV-файла и чтение его содержимого
+fn pick_csv_file() -> Option<(PathBuf, Result<String, String>)> {
+    let path = rfd::FileDialog::new()
+        .add_filter("CSV", &["csv"])
+        .pick_file()?;
+    let text = fs::read_to_string(&path).map_err(|err| err.to_string());
+    Some((path, text))
+}
+
+/// Проверка размера импортированных данных
+fn check_imported_size(size: usize) -> Result<usize, String> {
+    if (2..=128).contains(&size) {
+        Ok(size)
+    } else {
+        Err(format!(
+            "число сообщений {size} вне допустимого диапазона 2..=128"
+        ))
+    }
+}
+
+/// Импорт вероятностей `p_i` из CSV
+fn import_input_probs(state: &mut Labs1To3State) {
+    let Some((path, text)) = pick_csv_file() else {
+        return;
+    };
+    let imported = text
+        .and_then(|text| parse_vector(&text, state.csv_locale))
+        .and_then(|probs| check_imported_size(probs.len()).map(|_| probs));
+
+    state.csv_status = Some(match imported {
+        Ok(probs) => {
+            state.signals = probs.len();
+            state.manual_input_probs = probs;
+            state.resize_manual_input();
+            format!("Вероятности p_i загружены из {}", path.display())
+        }
+        Err(err) => format!("Не удалось импортировать {}: {err}", path.display()),
+    });
+}
+
+/// Импорт матрицы переходов из CSV
+fn import_transition_matrix(state: &mut Labs1To3State) {
+    let Some((path, text)) = pick_csv_file() else {
+        return;
+    };
+    let imported = text
+        .and_then(|text| parse_matrix(&text, state.csv_locale))
+        .and_then(|matrix| check_imported_size(matrix.len()).map(|_| matrix));
+
+    state.csv_status = Some(match imported {
+        Ok(matrix) => {
+            state.signals = matrix.len();
+            state.manual_transition_matrix = matrix;
+            state.resize_manual_input();
+            format!("Матрица переходов загружена из {}", path.display())
+        }
+        Err(err) => format!("Не удалось импортировать {}: {err}", path.display()),
+    });
+}
+
+/// Экспорт результата эксперимента в CSV
+fn export_experiment(state: &mut Labs1To3State, index: usize) {
+    let Some(result) = state.results.get(index) else {
+        return;
+    };
+    let Some(path) = rfd::FileDialog::new()
+        .add_filter("CSV", &["csv"])
+        .set_file_name(format!("experiment_{}.csv", index + 1))
+        .save_file()
+    else {
+        return;
+    };
+
+    let csv = experiment_to_csv(result, state.csv_locale);
+    state.csv_status = Some(match fs::write(&path, csv) {
+        Ok(()) => format!(
+            "Эксперимент #{} экспортирован в {}",
+            index + 1,
+            path.display()
+        ),
+        Err(err) => format!("Не удалось записать {}: {err}", path.display()),
+    });
+}
+
+/// Ошибки проверки вручную введенных данных
+fn manual_input_errors(state: &Labs1To3State) -> Vec<String> {
+    let mut errors = vec![];
+    if let Err(err) = validate_distribution(&state.manual_input_probs) {
+        errors.push(format!("Вероятности p_i: {err}"));
+    }
+    if state.with_noise
+        && let Err(matrix_errors) =
+            validate_transition_matrix(&state.manual_transition_matrix, state.signals)
+    {
+        errors.extend(
+            matrix_errors
+                .into_iter()
+                .map(|err| format!("Матрица переходов: {err}")),
+        );
+    }
+    errors
+}
+
+/// Параметры генерации одного эксперимента
+///
+/// При переборе параметра значения подменяются для каждой точки
+#[derive(Clone, Copy)]
+pub struct ExperimentParams {
+    pub signals: usize,
+    pub min_threshold: f64,
+    pub duration_scale: f64,
+    pub manual_input: bool,
+}
+
+impl ExperimentParams {
+    /// Параметры, заданные в интерфейсе
+    #[must_use]
+    pub const fn from_state(state: &Labs1To3State) -> Self {
+        Self {
+            signals: state.signals,
+            min_threshold: state.min_threshold,
+            duration_scale: state.duration_scale,
+            manual_input: state.manual_input,
+        }
+    }
 }
 
 /// Запуск экспериментов
+///
+/// При ручном вводе выполняется один расчет по введенным данным
 fn run_experiments(state: &mut Labs1To3State) {
     state.results.clear();
-    for _ in 0..state.experiments {
-        let input_probs = generate_probabilities(state.signals);
-        let input_entropy = calc_entropy(&input_probs);
+    if state.randomize_seed {
+        state.seed = random_seed();
+    }
+    let experiments = if state.manual_input {
+        1
+    } else {
+        state.experiments
+    };
+    let params = ExperimentParams::from_state(state);
+    state.results = (0..experiments)
+        .map(|i| compute_experiment(state, &params, experiment_seed(state.seed, i)))
+        .collect();
+}
 
-        let symbol_durations = if state.with_duration {
-            generate_symbol_durations(state.signals)
-        } else {
-            vec![]
-        };
-        let avg_duration = if state.with_duration {
-            calculate_average_duration(&input_probs, &symbol_durations)
+/// Расчет одного эксперимента
+#[allow(clippy::too_many_lines)]
+#[must_use]
+pub fn compute_experiment(
+    state: &Labs1To3State,
+    params: &ExperimentParams,
+    seed: u64,
+) -> ExperimentResult {
+    let signals = params.signals;
+    let mut rng = rng_from_seed(seed);
+
+    let input_probs = if params.manual_input {
+        state.manual_input_probs.clone()
+    } else {
+        generate_probabilities(signals, &mut rng)
+    };
+    let input_entropy = calc_entropy(&input_probs);
+
+    let symbol_durations: Vec<f64> = if state.with_duration {
+        generate_symbol_durations(signals, &mut rng)
+            .into_iter()
+            .map(|duration| duration * params.duration_scale)
+            .collect()
+    } else {
+        vec![]
+    };
+    let avg_duration = if state.with_duration {
+        calculate_average_duration(&input_probs, &symbol_durations)
+    } else {
+        0.0
+    };
+
+    let information_rate_no_noise = if state.with_duration {
+        calculate_information_rate_no_noise(input_entropy, avg_duration)
+    } else {
+        0.0
+    };
+    let capacity_no_noise = if state.with_duration {
+        calculate_capacity_no_noise(signals, avg_duration)
+    } else {
+        0.0
+    };
+
+    if state.with_noise {
+        let transition_matrix = if params.manual_input {
+            state.manual_transition_matrix.clone()
+        } else if state.with_duration {
+            generate_error_probability_matrix(signals, &mut rng)
         } else {
-            0.0
+            generate_transition_matrix(signals, params.min_threshold, &mut rng)
         };
+        let output_probs = calculate_output_probabilities(&input_probs, &transition_matrix);
+        let joint_probs =
+            calculate_joint_probabilities(&input_probs, &output_probs, &transition_matrix);
+        let conditional_entropy = calculate_conditional_entropy(&joint_probs, &transition_matrix);
+        let mutual_information = calculate_mutual_information(input_entropy, conditional_entropy);
+        let channel_capacity = blahut_arimoto(
+            &transition_matrix,
+            state.capacity_tolerance,
+            state.capacity_max_iterations,
+        );
 
-        let information_rate_no_noise = if state.with_duration {
-            calculate_information_rate_no_noise(input_entropy, avg_duration)
+        let information_rate_with_noise = if state.with_duration {
+            calculate_information_rate_with_noise(input_entropy, conditional_entropy, avg_duration)
         } else {
             0.0
         };
-        let capacity_no_noise = if state.with_duration {
-            calculate_capacity_no_noise(state.signals, avg_duration)
+        let capacity_with_noise = if state.with_duration {
+            calculate_capacity_with_noise(signals, conditional_entropy, avg_duration)
         } else {
             0.0
         };
 
-        if state.with_noise {
-            let transition_matrix = if state.with_duration {
-                generate_error_probability_matrix(state.signals)
-            } else {
-                generate_transition_matrix(state.signals, state.min_threshold)
-            };
-            let output_probs = calculate_output_probabilities(&input_probs, &transition_matrix);
-            let joint_probs =
-                calculate_joint_probabilities(&input_probs, &output_probs, &transition_matrix);
-            let conditional_entropy =
-                calculate_conditional_entropy(&joint_probs, &transition_matrix);
-            let mutual_information =
-                calculate_mutual_information(input_entropy, conditional_entropy);
-
-            let information_rate_with_noise = if state.with_duration {
-                calculate_information_rate_with_noise(
-                    input_entropy,
-                    conditional_entropy,
-                    avg_duration,
-                )
-            } else {
-                0.0
-            };
-            let capacity_with_noise = if state.with_duration {
-                calculate_capacity_with_noise(state.signals, conditional_entropy, avg_duration)
-            } else {
-                0.0
-            };
-
-            state.results.push(ExperimentResult {
-                input_probs,
-                transition_matrix,
-                output_probs,
-                joint_probs,
-                input_entropy,
-                conditional_entropy,
-                mutual_information,
-                symbol_durations,
-                avg_duration,
-                information_rate_no_noise,
-                capacity_no_noise,
-                information_rate_with_noise,
-                capacity_with_noise,
-            });
-        } else {
-            state.results.push(ExperimentResult {
-                input_probs,
-                transition_matrix: vec![],
-                output_probs: vec![],
-                joint_probs: vec![],
-                input_entropy,
-                conditional_entropy: 0.0,
-                mutual_information: input_entropy,
-                symbol_durations,
-                avg_duration,
-                information_rate_no_noise,
-                capacity_no_noise,
-                information_rate_with_noise: 0.0,
-                capacity_with_noise: 0.0,
-            });
+        ExperimentResult {
+            seed,
+            input_probs,
+            transition_matrix,
+            output_probs,
+            joint_probs,
+            input_entropy,
+            conditional_entropy,
+            mutual_information,
+            symbol_durations,
+            avg_duration,
+            information_rate_no_noise,
+            capacity_no_noise,
+            information_rate_with_noise,
+            capacity_with_noise,
+            channel_capacity: Some(channel_capacity),
+        }
+    } else {
+        ExperimentResult {
+            seed,
+            input_probs,
+            transition_matrix: vec![],
+            output_probs: vec![],
+            joint_probs: vec![],
+            input_entropy,
+            conditional_entropy: 0.0,
+            mutual_information: input_entropy,
+            symbol_durations,
+            avg_duration,
+            information_rate_no_noise,
+            capacity_no_noise,
+            information_rate_with_noise: 0.0,
+            capacity_with_noise: 0.0,
+            channel_capacity: None,
         }
     }
 }
@@ -166,11 +471,13 @@ fn run_experiments(state: &mut Labs1To3State) {
 /// Рендеринг статистики
 fn render_statistics(ui: &mut egui::Ui, state: &Labs1To3State) {
     let avg_mutual_info = calculate_average(&state.results, |r| r.mutual_information);
+    let (min_mutual_info, max_mutual_info) =
+        calculate_min_max(&state.results, |r| r.mutual_information);
     let avg_input_entropy = calculate_average(&state.results, |r| r.input_entropy);
 
     ui.label(
         egui::RichText::new(format!(
-            "Среднее количество информации: {avg_mutual_info:.5}"
+            "Среднее количество информации: {avg_mutual_info:.5} (мин. {min_mutual_info:.5}, макс. {max_mutual_info:.5})"
         ))
         .strong(),
     );
@@ -193,6 +500,16 @@ fn render_statistics(ui: &mut egui::Ui, state: &Labs1To3State) {
             ))
             .strong(),
         );
+
+        let avg_channel_capacity = calculate_average(&state.results, |r| {
+            r.channel_capacity.as_ref().map_or(0.0, |c| c.capacity)
+        });
+        ui.label(
+            egui::RichText::new(format!(
+                "Средняя пропускная способность канала C: {avg_channel_capacity:.5} бит/символ"
+            ))
+            .strong(),
+        );
     }
 
     if state.with_duration {
@@ -247,26 +564,38 @@ fn render_statistics(ui: &mut egui::Ui, state: &Labs1To3State) {
 }
 
 /// Рендеринг результатов экспериментов
-fn render_experiment_results(ui: &mut egui::Ui, state: &Labs1To3State) {
+fn render_experiment_results(ui: &mut egui::Ui, state: &mut Labs1To3State) {
+    let mut export_index = None;
+
     egui::ScrollArea::vertical()
         .id_salt("experiments_scroll")
         .auto_shrink([false; 2])
         .max_height(ui.available_height())
         .show(ui, |ui| {
             for (i, result) in state.results.iter().enumerate() {
-                ui.collapsing(format!("Эксперимент #{}", i + 1), |ui| {
-                    render_single_experiment(
-                        ui,
-                        result,
-                        i,
-                        state.signals,
-                        state.compact_view,
-                        state.with_duration,
-                        state.with_noise,
-                    );
-                });
+                ui.collapsing(
+                    format!("Эксперимент #{} (seed {})", i + 1, result.seed),
+                    |ui| {
+                        if ui.button("Экспорт в CSV...").clicked() {
+                            export_index = Some(i);
+                        }
+                        render_single_experiment(
+                            ui,
+                            result,
+                            i,
+                            state.signals,
+                            state.compact_view,
+                            state.with_duration,
+                            state.with_noise,
+                        );
+                    },
+                );
             }
         });
+
+    if let Some(index) = export_index {
+        export_experiment(state, index);
+    }
 }
 
 /// Рендеринг одного эксперимента
@@ -386,6 +715,30 @@ fn render_single_experiment(
             .strong(),
         );
 
+        if let Some(ref capacity) = result.channel_capacity {
+            ui.add_space(4.0);
+            ui.label(
+                egui::RichText::new(format!(
+                    "Пропускная способность канала C (Блахут–Аримото): {:.5} бит/символ",
+                    capacity.capacity
+                ))
+                .strong(),
+            );
+            ui.label(format!(
+                "Итераций: {}{}",
+                capacity.iterations,
+                if capacity.converged {
+                    ""
+                } else {
+                    " (заданная точность не достигнута)"
+                }
+            ));
+            ui.label(egui::RichText::new("Оптимальное распределение на входе:").strong());
+            for row in format_probabilities_row(&capacity.input_distribution, "x", 4) {
+                ui.label(row);
+            }
+        }
+
         if with_duration {
             ui.add_space(4.0);
             ui.label(
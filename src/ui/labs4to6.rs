@@ -1,20 +1,35 @@
+use crate::coding::bch::{BchCode, BchErrorInfo};
+use crate::coding::convolutional::ViterbiErrorInfo;
+use crate::coding::cyclic::CyclicDecoder;
+use crate::coding::golay::{GolayCode, GolayErrorInfo};
+use crate::coding::ldpc::{LdpcErrorInfo, LdpcReport};
+use crate::coding::reed_solomon::RsErrorInfo;
+use crate::formatting::polynomial::format_polynomial;
 use crate::formatting::{display_binary_matrix_compact, display_binary_matrix_full, format_bits};
+use crate::state::channel::ChannelModel;
 use crate::state::code_config::{
-    CodeConfig, CodeType, ErrorInfoType, Labs4To6ExperimentResult, SelectedCodeType,
+    CodeConfig, CodeType, DecodingOutcome, ErrorInfoType, Labs4To6ExperimentResult,
+    SelectedCodeType,
 };
-use crate::ui::widgets::add_label;
-use entropy_fx::coding::cyclic::{
-    compute_syndrome_cyclic, decode_cyclic, encode_cyclic, inject_single_error_cyclic,
-    polynomial_to_bits,
+use crate::ui::analysis::render_code_analysis_panel;
+use crate::ui::bch::render_bch_decoding;
+use crate::ui::ber::render_ber_panel;
+use crate::ui::convolutional::{render_convolutional_parameters, render_trellis};
+use crate::ui::coverage::render_coverage_panel;
+use crate::ui::custom_code::render_generator_editor;
+use crate::ui::cyclic_generator::render_polynomial_selector;
+use crate::ui::file_transfer::render_file_transfer_panel;
+use crate::ui::ldpc::{render_ldpc_iterations, render_ldpc_parameters};
+use crate::ui::lfsr::render_lfsr_window;
+use crate::ui::manual_errors::render_manual_injection_window;
+use crate::ui::message::render_message_panel;
+use crate::ui::reed_solomon::{
+    render_rs_decoding, render_rs_parameters, render_symbol_errors, render_symbol_view,
 };
-use entropy_fx::coding::hamming::{
-    add_parity_bit, compute_syndrome_hamming, decode_hamming, encode_hamming,
-    generate_error_multiplicity, inject_errors,
-};
-use entropy_fx::coding::systematic::{
-    compute_syndrome, correct_error, encode_message, inject_single_error,
-};
-use rand::Rng;
+use crate::ui::shift_decoder::render_shift_trace;
+use crate::ui::widgets::{add_label, render_seed_controls};
+use crate::utils::random::{experiment_seed, generate_message, random_seed, rng_from_seed};
+use entropy_fx::coding::systematic::BinaryMatrix;
 
 /// Рендеринг UI для Labs 4-6
 #[allow(clippy::too_many_lines)]
@@ -26,6 +41,14 @@ pub fn render_labs4to6_ui(
     let title = match config.code_type {
         SelectedCodeType::Hamming => "Лабораторные работы 4-6: Код Хемминга",
         SelectedCodeType::Cyclic => "Лабораторные работы 4-6: Циклический код",
+        SelectedCodeType::Bch => "Лабораторные работы 4-6: Код БЧХ",
+        SelectedCodeType::ReedSolomon => "Лабораторные работы 4-6: Код Рида–Соломона",
+        SelectedCodeType::Convolutional => "Лабораторные работы 4-6: Сверточный код",
+        SelectedCodeType::Golay => "Лабораторные работы 4-6: Код Голея (23, 12)",
+        SelectedCodeType::ExtendedGolay => {
+            "Лабораторные работы 4-6: Расширенный код Голея (24, 12)"
+        }
+        SelectedCodeType::Ldpc => "Лабораторные работы 4-6: LDPC-код",
         SelectedCodeType::Systematic => {
             "Лабораторные работы 4-6: Систематический помехоустойчивый код"
         }
@@ -38,11 +61,75 @@ pub fn render_labs4to6_ui(
 
     ui.separator();
 
-    if config.can_run_experiments() && ui.button("Запустить эксперименты").clicked()
-    {
-        *results = run_experiments(config);
+    ui.horizontal(|ui| {
+        if config.can_run_experiments() && ui.button("Запустить эксперименты").clicked()
+        {
+            if config.randomize_seed {
+                config.seed = random_seed();
+            }
+            *results = run_experiments(config);
+        }
+        if config.can_run_experiments() && ui.button("Ручное внесение ошибок").clicked()
+        {
+            config.manual_injection.open = true;
+        }
+        if config.code_type == SelectedCodeType::Cyclic
+            && config.can_run_experiments()
+            && ui.button("Регистр сдвига по шагам").clicked()
+        {
+            config.lfsr.open = true;
+        }
+    });
+
+    render_manual_injection_window(ui.ctx(), config);
+    render_lfsr_window(ui.ctx(), config);
+
+    if config.code_type == SelectedCodeType::Systematic {
+        egui::CollapsingHeader::new("Собственная порождающая матрица")
+            .id_salt("labs4to6_custom_generator")
+            .show(ui, |ui| {
+                render_generator_editor(ui, config);
+            });
     }
 
+    if config.code_type == SelectedCodeType::Cyclic {
+        egui::CollapsingHeader::new("Порождающий многочлен g(x)")
+            .id_salt("labs4to6_cyclic_generator")
+            .show(ui, |ui| {
+                render_polynomial_selector(ui, config);
+            });
+    }
+
+    egui::CollapsingHeader::new("Передача сообщения")
+        .id_salt("labs4to6_message")
+        .show(ui, |ui| {
+            render_message_panel(ui, config);
+        });
+
+    egui::CollapsingHeader::new("Передача файла")
+        .id_salt("labs4to6_file")
+        .show(ui, |ui| {
+            render_file_transfer_panel(ui, config);
+        });
+
+    egui::CollapsingHeader::new("Анализ кода")
+        .id_salt("labs4to6_analysis")
+        .show(ui, |ui| {
+            render_code_analysis_panel(ui, config);
+        });
+
+    egui::CollapsingHeader::new("Покрытие шаблонов ошибок")
+        .id_salt("labs4to6_coverage")
+        .show(ui, |ui| {
+            render_coverage_panel(ui, config);
+        });
+
+    egui::CollapsingHeader::new("Кривые BER/WER")
+        .id_salt("labs4to6_ber")
+        .show(ui, |ui| {
+            render_ber_panel(ui, config);
+        });
+
     ui.separator();
 
     render_code_matrices(ui, config);
@@ -58,133 +145,376 @@ fn render_code_type_selector(ui: &mut egui::Ui, config: &mut CodeConfig) {
     ui.horizontal(|ui| {
         ui.label("Тип кода:");
 
-        let mut systematic_selected = config.code_type == SelectedCodeType::Systematic;
-        let mut hamming_selected = config.code_type == SelectedCodeType::Hamming;
-        let mut cyclic_selected = config.code_type == SelectedCodeType::Cyclic;
-
-        let systematic_clicked = ui
-            .checkbox(&mut systematic_selected, "Систематический код")
-            .clicked();
-        let hamming_clicked = ui.checkbox(&mut hamming_selected, "Код Хемминга").clicked();
-        let cyclic_clicked = ui
-            .checkbox(&mut cyclic_selected, "Циклический код")
-            .clicked();
-
-        // Обработка кликов - только один тип может быть выбран
-        if systematic_clicked && systematic_selected {
-            config.set_code_type(SelectedCodeType::Systematic);
-        } else if hamming_clicked && hamming_selected {
-            config.set_code_type(SelectedCodeType::Hamming);
-        } else if cyclic_clicked && cyclic_selected {
-            config.set_code_type(SelectedCodeType::Cyclic);
-        } else if !systematic_selected && !hamming_selected && !cyclic_selected {
-            // Если все сняты, выбираем систематический код по умолчанию
-            config.set_code_type(SelectedCodeType::Systematic);
+        // Только один тип может быть выбран; если выбор снят, выбирается систематический код
+        let mut selected = None;
+        for code_type in SelectedCodeType::ALL {
+            let mut checked = config.code_type == code_type;
+            if ui.checkbox(&mut checked, code_type.label()).clicked() {
+                selected = Some(if checked {
+                    code_type
+                } else {
+                    SelectedCodeType::Systematic
+                });
+            }
+        }
+        if let Some(code_type) = selected {
+            config.set_code_type(code_type);
         }
     });
 }
 
 /// Рендеринг параметров кода
 fn render_code_parameters(ui: &mut egui::Ui, config: &mut CodeConfig) {
-    ui.horizontal(|ui| {
-        ui.label("k (длина сообщения):");
-        let response = ui.add(egui::DragValue::new(&mut config.k).range(1..=200));
-        if response.changed() {
-            config.update_n_and_p();
+    match config.code_type {
+        SelectedCodeType::Bch => render_bch_parameters(ui, config),
+        SelectedCodeType::ReedSolomon => render_rs_parameters(ui, config),
+        SelectedCodeType::Ldpc => render_ldpc_parameters(ui, config),
+        SelectedCodeType::Golay | SelectedCodeType::ExtendedGolay => {
+            if let Some(ref code) = config.golay_code {
+                ui.label(format!(
+                    "k = {} (фиксировано), d = {}, исправляются ошибки веса до {}; \
+                     исправимых синдромов {} из {}",
+                    GolayCode::K,
+                    if code.extended { 8 } else { 7 },
+                    GolayCode::T,
+                    code.correctable_syndromes(),
+                    1 << code.linear.p()
+                ));
+            }
         }
-    });
+        _ => {
+            ui.horizontal(|ui| {
+                ui.label("k (длина сообщения):");
+                let response = ui.add(egui::DragValue::new(&mut config.k).range(1..=200));
+                if response.changed() {
+                    config.update_n_and_p();
+                }
+            });
+        }
+    }
+    if config.code_type == SelectedCodeType::Convolutional {
+        render_convolutional_parameters(ui, config);
+    }
 
     ui.horizontal(|ui| {
         ui.label(format!("n (длина кодового слова): {}", config.n));
         ui.label(format!("p (число проверочных разрядов): {}", config.p));
     });
 
+    if config.code_type == SelectedCodeType::Cyclic {
+        ui.horizontal(|ui| {
+            ui.label("Декодер:");
+            egui::ComboBox::from_id_salt("cyclic_decoder")
+                .selected_text(config.cyclic_decoder.label())
+                .show_ui(ui, |ui| {
+                    for decoder in CyclicDecoder::ALL {
+                        ui.selectable_value(&mut config.cyclic_decoder, decoder, decoder.label());
+                    }
+                });
+        });
+    }
+
     ui.horizontal(|ui| {
         ui.label("Количество экспериментов:");
         ui.add(egui::DragValue::new(&mut config.experiments).range(1..=100));
     });
 
-    if config.code_type != SelectedCodeType::Hamming {
+    render_seed_controls(ui, &mut config.seed, &mut config.randomize_seed);
+
+    render_channel_parameters(ui, config);
+
+    if matches!(
+        config.code_type,
+        SelectedCodeType::Systematic | SelectedCodeType::Ldpc
+    ) || config.code_type.is_golay()
+    {
         ui.horizontal(|ui| {
-            ui.label("Вероятность ошибки:");
-            ui.add(
-                egui::DragValue::new(&mut config.error_probability)
-                    .range(0.0..=1.0)
-                    .speed(0.01),
-            );
+            ui.checkbox(&mut config.compact_view, "Сокращенный вид матриц");
         });
+    }
+}
+
+/// Рендеринг модели канала и ее параметров
+fn render_channel_parameters(ui: &mut egui::Ui, config: &mut CodeConfig) {
+    ui.horizontal(|ui| {
+        ui.label("Модель канала:");
+        egui::ComboBox::from_id_salt("channel_model")
+            .selected_text(config.channel.model.label())
+            .show_ui(ui, |ui| {
+                for model in ChannelModel::ALL {
+                    ui.selectable_value(&mut config.channel.model, model, model.label());
+                }
+            });
+    });
 
-        if config.code_type == SelectedCodeType::Systematic {
+    match config.channel.model {
+        ChannelModel::Classic if config.code_type == SelectedCodeType::ReedSolomon => {
+            render_symbol_errors(ui, config);
+        }
+        ChannelModel::Classic
+            if config.code_type != SelectedCodeType::Hamming && !config.code_type.is_golay() =>
+        {
             ui.horizontal(|ui| {
-                ui.checkbox(&mut config.compact_view, "Сокращенный вид матриц");
+                ui.label("Вероятность ошибки:");
+                ui.add(
+                    egui::DragValue::new(&mut config.error_probability)
+                        .range(0.0..=1.0)
+                        .speed(0.01),
+                );
+            });
+        }
+        ChannelModel::Classic => {}
+        ChannelModel::Burst => render_burst_parameters(ui, config),
+        ChannelModel::GilbertElliott => render_gilbert_elliott_parameters(ui, config),
+        ChannelModel::Bsc => {
+            ui.horizontal(|ui| {
+                ui.label("Вероятность инверсии бита p:");
+                ui.add(
+                    egui::DragValue::new(&mut config.channel.bit_error_probability)
+                        .range(0.0..=1.0)
+                        .speed(0.001),
+                );
+                #[allow(clippy::cast_precision_loss)]
+                let expected = config.channel.bit_error_probability * config.n as f64;
+                ui.label(format!("(в среднем {expected:.2} ошибок на слово)"));
             });
         }
     }
 }
 
-/// Рендеринг матриц кода
-fn render_code_matrices(ui: &mut egui::Ui, config: &CodeConfig) {
-    if config.code_type == SelectedCodeType::Systematic
-        && let Some(ref code) = config.systematic_code
-    {
-        egui::ScrollArea::vertical()
-            .id_salt("labs4to6_matrices_scroll")
-            .auto_shrink([false; 2])
-            .max_height(ui.available_height() * 0.7)
-            .show(ui, |ui| {
-                if config.compact_view {
-                    display_binary_matrix_compact(
-                        ui,
-                        &code.generator,
-                        &format!(
-                            "Производящая матрица P (k={}, n={}, p={}):",
-                            code.k, code.n, code.p
-                        ),
-                        "scroll_generator",
-                        "grid_generator",
-                    );
-                } else {
-                    display_binary_matrix_full(
-                        ui,
-                        &code.generator,
-                        &format!(
-                            "Производящая матрица P (k={}, n={}, p={}):",
-                            code.k, code.n, code.p
-                        ),
-                        "scroll_generator",
-                        "grid_generator",
-                    );
-                }
+/// Рендеринг параметров кода БЧХ: m и конструктивное расстояние δ задают n и k
+fn render_bch_parameters(ui: &mut egui::Ui, config: &mut CodeConfig) {
+    let parameters = &mut config.bch_parameters;
+    let mut changed = false;
+    ui.horizontal(|ui| {
+        ui.label("m (поле GF(2^m)):");
+        changed |= ui
+            .add(egui::DragValue::new(&mut parameters.m).range(BchCode::MIN_M..=BchCode::MAX_M))
+            .changed();
+        let n = (1 << parameters.m) - 1;
+        ui.label("Конструктивное расстояние δ:");
+        changed |= ui
+            .add(egui::DragValue::new(&mut parameters.designed_distance).range(3..=n))
+            .changed();
+        parameters.designed_distance = parameters.designed_distance.clamp(3, n);
+    });
+    if changed {
+        config.update_n_and_p();
+        config.analysis = None;
+        config.coverage = None;
+    }
 
-                ui.add_space(8.0);
+    if let Some(ref code) = config.bch_code {
+        ui.label(format!(
+            "({}, {})-код, исправляет t = {} ошибок; поле GF(2^{}) построено по {}",
+            code.cyclic.n,
+            code.cyclic.k,
+            code.t,
+            code.field.m,
+            format_polynomial(&code.field.primitive)
+        ));
+        ui.label(format!(
+            "g(x) = {}",
+            format_polynomial(&code.cyclic.generator)
+        ));
+        let minimal: Vec<String> = code
+            .minimal_polynomials
+            .iter()
+            .map(|(power, poly)| format!("m{power}(x) = {}", format_polynomial(poly)))
+            .collect();
+        ui.label(format!(
+            "g(x) = НОК минимальных многочленов α^1..α^{}: {}",
+            code.designed_distance - 1,
+            minimal.join(", ")
+        ));
+    }
+}
 
-                if config.compact_view {
-                    display_binary_matrix_compact(
-                        ui,
-                        &code.parity_check,
-                        &format!("Проверочная матрица H (p={}, n={}):", code.p, code.n),
-                        "scroll_parity_check",
-                        "grid_parity_check",
-                    );
+/// Рендеринг параметров пакета ошибок
+fn render_burst_parameters(ui: &mut egui::Ui, config: &mut CodeConfig) {
+    let length = config.n + usize::from(config.code_type == SelectedCodeType::Hamming);
+    let channel = &mut config.channel;
+    ui.horizontal(|ui| {
+        ui.label("Длина пакета b:");
+        ui.add(egui::DragValue::new(&mut channel.burst_length).range(1..=length.max(1)));
+        ui.checkbox(&mut channel.random_burst_start, "Случайное начало");
+        if !channel.random_burst_start {
+            ui.label("начало:");
+            let last_start = length.saturating_sub(channel.burst_length) + 1;
+            ui.add(egui::DragValue::new(&mut channel.burst_start).range(1..=last_start));
+        }
+    });
+
+    if config.code_type == SelectedCodeType::Cyclic {
+        let guaranteed = config.channel.burst_length <= config.p;
+        ui.label(
+            egui::RichText::new(format!(
+                "Циклический код обнаруживает любой пакет длины b ≤ p = {}: {}",
+                config.p,
+                if guaranteed {
+                    "гарантия выполняется"
                 } else {
-                    display_binary_matrix_full(
-                        ui,
-                        &code.parity_check,
-                        &format!("Проверочная матрица H (p={}, n={}):", code.p, code.n),
-                        "scroll_parity_check",
-                        "grid_parity_check",
-                    );
+                    "b > p, обнаружение не гарантировано"
                 }
-            });
+            ))
+            .color(if guaranteed {
+                egui::Color32::DARK_GREEN
+            } else {
+                egui::Color32::from_rgb(200, 120, 0)
+            }),
+        );
+    }
+}
+
+/// Рендеринг параметров канала Гилберта–Эллиота
+fn render_gilbert_elliott_parameters(ui: &mut egui::Ui, config: &mut CodeConfig) {
+    let params = &mut config.channel.gilbert_elliott;
+    ui.horizontal(|ui| {
+        ui.label("P(хорошее → плохое):");
+        ui.add(
+            egui::DragValue::new(&mut params.good_to_bad)
+                .range(0.0..=1.0)
+                .speed(0.001),
+        );
+        ui.label("P(плохое → хорошее):");
+        ui.add(
+            egui::DragValue::new(&mut params.bad_to_good)
+                .range(0.0..=1.0)
+                .speed(0.001),
+        );
+    });
+    ui.horizontal(|ui| {
+        ui.label("Вероятность ошибки: в хорошем состоянии");
+        ui.add(
+            egui::DragValue::new(&mut params.good_error_probability)
+                .range(0.0..=1.0)
+                .speed(0.001),
+        );
+        ui.label("в плохом состоянии");
+        ui.add(
+            egui::DragValue::new(&mut params.bad_error_probability)
+                .range(0.0..=1.0)
+                .speed(0.01),
+        );
+    });
+    ui.label(format!(
+        "Доля времени в плохом состоянии: {:.3}, средняя вероятность ошибки: {:.4}",
+        params.bad_state_probability(),
+        params.average_error_probability()
+    ));
+}
+
+/// Рендеринг матриц кода
+fn render_code_matrices(ui: &mut egui::Ui, config: &CodeConfig) {
+    // Разреженная H LDPC-кода в сокращенном виде выводится списками единиц
+    let mut sparse_title = None;
+    let (generator, parity_check, generator_title) = match config.code_type {
+        SelectedCodeType::Ldpc => {
+            let Some(ref code) = config.ldpc_code else {
+                return;
+            };
+            sparse_title = Some(format!(
+                "Проверочная матрица H ({} проверок, n={}, единиц {}):",
+                code.checks(),
+                code.linear.n,
+                code.ones()
+            ));
+            (
+                &code.linear.generator,
+                &code.linear.parity_check,
+                format!(
+                    "Порождающая матрица G по приведенной H (k={}, n={}):",
+                    code.linear.k, code.linear.n
+                ),
+            )
+        }
+        SelectedCodeType::Golay | SelectedCodeType::ExtendedGolay => {
+            let Some(ref code) = config.golay_code else {
+                return;
+            };
+            (
+                &code.linear.generator,
+                &code.linear.parity_check,
+                format!(
+                    "Порождающая матрица G = [I | P] (k={}, n={}, p={}):",
+                    code.linear.k,
+                    code.linear.n,
+                    code.linear.p()
+                ),
+            )
+        }
+        SelectedCodeType::Systematic => match (&config.custom_code, &config.systematic_code) {
+            (Some(code), _) => (
+                &code.generator,
+                &code.parity_check,
+                format!(
+                    "Порождающая матрица G, заданная пользователем (k={}, n={}, p={}):",
+                    code.k,
+                    code.n,
+                    code.p()
+                ),
+            ),
+            (None, Some(code)) => (
+                &code.generator,
+                &code.parity_check,
+                format!(
+                    "Производящая матрица P (k={}, n={}, p={}):",
+                    code.k, code.n, code.p
+                ),
+            ),
+            (None, None) => return,
+        },
+        _ => return,
+    };
+    let sparse = sparse_title.is_some();
+    let parity_check_title = sparse_title
+        .unwrap_or_else(|| format!("Проверочная матрица H (p={}, n={}):", config.p, config.n));
+
+    egui::ScrollArea::vertical()
+        .id_salt("labs4to6_matrices_scroll")
+        .auto_shrink([false; 2])
+        .max_height(ui.available_height() * 0.7)
+        .show(ui, |ui| {
+            display_matrix(
+                ui,
+                generator,
+                &generator_title,
+                (config.compact_view, false),
+                ("scroll_generator", "grid_generator"),
+            );
+
+            ui.add_space(8.0);
+
+            display_matrix(
+                ui,
+                parity_check,
+                &parity_check_title,
+                (config.compact_view, sparse),
+                ("scroll_parity_check", "grid_parity_check"),
+            );
+        });
+}
+
+/// Матрица в сокращенном или полном виде; разреженная в сокращенном виде - списками единиц
+fn display_matrix(
+    ui: &mut egui::Ui,
+    matrix: &BinaryMatrix,
+    title: &str,
+    (compact, sparse): (bool, bool),
+    (scroll_id, grid_id): (&str, &str),
+) {
+    if compact {
+        display_binary_matrix_compact(ui, matrix, title, scroll_id, grid_id, sparse);
+    } else {
+        display_binary_matrix_full(ui, matrix, title, scroll_id, grid_id);
     }
 }
 
 /// Рендеринг результатов экспериментов
-#[allow(clippy::too_many_lines)]
 fn render_experiment_results(
     ui: &mut egui::Ui,
     results: &[Labs4To6ExperimentResult],
-    config: &CodeConfig,
+    config: &mut CodeConfig,
 ) {
     ui.label(
         egui::RichText::new("Результаты экспериментов:")
@@ -192,302 +522,336 @@ fn render_experiment_results(
             .size(16.0),
     );
 
+    render_outcome_summary(ui, results);
+
     egui::ScrollArea::vertical()
         .id_salt("labs4to6_experiments_scroll")
         .auto_shrink([false; 2])
         .max_height(ui.available_height())
         .show(ui, |ui| {
             for (i, result) in results.iter().enumerate() {
-                ui.collapsing(format!("Эксперимент #{}", i + 1), |ui| {
-                    add_label(ui, "Сообщение:");
-                    ui.label(format_bits(&result.message));
-
-                    add_label(
-                        ui,
-                        match result.code_type {
-                            CodeType::Hamming => "Код Хемминга (без parity bit):",
-                            CodeType::Cyclic => "Циклический код:",
-                            CodeType::Systematic => "Кодовое слово:",
-                        },
-                    );
-                    ui.label(format_bits(&result.codeword));
-
-                    if let Some(ref codeword_with_parity) = result.codeword_with_parity {
-                        add_label(ui, "Модифицированный код Хемминга (с parity bit):");
-                        ui.label(format_bits(codeword_with_parity));
-                    }
+                ui.collapsing(
+                    format!("Эксперимент #{} (seed {})", i + 1, result.seed),
+                    |ui| {
+                        render_experiment_card(ui, result, config);
+                        ui.horizontal(|ui| {
+                            if ui.button("Изменить ошибки вручную").clicked() {
+                                config.manual_injection.load(result);
+                            }
+                            if result.code_type == CodeType::Cyclic
+                                && ui.button("Регистр сдвига по шагам").clicked()
+                            {
+                                config.lfsr.load(result);
+                            }
+                        });
+                        ui.separator();
+                    },
+                );
+            }
+        });
+}
 
-                    if result.code_type == CodeType::Hamming {
-                        add_label(
-                            ui,
-                            &format!("Кратность ошибки: {}", result.error_multiplicity),
-                        );
-                    }
+/// Сводка итогов декодирования по всем словам
+pub fn render_outcome_summary(ui: &mut egui::Ui, results: &[Labs4To6ExperimentResult]) {
+    ui.horizontal_wrapped(|ui| {
+        ui.label("Итого:");
+        for outcome in DecodingOutcome::ALL {
+            let count = results.iter().filter(|r| r.outcome() == outcome).count();
+            ui.label(
+                egui::RichText::new(format!("{} - {count}", outcome.label()))
+                    .color(outcome_color(outcome)),
+            );
+        }
+    });
+}
 
-                    if !result.error_positions.is_empty() {
-                        add_label(
-                            ui,
-                            &match result.code_type {
-                                CodeType::Hamming => format!(
-                                    "Ошибки внесены в разряды: {:?}",
-                                    result.error_positions
-                                ),
-                                _ => {
-                                    format!("Внесена ошибка в разряд {}", result.error_positions[0])
-                                }
-                            },
-                        );
-                    }
+/// Рендеринг карточки одного эксперимента
+#[allow(clippy::too_many_lines)]
+pub fn render_experiment_card(
+    ui: &mut egui::Ui,
+    result: &Labs4To6ExperimentResult,
+    config: &CodeConfig,
+) {
+    add_label(ui, "Сообщение:");
+    ui.label(format_bits(&result.message));
+
+    add_label(
+        ui,
+        match result.code_type {
+            CodeType::Hamming => "Код Хемминга (без parity bit):",
+            CodeType::Cyclic => "Циклический код:",
+            CodeType::Bch => "Код БЧХ:",
+            CodeType::ReedSolomon => "Код Рида–Соломона:",
+            CodeType::Convolutional => "Сверточный код (с хвостом из нулей):",
+            CodeType::Golay => "Код Голея:",
+            CodeType::ExtendedGolay => "Расширенный код Голея (с общей проверкой на четность):",
+            CodeType::Ldpc => "LDPC-код:",
+            CodeType::Systematic => "Кодовое слово:",
+        },
+    );
+    ui.label(format_bits(&result.codeword));
 
-                    add_label(ui, "Принятое сообщение:");
-                    ui.label(format_bits(&result.received));
-
-                    add_label(ui, "Синдром ошибки:");
-                    ui.label(format!("({})", format_bits(&result.syndrome)));
-
-                    // Вывод таблицы синдромов для циклического кода
-                    if result.code_type == CodeType::Cyclic
-                        && let Some(ref code) = config.cyclic_code
-                    {
-                        add_label(ui, "Таблица соответствия позиции ошибки к синдрому:");
-
-                        // Оценка ширины одной записи (примерно 150-200 пикселей)
-                        let estimated_entry_width = 180.0;
-                        let available_width = ui.available_width();
-                        let spacing_between_columns = 20.0;
-
-                        // Вычисляем оптимальное количество столбцов
-                        // available_width всегда неотрицателен и ограничен разумными значениями,
-                        // поэтому приведение безопасно (усечение не произойдет для реальных размеров экрана)
-                        #[allow(clippy::cast_sign_loss, clippy::cast_possible_truncation)]
-                        let max_columns = (available_width
-                            / (estimated_entry_width + spacing_between_columns))
-                            .floor()
-                            .max(1.0) as usize;
-                        let num_columns = max_columns.min(code.syndrome_table.len()).max(1);
-
-                        // Вычисляем количество записей в каждом столбце
-                        let entries_per_column = code.syndrome_table.len().div_ceil(num_columns);
-
-                        egui::ScrollArea::vertical()
-                            .max_height(300.0)
-                            .show(ui, |ui| {
-                                ui.horizontal(|ui| {
-                                    for col in 0..num_columns {
-                                        ui.vertical(|ui| {
-                                            let start_idx = col * entries_per_column;
-                                            let end_idx = ((col + 1) * entries_per_column)
-                                                .min(code.syndrome_table.len());
-
-                                            for i in start_idx..end_idx {
-                                                if let Some((syndrome_poly, error_pos)) =
-                                                    code.syndrome_table.get(i)
-                                                {
-                                                    let syndrome_bits =
-                                                        polynomial_to_bits(syndrome_poly, code.p);
-                                                    ui.horizontal(|ui| {
-                                                        ui.label(format!("{}:", error_pos + 1));
-                                                        ui.label(format!(
-                                                            "({})",
-                                                            format_bits(&syndrome_bits)
-                                                        ));
-                                                    });
-                                                }
-                                            }
-                                        });
-
-                                        if col < num_columns - 1 {
-                                            ui.add_space(spacing_between_columns);
-                                        }
-                                    }
-                                });
-                            });
-                    }
+    if let Some(ref codeword_with_parity) = result.codeword_with_parity {
+        add_label(ui, "Модифицированный код Хемминга (с parity bit):");
+        ui.label(format_bits(codeword_with_parity));
+    }
 
-                    if let Some(overall_parity) = result.overall_parity {
-                        add_label(
-                            ui,
-                            &format!(
-                                "Общая проверка четности: {}",
-                                if overall_parity { "OK" } else { "Ошибка" }
-                            ),
-                        );
-                    }
+    if result.code_type == CodeType::Hamming || result.error_multiplicity > 1 {
+        add_label(
+            ui,
+            &format!("Кратность ошибки: {}", result.error_multiplicity),
+        );
+    }
 
-                    add_label(ui, "");
-                    match &result.error_info {
-                        ErrorInfoType::Systematic(
-                            entropy_fx::coding::systematic::ErrorInfo::NoError,
-                        )
-                        | ErrorInfoType::Hamming(
-                            entropy_fx::coding::hamming::HammingErrorInfo::NoError,
-                        )
-                        | ErrorInfoType::Cyclic(
-                            entropy_fx::coding::cyclic::CyclicErrorInfo::NoError,
-                        ) => {
-                            ui.label(egui::RichText::new("Сообщение не содержит ошибок!").strong());
-                        }
-                        ErrorInfoType::Systematic(
-                            entropy_fx::coding::systematic::ErrorInfo::SingleError(pos),
-                        ) => {
-                            ui.label(
-                                egui::RichText::new(format!(
-                                    "Обнаружена ошибка в разряде {}",
-                                    pos + 1
-                                ))
-                                .strong(),
-                            );
-                        }
-                        ErrorInfoType::Systematic(
-                            entropy_fx::coding::systematic::ErrorInfo::Uncorrectable,
-                        )
-                        | ErrorInfoType::Cyclic(
-                            entropy_fx::coding::cyclic::CyclicErrorInfo::Uncorrectable,
-                        ) => {
-                            ui.label(
-                                egui::RichText::new(
-                                    "Множественные ошибки или некорректируемая ошибка",
-                                )
-                                .strong(),
-                            );
-                        }
-                        ErrorInfoType::Hamming(
-                            entropy_fx::coding::hamming::HammingErrorInfo::SingleError(pos),
-                        ) => {
-                            ui.label(
-                                egui::RichText::new(format!(
-                                    "Обнаружена однократная ошибка в разряде {pos}"
-                                ))
-                                .strong(),
-                            );
-                        }
-                        ErrorInfoType::Hamming(
-                            entropy_fx::coding::hamming::HammingErrorInfo::DoubleError,
-                        ) => {
-                            ui.label(
-                                egui::RichText::new(
-                                    "Обнаружена двукратная ошибка (коррекция невозможна)",
-                                )
-                                .strong(),
-                            );
-                        }
-                        ErrorInfoType::Cyclic(
-                            entropy_fx::coding::cyclic::CyclicErrorInfo::SingleError(pos),
-                        ) => {
-                            ui.label(
-                                egui::RichText::new(format!("Обнаружена ошибка в разряде {pos}"))
-                                    .strong(),
-                            );
+    if !result.error_positions.is_empty() {
+        add_label(
+            ui,
+            &match result.error_positions.as_slice() {
+                [position] if result.code_type != CodeType::Hamming => {
+                    format!("Внесена ошибка в разряд {position}")
+                }
+                positions => {
+                    format!("Ошибки внесены в разряды: {positions:?}")
+                }
+            },
+        );
+    }
+
+    if result.error_multiplicity > 1 {
+        let burst_length = result.burst_length();
+        let guaranteed = result.code_type == CodeType::Cyclic && burst_length <= config.p;
+        add_label(
+            ui,
+            &format!(
+                "Длина пакета ошибок: {burst_length}{}",
+                if guaranteed {
+                    format!(" (b ≤ p = {}, обнаружение гарантировано)", config.p)
+                } else {
+                    String::new()
+                }
+            ),
+        );
+    }
+
+    add_label(ui, "Принятое сообщение:");
+    ui.label(format_bits(&result.received));
+
+    add_label(ui, "Синдром ошибки:");
+    ui.label(format!("({})", format_bits(&result.syndrome)));
+
+    // Вывод таблицы синдромов для циклического кода
+    if result.code_type == CodeType::Cyclic
+        && let Some(ref code) = config.cyclic_code
+    {
+        add_label(ui, "Таблица соответствия позиции ошибки к синдрому:");
+
+        // Оценка ширины одной записи (примерно 150-200 пикселей)
+        let estimated_entry_width = 180.0;
+        let available_width = ui.available_width();
+        let spacing_between_columns = 20.0;
+
+        // Вычисляем оптимальное количество столбцов
+        // available_width всегда неотрицателен и ограничен разумными значениями,
+        // поэтому приведение безопасно (усечение не произойдет для реальных размеров экрана)
+        #[allow(clippy::cast_sign_loss, clippy::cast_possible_truncation)]
+        let max_columns = (available_width / (estimated_entry_width + spacing_between_columns))
+            .floor()
+            .max(1.0) as usize;
+        let num_columns = max_columns.min(code.syndrome_table.len()).max(1);
+
+        // Вычисляем количество записей в каждом столбце
+        let entries_per_column = code.syndrome_table.len().div_ceil(num_columns);
+
+        egui::ScrollArea::vertical()
+            .max_height(300.0)
+            .show(ui, |ui| {
+                ui.horizontal(|ui| {
+                    for col in 0..num_columns {
+                        ui.vertical(|ui| {
+                            let start_idx = col * entries_per_column;
+                            let end_idx =
+                                ((col + 1) * entries_per_column).min(code.syndrome_table.len());
+
+                            for i in start_idx..end_idx {
+                                if let Some((syndrome, error_pos)) = code.syndrome_table.get(i) {
+                                    ui.horizontal(|ui| {
+                                        ui.label(format!("{}:", error_pos + 1));
+                                        ui.label(format!("({})", format_bits(syndrome)));
+                                    });
+                                }
+                            }
+                        });
+
+                        if col < num_columns - 1 {
+                            ui.add_space(spacing_between_columns);
                         }
                     }
+                });
+            });
+    }
 
-                    add_label(ui, "Скорректированное кодовое слово:");
-                    ui.label(format_bits(&result.corrected));
+    if let Some(decoder) = result.cyclic_decoder
+        && let Some(ref code) = config.cyclic_code
+    {
+        render_shift_trace(ui, code, decoder, &result.received);
+    }
 
-                    ui.separator();
-                });
-            }
-        });
+    if result.code_type == CodeType::Bch
+        && let Some(ref code) = config.bch_code
+    {
+        render_bch_decoding(ui, code, &result.received);
+    }
+
+    if result.code_type == CodeType::ReedSolomon
+        && let Some(ref code) = config.rs_code
+    {
+        render_symbol_view(ui, code, result);
+        render_rs_decoding(ui, code, result);
+    }
+
+    if result.code_type == CodeType::Convolutional
+        && let Some(ref code) = config.convolutional_code
+    {
+        render_trellis(ui, code, result);
+    }
+
+    if let ErrorInfoType::Ldpc(ref report) = result.error_info {
+        render_ldpc_iterations(ui, report, result.seed);
+    }
+
+    if let Some(overall_parity) = result.overall_parity {
+        add_label(
+            ui,
+            &format!(
+                "Общая проверка четности: {}",
+                if overall_parity { "OK" } else { "Ошибка" }
+            ),
+        );
+    }
+
+    add_label(ui, "");
+    match &result.error_info {
+        ErrorInfoType::Systematic(entropy_fx::coding::systematic::ErrorInfo::NoError)
+        | ErrorInfoType::Hamming(entropy_fx::coding::hamming::HammingErrorInfo::NoError)
+        | ErrorInfoType::Cyclic(entropy_fx::coding::cyclic::CyclicErrorInfo::NoError)
+        | ErrorInfoType::Bch(BchErrorInfo::NoError)
+        | ErrorInfoType::ReedSolomon(RsErrorInfo::NoError)
+        | ErrorInfoType::Convolutional(ViterbiErrorInfo::NoError)
+        | ErrorInfoType::Golay(GolayErrorInfo::NoError)
+        | ErrorInfoType::Ldpc(LdpcReport {
+            info: LdpcErrorInfo::NoError,
+            ..
+        }) => {
+            ui.label(egui::RichText::new("Сообщение не содержит ошибок!").strong());
+        }
+        ErrorInfoType::Systematic(entropy_fx::coding::systematic::ErrorInfo::SingleError(pos)) => {
+            ui.label(
+                egui::RichText::new(format!("Обнаружена ошибка в разряде {}", pos + 1)).strong(),
+            );
+        }
+        ErrorInfoType::Systematic(entropy_fx::coding::systematic::ErrorInfo::Uncorrectable)
+        | ErrorInfoType::Cyclic(entropy_fx::coding::cyclic::CyclicErrorInfo::Uncorrectable)
+        | ErrorInfoType::Bch(BchErrorInfo::Uncorrectable)
+        | ErrorInfoType::ReedSolomon(RsErrorInfo::Uncorrectable)
+        | ErrorInfoType::Golay(GolayErrorInfo::Uncorrectable)
+        | ErrorInfoType::Ldpc(LdpcReport {
+            info: LdpcErrorInfo::NotConverged,
+            ..
+        }) => {
+            ui.label(
+                egui::RichText::new("Множественные ошибки или некорректируемая ошибка").strong(),
+            );
+        }
+        ErrorInfoType::Hamming(entropy_fx::coding::hamming::HammingErrorInfo::SingleError(pos)) => {
+            ui.label(
+                egui::RichText::new(format!("Обнаружена однократная ошибка в разряде {pos}"))
+                    .strong(),
+            );
+        }
+        ErrorInfoType::Hamming(entropy_fx::coding::hamming::HammingErrorInfo::DoubleError) => {
+            ui.label(
+                egui::RichText::new("Обнаружена двукратная ошибка (коррекция невозможна)").strong(),
+            );
+        }
+        ErrorInfoType::Cyclic(entropy_fx::coding::cyclic::CyclicErrorInfo::SingleError(pos)) => {
+            ui.label(egui::RichText::new(format!("Обнаружена ошибка в разряде {pos}")).strong());
+        }
+        ErrorInfoType::Bch(BchErrorInfo::Corrected(positions))
+        | ErrorInfoType::Golay(GolayErrorInfo::Corrected(positions)) => {
+            ui.label(
+                egui::RichText::new(format!("Исправлены ошибки в разрядах {positions:?}")).strong(),
+            );
+        }
+        ErrorInfoType::ReedSolomon(RsErrorInfo::Corrected(positions)) => {
+            ui.label(egui::RichText::new(format!("Исправлены символы {positions:?}")).strong());
+        }
+        ErrorInfoType::Ldpc(LdpcReport {
+            info: LdpcErrorInfo::Converged(iterations),
+            ..
+        }) => {
+            ui.label(
+                egui::RichText::new(format!(
+                    "Декодер сошелся к кодовому слову за {iterations} итераций"
+                ))
+                .strong(),
+            );
+        }
+        ErrorInfoType::Convolutional(ViterbiErrorInfo::Corrected(distance)) => {
+            ui.label(
+                egui::RichText::new(format!(
+                    "Выживший путь отличается от принятой последовательности в {distance} разрядах"
+                ))
+                .strong(),
+            );
+        }
+        ErrorInfoType::Convolutional(ViterbiErrorInfo::Miscorrected(distance)) => {
+            ui.label(
+                egui::RichText::new(format!(
+                    "Выживший путь отличается от принятой последовательности в {distance} разрядах, но ведет к другому сообщению"
+                ))
+                .strong(),
+            );
+        }
+    }
+
+    add_label(ui, "Скорректированное кодовое слово:");
+    ui.label(format_bits(&result.corrected));
+
+    let outcome = result.outcome();
+    if outcome != DecodingOutcome::NoError {
+        ui.label(
+            egui::RichText::new(format!("Итог: {}", outcome.label()))
+                .strong()
+                .color(outcome_color(outcome)),
+        );
+    }
+}
+
+/// Цвет итога декодирования
+pub const fn outcome_color(outcome: DecodingOutcome) -> egui::Color32 {
+    match outcome {
+        DecodingOutcome::NoError | DecodingOutcome::Corrected => egui::Color32::DARK_GREEN,
+        DecodingOutcome::Detected => egui::Color32::from_rgb(200, 120, 0),
+        DecodingOutcome::Miscorrected | DecodingOutcome::Undetected => egui::Color32::RED,
+    }
 }
 
 /// Запуск экспериментов
-#[allow(clippy::too_many_lines)]
 fn run_experiments(config: &CodeConfig) -> Vec<Labs4To6ExperimentResult> {
     let mut results = Vec::new();
-    let mut rng = rand::rng();
-
-    for _ in 0..config.experiments {
-        match config.code_type {
-            SelectedCodeType::Hamming => {
-                if let Some(ref code) = config.hamming_code {
-                    let message: Vec<bool> =
-                        (0..code.k).map(|_| rng.random_range(0..2) == 1).collect();
-
-                    let codeword = encode_hamming(&message, code);
-                    let codeword_with_parity = add_parity_bit(&codeword);
-                    let error_multiplicity = generate_error_multiplicity();
-                    let (received, error_positions) =
-                        inject_errors(&codeword_with_parity, error_multiplicity);
-
-                    let codeword_part = &received[..code.n];
-                    let received_parity = received[code.n];
-                    let (syndrome, computed_parity) = compute_syndrome_hamming(codeword_part, code);
-                    let overall_parity = received_parity == computed_parity;
-
-                    let (corrected, error_info) = decode_hamming(&received, code, true);
-
-                    results.push(Labs4To6ExperimentResult {
-                        code_type: CodeType::Hamming,
-                        message,
-                        codeword,
-                        codeword_with_parity: Some(codeword_with_parity),
-                        error_multiplicity,
-                        error_positions,
-                        received,
-                        syndrome,
-                        overall_parity: Some(overall_parity),
-                        corrected,
-                        error_info: ErrorInfoType::Hamming(error_info),
-                    });
-                }
-            }
-            SelectedCodeType::Systematic => {
-                if let Some(ref code) = config.systematic_code {
-                    let message: Vec<bool> =
-                        (0..code.k).map(|_| rng.random_range(0..2) == 1).collect();
-
-                    let codeword = encode_message(&message, code);
-                    let (received, error_position) =
-                        inject_single_error(&codeword, config.error_probability);
-                    let syndrome = compute_syndrome(&code.parity_check, &received);
-                    let (corrected, error_info) = correct_error(&code.parity_check, &received);
-
-                    let error_positions = error_position.map(|p| vec![p + 1]).unwrap_or_default();
-
-                    results.push(Labs4To6ExperimentResult {
-                        code_type: CodeType::Systematic,
-                        message,
-                        codeword,
-                        codeword_with_parity: None,
-                        error_multiplicity: usize::from(error_position.is_some()),
-                        error_positions,
-                        received,
-                        syndrome,
-                        overall_parity: None,
-                        corrected,
-                        error_info: ErrorInfoType::Systematic(error_info),
-                    });
-                }
-            }
-            SelectedCodeType::Cyclic => {
-                if let Some(ref code) = config.cyclic_code {
-                    let message: Vec<bool> =
-                        (0..code.k).map(|_| rng.random_range(0..2) == 1).collect();
-
-                    let codeword = encode_cyclic(&message, code);
-                    let (received, error_position) =
-                        inject_single_error_cyclic(&codeword, config.error_probability);
-                    let syndrome_poly = compute_syndrome_cyclic(&received, code);
-                    let syndrome = polynomial_to_bits(&syndrome_poly, code.p);
-                    let (corrected, error_info) = decode_cyclic(&received, code);
-
-                    let error_positions = error_position.map(|p| vec![p + 1]).unwrap_or_default();
-
-                    results.push(Labs4To6ExperimentResult {
-                        code_type: CodeType::Cyclic,
-                        message,
-                        codeword,
-                        codeword_with_parity: None,
-                        error_multiplicity: usize::from(error_position.is_some()),
-                        error_positions,
-                        received,
-                        syndrome,
-                        overall_parity: None,
-                        corrected,
-                        error_info: ErrorInfoType::Cyclic(error_info),
-                    });
-                }
-            }
+
+    for i in 0..config.experiments {
+        let seed = experiment_seed(config.seed, i);
+        let mut rng = rng_from_seed(seed);
+
+        let message = generate_message(config.k, &mut rng);
+        let Some(transmitted) = config.encode(&message) else {
+            continue;
+        };
+        let (mut received, _) = config.transmit(&transmitted, &mut rng);
+        let erasures = config.erase(&mut received, &mut rng);
+        if let Some(result) = config.evaluate_with_erasures(seed, message, received, erasures) {
+            results.push(result);
         }
     }
 
@@ -0,0 +1,144 @@
+use crate::coding::ldpc::{LdpcCode, LdpcDecoder, LdpcParameters, LdpcReport, parse_alist};
+use crate::state::code_config::CodeConfig;
+use crate::ui::widgets::add_label;
+use std::fs;
+
+/// Рендеринг параметров LDPC-кода: источник H и настройки декодера
+pub fn render_ldpc_parameters(ui: &mut egui::Ui, config: &mut CodeConfig) {
+    let mut changed = false;
+    let parameters = &mut config.ldpc_parameters;
+    if let Some(ref matrix) = parameters.loaded {
+        let size = (matrix.len(), matrix.first().map_or(0, Vec::len));
+        ui.horizontal(|ui| {
+            ui.label(format!(
+                "H загружена из файла alist: {} x {}",
+                size.0, size.1
+            ));
+            if ui.button("Случайная регулярная H").clicked() {
+                parameters.loaded = None;
+                changed = true;
+            }
+        });
+    } else {
+        ui.horizontal(|ui| {
+            ui.label("n:");
+            changed |= ui
+                .add(egui::DragValue::new(&mut parameters.n).range(4..=LdpcParameters::MAX_N))
+                .changed();
+            ui.label("Вес столбца wc:");
+            changed |= ui
+                .add(egui::DragValue::new(&mut parameters.column_weight).range(2..=8))
+                .changed();
+            ui.label("Вес строки wr:");
+            changed |= ui
+                .add(egui::DragValue::new(&mut parameters.row_weight).range(3..=16))
+                .changed();
+            ui.label("Seed матрицы:");
+            changed |= ui.add(egui::DragValue::new(&mut parameters.seed)).changed();
+        });
+    }
+    if ui.button("Загрузить H из alist...").clicked() {
+        load_alist(config);
+        changed = true;
+    }
+    if changed {
+        config.update_n_and_p();
+        config.analysis = None;
+        config.coverage = None;
+    }
+
+    match config.ldpc_status {
+        Some(Ok(ref message)) => {
+            ui.colored_label(egui::Color32::DARK_GREEN, message);
+        }
+        Some(Err(ref error)) => {
+            ui.colored_label(egui::Color32::RED, format!("Ошибка: {error}"));
+        }
+        None => {}
+    }
+    if config.ldpc_code.is_none()
+        && let Err(err) = config.ldpc_parameters.build()
+    {
+        ui.colored_label(egui::Color32::RED, format!("Ошибка: {err}"));
+    }
+
+    let parameters = &mut config.ldpc_parameters;
+    ui.horizontal(|ui| {
+        ui.label("Декодер:");
+        egui::ComboBox::from_id_salt("ldpc_decoder")
+            .selected_text(parameters.decoder.label())
+            .show_ui(ui, |ui| {
+                for decoder in LdpcDecoder::ALL {
+                    ui.selectable_value(&mut parameters.decoder, decoder, decoder.label());
+                }
+            });
+        ui.label("Итераций не больше:");
+        ui.add(egui::DragValue::new(&mut parameters.max_iterations).range(1..=200));
+        if parameters.decoder == LdpcDecoder::SumProduct {
+            ui.label("p канала для LLR:");
+            ui.add(
+                egui::DragValue::new(&mut parameters.crossover_probability)
+                    .range(0.001..=0.499)
+                    .speed(0.001),
+            );
+        }
+    });
+}
+
+/// Выбор и разбор файла alist; матрица сохраняется в параметрах кода
+fn load_alist(config: &mut CodeConfig) {
+    let Some(path) = rfd::FileDialog::new()
+        .add_filter("alist", &["alist", "txt"])
+        .pick_file()
+    else {
+        return;
+    };
+    let parsed = fs::read_to_string(&path)
+        .map_err(|err| err.to_string())
+        .and_then(|text| parse_alist(&text))
+        .and_then(|matrix| LdpcCode::from_parity_check(matrix.clone()).map(|_| matrix));
+    config.ldpc_status = Some(match parsed {
+        Ok(matrix) => {
+            let message = format!(
+                "H {} x {} загружена из {}",
+                matrix.len(),
+                matrix.first().map_or(0, Vec::len),
+                path.display()
+            );
+            config.ldpc_parameters.loaded = Some(matrix);
+            Ok(message)
+        }
+        Err(err) => Err(format!("{}: {err}", path.display())),
+    });
+}
+
+/// Вес синдрома по итерациям декодера, которым получен результат
+pub fn render_ldpc_iterations(ui: &mut egui::Ui, report: &LdpcReport, seed: u64) {
+    add_label(
+        ui,
+        &format!("Вес синдрома по итерациям ({}):", report.decoder.label()),
+    );
+    egui::ScrollArea::horizontal()
+        .id_salt(("ldpc_iterations", seed))
+        .show(ui, |ui| {
+            egui::Grid::new(("ldpc_iterations_grid", seed))
+                .striped(true)
+                .show(ui, |ui| {
+                    ui.label("Итерация");
+                    for iteration in 0..report.syndrome_weights.len() {
+                        ui.label(iteration.to_string());
+                    }
+                    ui.end_row();
+                    ui.label("Вес синдрома");
+                    for &weight in &report.syndrome_weights {
+                        let text = egui::RichText::new(weight.to_string());
+                        ui.label(if weight == 0 {
+                            text.color(egui::Color32::DARK_GREEN)
+                        } else {
+                            text
+                        });
+                    }
+                    ui.end_row();
+                });
+        });
+}
@@ -0,0 +1,450 @@
+use crate::coding::lfsr::{LfsrMode, LfsrStep, lfsr_trace, register_remainder};
+use crate::formatting::format_bits;
+use crate::formatting::polynomial::format_polynomial;
+use crate::state::code_config::{CodeConfig, SelectedCodeType};
+use crate::state::lfsr::LfsrAnimation;
+use crate::ui::widgets::add_label;
+use crate::utils::random::{generate_message, rng_from_seed};
+
+const CELL_SIZE: f32 = 32.0;
+const CELL_GAP: f32 = 34.0;
+const XOR_RADIUS: f32 = 9.0;
+/// Отступ слева под вход схемы вычисления синдрома
+const INPUT_MARGIN: f32 = 70.0;
+
+/// Окно пошаговой работы регистра сдвига: кодирование и вычисление синдрома
+pub fn render_lfsr_window(ctx: &egui::Context, config: &mut CodeConfig) {
+    let mut open = config.lfsr.open;
+    if !open || config.code_type != SelectedCodeType::Cyclic || config.cyclic_code.is_none() {
+        return;
+    }
+
+    egui::Window::new("Регистр сдвига (деление на g(x))")
+        .open(&mut open)
+        .default_width(700.0)
+        .vscroll(true)
+        .show(ctx, |ui| {
+            render_lfsr(ui, config);
+        });
+
+    config.lfsr.open = open;
+}
+
+/// Содержимое окна регистра сдвига
+fn render_lfsr(ui: &mut egui::Ui, config: &mut CodeConfig) {
+    let Some(code) = config.cyclic_code.clone() else {
+        return;
+    };
+    if config.lfsr.message.len() != code.k || config.lfsr.received.len() != code.n {
+        let mut rng = rng_from_seed(config.seed);
+        config.lfsr.message = generate_message(code.k, &mut rng);
+        config.lfsr.received = code.encode(&config.lfsr.message);
+        config.lfsr.rewind();
+    }
+
+    let animation = &mut config.lfsr;
+    let mut mode = animation.mode;
+    ui.horizontal(|ui| {
+        ui.label("Схема:");
+        egui::ComboBox::from_id_salt("lfsr_mode")
+            .selected_text(mode.label())
+            .show_ui(ui, |ui| {
+                for option in LfsrMode::ALL {
+                    ui.selectable_value(&mut mode, option, option.label());
+                }
+            });
+    });
+    if mode != animation.mode {
+        animation.mode = mode;
+        animation.rewind();
+    }
+
+    let trace = lfsr_trace(&code.generator, animation.input(), animation.mode);
+    render_controls(ui, animation, trace.len());
+
+    ui.label(format!(
+        "g(x) = {}, подаются {} старшими степенями вперед",
+        format_polynomial(&code.generator),
+        match animation.mode {
+            LfsrMode::Encoder => format!("биты сообщения {}", format_bits(&animation.message)),
+            LfsrMode::Syndrome =>
+                format!("биты принятого слова {}", format_bits(&animation.received)),
+        }
+    ));
+
+    let step = animation.step.min(trace.len());
+    let current = step.checked_sub(1).map(|i| &trace[i]);
+    let previous = step.checked_sub(2).map(|i| &trace[i]);
+    egui::ScrollArea::horizontal()
+        .id_salt("lfsr_circuit_scroll")
+        .show(ui, |ui| {
+            draw_circuit(ui, &code.generator, animation.mode, current, previous);
+        });
+
+    render_trace_table(ui, &trace, step);
+
+    if step == trace.len() {
+        let remainder = trace
+            .last()
+            .map(|last| register_remainder(&last.register))
+            .unwrap_or_default();
+        let (expected, title) = match animation.mode {
+            LfsrMode::Encoder => (
+                code.encode(&animation.message)[code.k..].to_vec(),
+                "проверочными разрядами кодового слова",
+            ),
+            LfsrMode::Syndrome => (code.syndrome(&animation.received), "синдромом"),
+        };
+        add_label(
+            ui,
+            &format!(
+                "Остаток в регистре (r{} … r0): ({})",
+                code.p - 1,
+                format_bits(&remainder)
+            ),
+        );
+        if remainder == expected {
+            ui.colored_label(
+                egui::Color32::DARK_GREEN,
+                format!("Совпадает с {title} ({})", format_bits(&expected)),
+            );
+        } else {
+            ui.colored_label(
+                egui::Color32::RED,
+                format!("Не совпадает с {title} ({})", format_bits(&expected)),
+            );
+        }
+    }
+}
+
+/// Кнопки воспроизведения и продвижение анимации по времени
+fn render_controls(ui: &mut egui::Ui, animation: &mut LfsrAnimation, len: usize) {
+    let now = ui.input(|input| input.time);
+    ui.horizontal(|ui| {
+        if ui.button("⏮ Сначала").clicked() {
+            animation.rewind();
+        }
+        let play_label = if animation.playing {
+            "⏸ Пауза"
+        } else {
+            "▶ Воспроизвести"
+        };
+        if ui.button(play_label).clicked() {
+            if animation.step >= len {
+                animation.step = 0;
+            }
+            animation.playing = !animation.playing;
+            animation.last_tick = now;
+        }
+        if ui
+            .add_enabled(animation.step < len, egui::Button::new("Шаг ⏵"))
+            .clicked()
+        {
+            animation.playing = false;
+            animation.step += 1;
+        }
+        if ui
+            .add_enabled(animation.step < len, egui::Button::new("В конец ⏭"))
+            .clicked()
+        {
+            animation.playing = false;
+            animation.step = len;
+        }
+        ui.label("Тактов в секунду:");
+        ui.add(
+            egui::DragValue::new(&mut animation.steps_per_second)
+                .range(0.25..=20.0)
+                .speed(0.1),
+        );
+        ui.label(format!("Такт {} из {len}", animation.step.min(len)));
+    });
+
+    if animation.playing {
+        let interval = 1.0 / animation.steps_per_second.max(0.25);
+        if now - animation.last_tick >= interval {
+            animation.step += 1;
+            animation.last_tick = now;
+        }
+        if animation.step >= len {
+            animation.step = len;
+            animation.playing = false;
+        } else {
+            ui.ctx()
+                .request_repaint_after(std::time::Duration::from_secs_f64(interval));
+        }
+    }
+}
+
+/// Геометрия и оформление схемы деления
+struct Circuit<'a> {
+    painter: &'a egui::Painter,
+    left: f32,
+    bus_y: f32,
+    mid_y: f32,
+    input_y: f32,
+    stroke: egui::Stroke,
+    /// Линии обратной связи выделяются, когда по ним идет единица
+    feedback_stroke: egui::Stroke,
+    text_color: egui::Color32,
+}
+
+impl Circuit<'_> {
+    /// Левая граница ячейки `r_i`
+    #[allow(clippy::cast_precision_loss)]
+    fn cell_left(&self, i: usize) -> f32 {
+        (i as f32).mul_add(CELL_SIZE + CELL_GAP, self.left)
+    }
+
+    /// Центр сумматора перед ячейкой `r_i`
+    fn xor_at(&self, i: usize) -> egui::Pos2 {
+        egui::pos2(self.cell_left(i) - CELL_GAP / 2.0, self.mid_y)
+    }
+
+    fn draw_xor(&self, center: egui::Pos2) {
+        self.painter.circle_stroke(center, XOR_RADIUS, self.stroke);
+        for direction in [egui::vec2(XOR_RADIUS, 0.0), egui::vec2(0.0, XOR_RADIUS)] {
+            self.painter
+                .line_segment([center - direction, center + direction], self.stroke);
+        }
+    }
+
+    fn label(&self, pos: egui::Pos2, align: egui::Align2, text: String) {
+        self.painter.text(
+            pos,
+            align,
+            text,
+            egui::FontId::proportional(12.0),
+            self.text_color,
+        );
+    }
+
+    /// Ячейка `r_i` с сумматором на входе (если он есть) и связью с предыдущей ячейкой
+    fn draw_cell(&self, i: usize, bit: bool, changed: bool, has_xor: bool) {
+        let rect = egui::Rect::from_min_size(
+            egui::pos2(self.cell_left(i), self.mid_y - CELL_SIZE / 2.0),
+            egui::vec2(CELL_SIZE, CELL_SIZE),
+        );
+        if changed {
+            self.painter
+                .rect_filled(rect, 2.0, egui::Color32::from_rgb(255, 220, 120));
+        }
+        self.painter
+            .rect_stroke(rect, 2.0, self.stroke, egui::StrokeKind::Inside);
+        self.painter.text(
+            rect.center(),
+            egui::Align2::CENTER_CENTER,
+            if bit { "1" } else { "0" },
+            egui::FontId::monospace(16.0),
+            if changed {
+                egui::Color32::BLACK
+            } else {
+                self.text_color
+            },
+        );
+        self.label(
+            egui::pos2(rect.center().x, rect.bottom() + 4.0),
+            egui::Align2::CENTER_TOP,
+            format!("r{i}"),
+        );
+
+        let from_x = (i > 0).then(|| self.cell_left(i - 1) + CELL_SIZE);
+        if has_xor {
+            let center = self.xor_at(i);
+            self.draw_xor(center);
+            if let Some(from_x) = from_x {
+                self.painter.arrow(
+                    egui::pos2(from_x, self.mid_y),
+                    egui::vec2(center.x - XOR_RADIUS - from_x, 0.0),
+                    self.stroke,
+                );
+            }
+            self.painter.arrow(
+                center + egui::vec2(XOR_RADIUS, 0.0),
+                egui::vec2(rect.left() - center.x - XOR_RADIUS, 0.0),
+                self.stroke,
+            );
+            self.painter.line_segment(
+                [
+                    egui::pos2(center.x, self.bus_y),
+                    center - egui::vec2(0.0, XOR_RADIUS),
+                ],
+                self.feedback_stroke,
+            );
+            self.label(
+                egui::pos2(center.x + 3.0, self.bus_y + 4.0),
+                egui::Align2::LEFT_TOP,
+                format!("g{i}"),
+            );
+        } else if let Some(from_x) = from_x {
+            self.painter.arrow(
+                egui::pos2(from_x, self.mid_y),
+                egui::vec2(rect.left() - from_x, 0.0),
+                self.stroke,
+            );
+        }
+    }
+
+    /// Вход схемы и шина обратной связи от выхода регистра к сумматорам
+    fn draw_feedback(&self, p: usize, mode: LfsrMode, step: Option<&LfsrStep>) {
+        let output_x = self.cell_left(p.saturating_sub(1)) + CELL_SIZE;
+        let tap = egui::pos2(output_x + CELL_GAP / 2.0, self.mid_y);
+        let left_x = self.xor_at(0).x;
+        let bit_text = |bit: Option<bool>| match bit {
+            Some(true) => "1",
+            Some(false) => "0",
+            None => "-",
+        };
+        let input = bit_text(step.map(|step| step.input));
+        match mode {
+            LfsrMode::Encoder => {
+                // Входной бит складывается с выходом регистра, сумма идет на шину и прямо в r0
+                self.draw_xor(tap);
+                self.painter.line_segment(
+                    [
+                        egui::pos2(output_x, self.mid_y),
+                        tap - egui::vec2(XOR_RADIUS, 0.0),
+                    ],
+                    self.stroke,
+                );
+                self.painter.arrow(
+                    egui::pos2(tap.x, self.input_y),
+                    egui::vec2(0.0, tap.y + XOR_RADIUS - self.input_y),
+                    self.stroke,
+                );
+                self.painter.line_segment(
+                    [
+                        tap - egui::vec2(0.0, XOR_RADIUS),
+                        egui::pos2(tap.x, self.bus_y),
+                    ],
+                    self.feedback_stroke,
+                );
+                self.label(
+                    egui::pos2(tap.x + 6.0, self.input_y),
+                    egui::Align2::LEFT_BOTTOM,
+                    format!("вход m: {input}"),
+                );
+                self.painter.line_segment(
+                    [
+                        egui::pos2(left_x, self.bus_y),
+                        egui::pos2(left_x, self.mid_y),
+                    ],
+                    self.feedback_stroke,
+                );
+                self.painter.arrow(
+                    egui::pos2(left_x, self.mid_y),
+                    egui::vec2(self.cell_left(0) - left_x, 0.0),
+                    self.feedback_stroke,
+                );
+            }
+            LfsrMode::Syndrome => {
+                // Выход регистра идет на шину, входной бит - в сумматор перед r0
+                self.painter
+                    .line_segment([egui::pos2(output_x, self.mid_y), tap], self.stroke);
+                self.painter
+                    .line_segment([tap, egui::pos2(tap.x, self.bus_y)], self.feedback_stroke);
+                let input_x = left_x - CELL_GAP;
+                self.painter.arrow(
+                    egui::pos2(input_x, self.mid_y),
+                    egui::vec2(left_x - XOR_RADIUS - input_x, 0.0),
+                    self.stroke,
+                );
+                self.label(
+                    egui::pos2(input_x, self.mid_y + 6.0),
+                    egui::Align2::LEFT_TOP,
+                    format!("вход r: {input}"),
+                );
+            }
+        }
+        self.painter.line_segment(
+            [
+                egui::pos2(left_x, self.bus_y),
+                egui::pos2(tap.x, self.bus_y),
+            ],
+            self.feedback_stroke,
+        );
+        self.label(
+            egui::pos2(tap.x + 6.0, self.bus_y),
+            egui::Align2::LEFT_CENTER,
+            format!(
+                "обратная связь: {}",
+                bit_text(step.map(|step| step.feedback))
+            ),
+        );
+    }
+}
+
+/// Схема деления: ячейки `r0..r(p-1)`, сумматоры по модулю 2 при `g_i = 1` и цепь обратной связи
+#[allow(clippy::cast_precision_loss)]
+fn draw_circuit(
+    ui: &mut egui::Ui,
+    generator: &[bool],
+    mode: LfsrMode,
+    current: Option<&LfsrStep>,
+    previous: Option<&LfsrStep>,
+) {
+    let p = generator.len().saturating_sub(1);
+    let width = (p as f32).mul_add(CELL_SIZE + CELL_GAP, INPUT_MARGIN) + 160.0;
+    let (response, painter) = ui.allocate_painter(egui::vec2(width, 150.0), egui::Sense::hover());
+    let origin = response.rect.min;
+    let stroke = egui::Stroke::new(1.5, ui.visuals().text_color());
+    let circuit = Circuit {
+        painter: &painter,
+        left: origin.x + INPUT_MARGIN,
+        bus_y: origin.y + 20.0,
+        mid_y: origin.y + 76.0,
+        input_y: origin.y + 140.0,
+        stroke,
+        feedback_stroke: if current.is_some_and(|step| step.feedback) {
+            egui::Stroke::new(2.5, egui::Color32::from_rgb(200, 60, 60))
+        } else {
+            stroke
+        },
+        text_color: ui.visuals().text_color(),
+    };
+
+    // Сумматор перед r0 есть только в схеме вычисления синдрома
+    for (i, &tap) in generator.iter().enumerate().take(p) {
+        let bit = current.is_some_and(|step| step.register[i]);
+        let changed = previous.is_some_and(|step| step.register[i]) != bit;
+        let has_xor = if i == 0 {
+            mode == LfsrMode::Syndrome
+        } else {
+            tap
+        };
+        circuit.draw_cell(i, bit, changed, has_xor);
+    }
+    circuit.draw_feedback(p, mode, current);
+}
+
+/// Таблица тактов до текущего включительно
+fn render_trace_table(ui: &mut egui::Ui, trace: &[LfsrStep], step: usize) {
+    let p = trace.first().map_or(0, |first| first.register.len());
+    egui::ScrollArea::vertical()
+        .id_salt("lfsr_trace_scroll")
+        .max_height(250.0)
+        .show(ui, |ui| {
+            egui::Grid::new("lfsr_trace_grid")
+                .striped(true)
+                .show(ui, |ui| {
+                    for header in ["Такт", "Вход", "Обратная связь", "r0 … r(p-1)"]
+                    {
+                        ui.label(egui::RichText::new(header).strong());
+                    }
+                    ui.end_row();
+
+                    ui.label("0");
+                    ui.label("-");
+                    ui.label("-");
+                    ui.monospace(format_bits(&vec![false; p]));
+                    ui.end_row();
+
+                    for (i, tick) in trace.iter().take(step).enumerate() {
+                        ui.label((i + 1).to_string());
+                        ui.monospace(if tick.input { "1" } else { "0" });
+                        ui.monospace(if tick.feedback { "1" } else { "0" });
+                        ui.monospace(format_bits(&tick.register));
+                        ui.end_row();
+                    }
+                });
+        });
+}
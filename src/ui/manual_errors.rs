@@ -0,0 +1,94 @@
+use crate::formatting::format_bits;
+use crate::state::code_config::{CodeConfig, SelectedCodeType};
+use crate::ui::labs4to6::render_experiment_card;
+use crate::ui::widgets::add_label;
+use crate::utils::random::{generate_message, random_seed, rng_from_seed};
+
+/// Окно ручного внесения ошибок: разряды передаваемого слова инвертируются щелчком
+pub fn render_manual_injection_window(ctx: &egui::Context, config: &mut CodeConfig) {
+    let mut open = config.manual_injection.open;
+    if !open || !config.can_run_experiments() {
+        return;
+    }
+
+    egui::Window::new("Ручное внесение ошибок")
+        .open(&mut open)
+        .default_width(600.0)
+        .vscroll(true)
+        .show(ctx, |ui| {
+            render_manual_injection(ui, config);
+        });
+
+    config.manual_injection.open = open;
+}
+
+/// Содержимое окна ручного внесения ошибок
+fn render_manual_injection(ui: &mut egui::Ui, config: &mut CodeConfig) {
+    if config.manual_injection.message.len() != config.k {
+        let mut rng = rng_from_seed(config.seed);
+        config.manual_injection.message = generate_message(config.k, &mut rng);
+    }
+    let Some(transmitted) = config.encode(&config.manual_injection.message) else {
+        return;
+    };
+    if config.manual_injection.error_mask.len() != transmitted.len() {
+        config.manual_injection.error_mask = vec![false; transmitted.len()];
+    }
+
+    ui.horizontal(|ui| {
+        if ui.button("Новое сообщение").clicked() {
+            let mut rng = rng_from_seed(random_seed());
+            config.manual_injection.message = generate_message(config.k, &mut rng);
+        }
+        if ui.button("Сбросить ошибки").clicked() {
+            config.manual_injection.error_mask.fill(false);
+        }
+    });
+
+    add_label(ui, "Сообщение:");
+    ui.label(format_bits(&config.manual_injection.message));
+
+    add_label(
+        ui,
+        "Передаваемое слово (щелчок по разряду инвертирует его):",
+    );
+    let parity_position =
+        (config.code_type == SelectedCodeType::Hamming).then(|| transmitted.len() - 1);
+    ui.horizontal_wrapped(|ui| {
+        ui.spacing_mut().item_spacing.x = 2.0;
+        for (i, flipped) in config.manual_injection.error_mask.iter_mut().enumerate() {
+            let bit = transmitted[i] ^ *flipped;
+            let text = egui::RichText::new(if bit { "1" } else { "0" }).monospace();
+            let hint = if parity_position == Some(i) {
+                format!("Разряд {} (parity bit)", i + 1)
+            } else {
+                format!("Разряд {}", i + 1)
+            };
+            if ui
+                .add(
+                    egui::Button::new(text)
+                        .selected(*flipped)
+                        .min_size(egui::vec2(18.0, 18.0)),
+                )
+                .on_hover_text(hint)
+                .clicked()
+            {
+                *flipped = !*flipped;
+            }
+        }
+    });
+
+    let received = transmitted
+        .iter()
+        .zip(&config.manual_injection.error_mask)
+        .map(|(&bit, &flipped)| bit ^ flipped)
+        .collect();
+    if let Some(result) = config.evaluate(
+        config.seed,
+        config.manual_injection.message.clone(),
+        received,
+    ) {
+        ui.separator();
+        render_experiment_card(ui, &result, config);
+    }
+}
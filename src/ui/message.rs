@@ -0,0 +1,135 @@
+use crate::formatting::message::{MessageFormat, message_segments, parse_message};
+use crate::state::code_config::CodeConfig;
+use crate::ui::labs4to6::{render_experiment_card, render_outcome_summary};
+use crate::ui::widgets::add_label;
+use crate::utils::random::random_seed;
+use crate::utils::transmission::transmit_bits;
+use egui::text::{LayoutJob, TextFormat};
+
+/// Максимальное число блоков, для которых выводятся карточки
+const MAX_BLOCK_CARDS: usize = 100;
+
+/// Рендеринг панели передачи введенного сообщения
+pub fn render_message_panel(ui: &mut egui::Ui, config: &mut CodeConfig) {
+    let input = &mut config.message_input;
+    ui.horizontal(|ui| {
+        ui.label("Формат:");
+        egui::ComboBox::from_id_salt("message_format")
+            .selected_text(input.format.label())
+            .show_ui(ui, |ui| {
+                for format in MessageFormat::ALL {
+                    ui.selectable_value(&mut input.format, format, format.label());
+                }
+            });
+    });
+    ui.add(
+        egui::TextEdit::multiline(&mut input.text)
+            .desired_rows(3)
+            .desired_width(f32::INFINITY)
+            .hint_text(match input.format {
+                MessageFormat::Bits => "0110 1001 ...",
+                MessageFormat::Hex => "0x48 65 6C 6C 6F",
+                MessageFormat::Text => "Привет, мир!",
+            }),
+    );
+
+    if ui
+        .add_enabled(config.can_run_experiments(), egui::Button::new("Передать"))
+        .clicked()
+    {
+        let input = &config.message_input;
+        match parse_message(&input.text, input.format) {
+            Ok(bits) => {
+                if config.randomize_seed {
+                    config.seed = random_seed();
+                }
+                let format = config.message_input.format;
+                let transmission = transmit_bits(config, &bits, config.seed);
+                config.message_input.error = transmission
+                    .is_none()
+                    .then(|| "код не инициализирован".to_string());
+                config.message_input.transmission = transmission.map(|t| (format, t));
+            }
+            Err(error) => {
+                config.message_input.error = Some(error);
+                config.message_input.transmission = None;
+            }
+        }
+    }
+
+    if let Some(ref error) = config.message_input.error {
+        ui.colored_label(egui::Color32::RED, format!("Ошибка: {error}"));
+    }
+
+    let Some((format, ref transmission)) = config.message_input.transmission else {
+        return;
+    };
+
+    ui.label(format!(
+        "{} бит, {} блок(ов) по k = {} бит, seed {}",
+        transmission.original.len(),
+        transmission.blocks.len(),
+        config.k,
+        config.seed
+    ));
+    render_outcome_summary(ui, &transmission.blocks);
+
+    add_label(ui, "Исходное сообщение:");
+    render_highlighted(
+        ui,
+        &message_segments(&transmission.original, &transmission.original, format),
+    );
+    if let Some(ref received) = transmission.received {
+        add_label(ui, "Принятое без исправления ошибок:");
+        render_highlighted(
+            ui,
+            &message_segments(received, &transmission.original, format),
+        );
+    }
+    add_label(ui, "После декодирования:");
+    render_highlighted(
+        ui,
+        &message_segments(&transmission.decoded, &transmission.original, format),
+    );
+
+    egui::CollapsingHeader::new(format!("Блоки ({})", transmission.blocks.len()))
+        .id_salt("labs4to6_message_blocks")
+        .show(ui, |ui| {
+            if transmission.blocks.len() > MAX_BLOCK_CARDS {
+                ui.label(format!("Показаны первые {MAX_BLOCK_CARDS} блоков"));
+            }
+            for (i, block) in transmission.blocks.iter().take(MAX_BLOCK_CARDS).enumerate() {
+                ui.collapsing(
+                    format!("Блок #{} ({})", i + 1, block.outcome().label()),
+                    |ui| {
+                        render_experiment_card(ui, block, config);
+                    },
+                );
+            }
+        });
+}
+
+/// Вывод сообщения с подсветкой искаженных фрагментов
+fn render_highlighted(ui: &mut egui::Ui, segments: &[(String, bool)]) {
+    let font_id = egui::TextStyle::Monospace.resolve(ui.style());
+    let mut job = LayoutJob::default();
+    for (text, corrupted) in segments {
+        let format = if *corrupted {
+            TextFormat {
+                font_id: font_id.clone(),
+                color: egui::Color32::WHITE,
+                background: egui::Color32::RED,
+                ..Default::default()
+            }
+        } else {
+            TextFormat {
+                font_id: font_id.clone(),
+                color: ui.visuals().text_color(),
+                ..Default::default()
+            }
+        };
+        job.append(text, 0.0, format);
+    }
+    job.wrap.max_width = ui.available_width();
+    ui.label(job);
+}
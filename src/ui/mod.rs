@@ -1,5 +1,22 @@
+pub mod analysis;
+pub mod bch;
+pub mod ber;
+pub mod convolutional;
+pub mod coverage;
+pub mod custom_code;
+pub mod cyclic_generator;
+pub mod editors;
+pub mod file_transfer;
 pub mod labs1to3;
 pub mod labs4to6;
+pub mod ldpc;
+pub mod lfsr;
+pub mod manual_errors;
+pub mod message;
+pub mod plots;
+pub mod reed_solomon;
+pub mod shift_decoder;
+pub mod sweep;
 pub mod widgets;
 
 pub use labs1to3::*;
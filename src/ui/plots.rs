@@ -0,0 +1,122 @@
+use crate::state::experiments::{ExperimentMetric, ExperimentResult, Labs1To3State};
+use crate::utils::statistics::build_histogram;
+use egui_plot::{Bar, BarChart, HLine, Legend, Line, Plot, PlotPoints, Points};
+use entropy_fx::max_entropy;
+
+/// Высота одного графика
+const PLOT_HEIGHT: f32 = 220.0;
+
+/// Точки показателя по номерам экспериментов
+#[allow(clippy::cast_precision_loss)]
+fn metric_points(results: &[ExperimentResult], metric: ExperimentMetric) -> Vec<[f64; 2]> {
+    results
+        .iter()
+        .enumerate()
+        .map(|(i, result)| [(i + 1) as f64, metric.value(result)])
+        .collect()
+}
+
+/// Линия с маркерами для показателя
+fn add_metric_series(
+    plot_ui: &mut egui_plot::PlotUi<'_>,
+    results: &[ExperimentResult],
+    metric: ExperimentMetric,
+) {
+    let points = metric_points(results, metric);
+    plot_ui.line(Line::new(metric.label(), PlotPoints::from(points.clone())));
+    plot_ui.points(Points::new(metric.label(), PlotPoints::from(points)).radius(3.0));
+}
+
+/// Рендеринг графиков статистики экспериментов Labs 1-3
+pub fn render_labs1to3_plots(ui: &mut egui::Ui, state: &mut Labs1To3State) {
+    let info_metrics: Vec<ExperimentMetric> = [
+        ExperimentMetric::InputEntropy,
+        ExperimentMetric::ConditionalEntropy,
+        ExperimentMetric::MutualInformation,
+        ExperimentMetric::ChannelCapacity,
+    ]
+    .into_iter()
+    .filter(|metric| metric.is_available(state.with_noise, state.with_duration))
+    .collect();
+
+    ui.label(egui::RichText::new("Энтропия и количество информации по экспериментам").strong());
+    Plot::new("labs1to3_info_plot")
+        .height(PLOT_HEIGHT)
+        .legend(Legend::default())
+        .x_axis_label("Эксперимент")
+        .y_axis_label("бит")
+        .include_y(0.0)
+        .show(ui, |plot_ui| {
+            for &metric in &info_metrics {
+                add_metric_series(plot_ui, &state.results, metric);
+            }
+            plot_ui.hline(HLine::new(
+                "log₂ N (макс. энтропия)",
+                max_entropy(state.signals),
+            ));
+        });
+
+    if state.with_duration {
+        let rate_metrics: Vec<ExperimentMetric> = [
+            ExperimentMetric::RateNoNoise,
+            ExperimentMetric::RateWithNoise,
+        ]
+        .into_iter()
+        .filter(|metric| metric.is_available(state.with_noise, state.with_duration))
+        .collect();
+
+        ui.add_space(8.0);
+        ui.label(egui::RichText::new("Скорость передачи по экспериментам").strong());
+        Plot::new("labs1to3_rate_plot")
+            .height(PLOT_HEIGHT)
+            .legend(Legend::default())
+            .x_axis_label("Эксперимент")
+            .y_axis_label("бит/с")
+            .include_y(0.0)
+            .show(ui, |plot_ui| {
+                for &metric in &rate_metrics {
+                    add_metric_series(plot_ui, &state.results, metric);
+                }
+            });
+    }
+
+    ui.add_space(8.0);
+    ui.horizontal(|ui| {
+        ui.label(egui::RichText::new("Гистограмма:").strong());
+        egui::ComboBox::from_id_salt("histogram_metric")
+            .selected_text(state.histogram_metric.label())
+            .show_ui(ui, |ui| {
+                for metric in ExperimentMetric::ALL {
+                    if metric.is_available(state.with_noise, state.with_duration) {
+                        ui.selectable_value(&mut state.histogram_metric, metric, metric.label());
+                    }
+                }
+            });
+        ui.label("интервалов:");
+        ui.add(egui::DragValue::new(&mut state.histogram_bins).range(1..=50));
+    });
+
+    let values: Vec<f64> = state
+        .results
+        .iter()
+        .map(|result| state.histogram_metric.value(result))
+        .collect();
+    let histogram = build_histogram(&values, state.histogram_bins);
+    let bar_width = match histogram.as_slice() {
+        [first, second, ..] => (second.0 - first.0) * 0.9,
+        _ => 0.1,
+    };
+    #[allow(clippy::cast_precision_loss)]
+    let bars: Vec<Bar> = histogram
+        .into_iter()
+        .map(|(center, count)| Bar::new(center, count as f64).width(bar_width))
+        .collect();
+
+    Plot::new("labs1to3_histogram_plot")
+        .height(PLOT_HEIGHT)
+        .x_axis_label(state.histogram_metric.label())
+        .y_axis_label("Число экспериментов")
+        .show(ui, |plot_ui| {
+            plot_ui.bar_chart(BarChart::new(state.histogram_metric.label(), bars));
+        });
+}
@@ -0,0 +1,188 @@
+use crate::coding::reed_solomon::{ReedSolomonCode, bits_to_symbols};
+use crate::formatting::{format_bits, format_symbols_hex};
+use crate::state::code_config::{CodeConfig, Labs4To6ExperimentResult};
+use crate::ui::widgets::add_label;
+
+/// Рендеринг параметров кода Рида–Соломона: m, n и k в символах, ошибки и стирания
+pub fn render_rs_parameters(ui: &mut egui::Ui, config: &mut CodeConfig) {
+    let parameters = &mut config.rs_parameters;
+    let mut changed = false;
+    ui.horizontal(|ui| {
+        ui.label("m (бит в символе):");
+        changed |= ui
+            .add(
+                egui::DragValue::new(&mut parameters.m)
+                    .range(ReedSolomonCode::MIN_M..=ReedSolomonCode::MAX_M),
+            )
+            .changed();
+        let order = (1 << parameters.m) - 1;
+        ui.label("n (символов):");
+        changed |= ui
+            .add(egui::DragValue::new(&mut parameters.n).range(2..=order))
+            .changed();
+        parameters.n = parameters.n.clamp(2, order);
+        ui.label("k (символов):");
+        changed |= ui
+            .add(egui::DragValue::new(&mut parameters.k).range(1..=parameters.n - 1))
+            .changed();
+        parameters.k = parameters.k.clamp(1, parameters.n - 1);
+    });
+    ui.horizontal(|ui| {
+        let parity = parameters.n - parameters.k;
+        ui.label("Стираний в слове:");
+        ui.add(egui::DragValue::new(&mut parameters.erasures).range(0..=parameters.n));
+        ui.label(format!("(исправимо при 2ν + f ≤ n - k = {parity})"));
+    });
+    if changed {
+        config.update_n_and_p();
+        config.analysis = None;
+        config.coverage = None;
+    }
+
+    if let Some(ref code) = config.rs_code {
+        ui.label(format!(
+            "RS({}, {}) над GF(2^{}): исправляет t = {} символьных ошибок или до {} стираний",
+            code.n,
+            code.k,
+            code.m(),
+            code.t(),
+            code.parity_symbols()
+        ));
+        ui.label(format!(
+            "g(x) = {}",
+            code.field.format_poly(&code.generator)
+        ));
+    }
+}
+
+/// Число искаженных символов в модели «Одиночная ошибка»
+pub fn render_symbol_errors(ui: &mut egui::Ui, config: &mut CodeConfig) {
+    ui.horizontal(|ui| {
+        ui.label("Искаженных символов в слове ν:");
+        ui.add(
+            egui::DragValue::new(&mut config.rs_parameters.symbol_errors)
+                .range(0..=config.rs_parameters.n),
+        );
+    });
+}
+
+/// Слова эксперимента по символам: биты и шестнадцатеричные значения символов
+///
+/// Искаженные символы принятого слова выделены красным, стертые - оранжевым
+pub fn render_symbol_view(
+    ui: &mut egui::Ui,
+    code: &ReedSolomonCode,
+    result: &Labs4To6ExperimentResult,
+) {
+    let m = code.m();
+    let codeword = bits_to_symbols(&result.codeword, m);
+    let received = bits_to_symbols(&result.received, m);
+
+    add_label(ui, "Символы GF(2^m) в шестнадцатеричном виде:");
+    egui::Grid::new(("rs_symbols", result.seed))
+        .striped(true)
+        .show(ui, |ui| {
+            for (label, bits) in [
+                ("Сообщение", &result.message),
+                ("Кодовое слово", &result.codeword),
+            ] {
+                ui.label(label);
+                ui.monospace(format_bits(bits));
+                ui.monospace(format_symbols_hex(&bits_to_symbols(bits, m), m));
+                ui.end_row();
+            }
+
+            ui.label("Принятое слово");
+            ui.monospace(format_bits(&result.received));
+            ui.horizontal(|ui| {
+                ui.spacing_mut().item_spacing.x = 0.0;
+                for (i, (&symbol, &sent)) in received.iter().zip(&codeword).enumerate() {
+                    let text =
+                        egui::RichText::new(format!("{} ", format_symbols_hex(&[symbol], m)))
+                            .monospace();
+                    let text = if result.erasures.contains(&(i + 1)) {
+                        text.color(egui::Color32::from_rgb(200, 120, 0))
+                    } else if symbol != sent {
+                        text.color(egui::Color32::RED)
+                    } else {
+                        text
+                    };
+                    ui.label(text);
+                }
+            });
+            ui.end_row();
+
+            ui.label("Исправленное слово");
+            ui.monospace(format_bits(&result.corrected));
+            ui.monospace(format_symbols_hex(
+                &bits_to_symbols(&result.corrected, m),
+                m,
+            ));
+            ui.end_row();
+        });
+
+    if !result.erasures.is_empty() {
+        ui.label(format!("Стерты символы: {:?}", result.erasures));
+    }
+}
+
+/// Синдромы, многочлены Γ(x), Λ(x), Ω(x) и значения ошибок по Форни
+pub fn render_rs_decoding(
+    ui: &mut egui::Ui,
+    code: &ReedSolomonCode,
+    result: &Labs4To6ExperimentResult,
+) {
+    let decoding = match code.decode(&result.received, &result.erasures) {
+        Ok((_, decoding)) => decoding,
+        Err(err) => {
+            ui.colored_label(egui::Color32::RED, format!("Ошибка: {err}"));
+            return;
+        }
+    };
+    let field = &code.field;
+
+    add_label(ui, "Синдромы S_j = r(α^j):");
+    let syndromes: Vec<String> = decoding
+        .syndromes
+        .iter()
+        .enumerate()
+        .map(|(j, &s)| format!("S{} = {}", j + 1, field.format(s)))
+        .collect();
+    ui.label(syndromes.join(", "));
+
+    if decoding.syndromes.iter().all(|&s| s == 0) {
+        return;
+    }
+
+    if !result.erasures.is_empty() {
+        add_label(ui, "Многочлен локаторов стираний:");
+        ui.label(format!(
+            "Γ(x) = {}",
+            field.format_poly(&decoding.erasure_locator)
+        ));
+    }
+    add_label(
+        ui,
+        "Многочлен локаторов ошибок (Берлекэмп–Мэсси по синдромам Форни):",
+    );
+    ui.label(format!(
+        "Λ(x) = {}",
+        field.format_poly(&decoding.error_locator)
+    ));
+    if !decoding.evaluator.is_empty() {
+        ui.label(format!("Ω(x) = {}", field.format_poly(&decoding.evaluator)));
+    }
+
+    add_label(ui, "Процедура Ченя и значения ошибок (Форни):");
+    if decoding.errata.is_empty() {
+        ui.label("корней не найдено");
+    }
+    for &(position, magnitude) in &decoding.errata {
+        ui.label(format!(
+            "символ {}: e = {} ({})",
+            position + 1,
+            field.format(magnitude),
+            format_symbols_hex(&[magnitude], code.m())
+        ));
+    }
+}
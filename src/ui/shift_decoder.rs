@@ -0,0 +1,80 @@
+use crate::coding::cyclic::{CyclicCode, CyclicDecoder, ShiftDecoding};
+use crate::formatting::format_bits;
+
+/// Трассировка декодера Меггитта или вылавливания ошибок по сдвигам
+pub fn render_shift_trace(
+    ui: &mut egui::Ui,
+    code: &CyclicCode,
+    decoder: CyclicDecoder,
+    received: &[bool],
+) {
+    let decoding: ShiftDecoding = match decoder {
+        CyclicDecoder::Table => return,
+        CyclicDecoder::Meggitt => code.decode_meggitt(received),
+        CyclicDecoder::ErrorTrapping => code.decode_error_trapping(received),
+    };
+    if decoding.trace.is_empty() {
+        return;
+    }
+
+    egui::CollapsingHeader::new(format!(
+        "{}: {} сдвигов синдрома",
+        decoder.label(),
+        decoding.trace.len()
+    ))
+    .id_salt(("shift_trace", received))
+    .show(ui, |ui| {
+        match decoder {
+            CyclicDecoder::Meggitt => {
+                let mut highest_error = vec![false; code.n];
+                highest_error[0] = true;
+                ui.label(format!(
+                    "Синдром ошибки в разряде 1 (x^{}): ({})",
+                    code.n - 1,
+                    format_bits(&code.syndrome(&highest_error))
+                ));
+            }
+            CyclicDecoder::ErrorTrapping => {
+                ui.label("Ошибка вылавливается, когда вес синдрома становится равным 1");
+            }
+            CyclicDecoder::Table => {}
+        }
+
+        egui::ScrollArea::vertical()
+            .id_salt(("shift_trace_scroll", received))
+            .max_height(250.0)
+            .show(ui, |ui| {
+                egui::Grid::new(("shift_trace_grid", received))
+                    .striped(true)
+                    .show(ui, |ui| {
+                        for header in ["Сдвиг j", "Синдром x^j·r(x) mod g(x)", "Действие"]
+                        {
+                            ui.label(egui::RichText::new(header).strong());
+                        }
+                        ui.end_row();
+
+                        for step in &decoding.trace {
+                            ui.label(step.shift.to_string());
+                            ui.monospace(format!("({})", format_bits(&step.syndrome)));
+                            if step.corrected.is_empty() {
+                                ui.label("-");
+                            } else {
+                                let positions: Vec<String> = step
+                                    .corrected
+                                    .iter()
+                                    .map(|position| (position + 1).to_string())
+                                    .collect();
+                                ui.label(
+                                    egui::RichText::new(format!(
+                                        "инвертирован разряд {}",
+                                        positions.join(", ")
+                                    ))
+                                    .strong(),
+                                );
+                            }
+                            ui.end_row();
+                        }
+                    });
+            });
+    });
+}
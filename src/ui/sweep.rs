@@ -0,0 +1,263 @@
+use crate::state::experiments::{ExperimentMetric, ExperimentResult, Labs1To3State};
+use crate::state::sweep::{MetricStats, SweepJob, SweepParameter, SweepPoint};
+use crate::ui::labs1to3::{ExperimentParams, compute_experiment};
+use crate::utils::random::{experiment_seed, random_seed};
+use crate::utils::statistics::{calculate_average, calculate_min_max};
+use egui_plot::{Legend, Line, LineStyle, Plot, PlotPoints};
+
+/// Показатели, собираемые при переборе: количество информации и пропускные способности
+fn sweep_metrics(state: &Labs1To3State) -> Vec<ExperimentMetric> {
+    [
+        ExperimentMetric::MutualInformation,
+        ExperimentMetric::ChannelCapacity,
+        ExperimentMetric::CapacityNoNoise,
+        ExperimentMetric::CapacityWithNoise,
+    ]
+    .into_iter()
+    .filter(|metric| metric.is_available(state.with_noise, state.with_duration))
+    .collect()
+}
+
+/// Рендеринг панели перебора параметра
+///
+/// Перебор идет в фоновом потоке `job`; готовые точки переносятся в `state.sweep_results`
+pub fn render_sweep_panel(
+    ui: &mut egui::Ui,
+    state: &mut Labs1To3State,
+    job: &mut Option<SweepJob>,
+) {
+    render_sweep_parameters(ui, state);
+
+    if let Some(job) = job.as_mut() {
+        job.poll(&mut state.sweep_results);
+    }
+    match *job {
+        Some(ref running) if !running.finished => {
+            let mut stop = false;
+            ui.horizontal(|ui| {
+                ui.spinner();
+                ui.label(format!(
+                    "Перебор: {} из {} точек",
+                    state.sweep_results.len(),
+                    running.total
+                ));
+                stop = ui.button("Остановить").clicked();
+            });
+            if stop {
+                running.cancel();
+            }
+        }
+        _ => {
+            if ui.button("Запустить перебор").clicked() {
+                *job = Some(spawn_sweep(state));
+            }
+        }
+    }
+
+    if !state.sweep_results.is_empty() {
+        ui.separator();
+        render_sweep_plot(ui, state);
+        ui.add_space(8.0);
+        render_sweep_table(ui, state);
+    }
+}
+
+/// Рендеринг настроек перебора
+fn render_sweep_parameters(ui: &mut egui::Ui, state: &mut Labs1To3State) {
+    let sweep = &mut state.sweep;
+
+    ui.horizontal(|ui| {
+        ui.label("Параметр:");
+        let previous = sweep.parameter;
+        egui::ComboBox::from_id_salt("sweep_parameter")
+            .selected_text(sweep.parameter.label())
+            .show_ui(ui, |ui| {
+                for parameter in SweepParameter::ALL {
+                    ui.selectable_value(&mut sweep.parameter, parameter, parameter.label());
+                }
+            });
+        if sweep.parameter != previous {
+            (sweep.from, sweep.to) = sweep.parameter.bounds();
+        }
+    });
+
+    let (min, max) = sweep.parameter.bounds();
+    let speed = (max - min) / 200.0;
+    ui.horizontal(|ui| {
+        ui.label("от");
+        ui.add(
+            egui::DragValue::new(&mut sweep.from)
+                .range(min..=max)
+                .speed(speed),
+        );
+        ui.label("до");
+        ui.add(
+            egui::DragValue::new(&mut sweep.to)
+                .range(min..=max)
+                .speed(speed),
+        );
+        ui.label("точек:");
+        ui.add(egui::DragValue::new(&mut sweep.points).range(1..=200));
+        ui.label("экспериментов в точке:");
+        ui.add(egui::DragValue::new(&mut sweep.experiments_per_point).range(1..=100));
+    });
+
+    let hint = match sweep.parameter {
+        SweepParameter::MinThreshold if !state.with_noise || state.with_duration => {
+            Some("Порог влияет на результат только при учете помех без режима длительностей")
+        }
+        SweepParameter::DurationScale if !state.with_duration => {
+            Some("Масштаб длительностей влияет на результат только в режиме длительностей")
+        }
+        _ => None,
+    };
+    if let Some(hint) = hint {
+        ui.label(egui::RichText::new(hint).italics());
+    }
+}
+
+/// Запуск серии экспериментов в каждой точке перебора в фоновом потоке
+///
+/// Поток получает копию состояния, поэтому изменения параметров на идущий перебор не влияют
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+fn spawn_sweep(state: &mut Labs1To3State) -> SweepJob {
+    if state.randomize_seed {
+        state.seed = random_seed();
+    }
+    state.sweep_results.clear();
+
+    let metrics = sweep_metrics(state);
+    let experiments = state.sweep.experiments_per_point.max(1);
+    let base = ExperimentParams {
+        manual_input: false,
+        ..ExperimentParams::from_state(state)
+    };
+    let snapshot = Labs1To3State {
+        results: vec![],
+        sweep_results: vec![],
+        ..state.clone()
+    };
+
+    SweepJob::spawn(state.sweep.values(), move |point, value| {
+        let params = match snapshot.sweep.parameter {
+            SweepParameter::Signals => ExperimentParams {
+                signals: value as usize,
+                ..base
+            },
+            SweepParameter::MinThreshold => ExperimentParams {
+                min_threshold: value,
+                ..base
+            },
+            SweepParameter::DurationScale => ExperimentParams {
+                duration_scale: value,
+                ..base
+            },
+        };
+
+        let results: Vec<ExperimentResult> = (0..experiments)
+            .map(|i| {
+                let seed = experiment_seed(snapshot.seed, point * experiments + i);
+                compute_experiment(&snapshot, &params, seed)
+            })
+            .collect();
+
+        let metric_stats = metrics
+            .iter()
+            .map(|&metric| {
+                let mean = calculate_average(&results, |r| metric.value(r));
+                let (min, max) = calculate_min_max(&results, |r| metric.value(r));
+                (metric, MetricStats { mean, min, max })
+            })
+            .collect();
+
+        SweepPoint {
+            value,
+            stats: metric_stats,
+        }
+    })
+}
+
+/// График среднего, минимума и максимума показателей от значения параметра
+fn render_sweep_plot(ui: &mut egui::Ui, state: &Labs1To3State) {
+    let metrics = sweep_metrics(state);
+    let series = |metric: ExperimentMetric, f: fn(MetricStats) -> f64| -> PlotPoints<'static> {
+        state
+            .sweep_results
+            .iter()
+            .filter_map(|point| point.get(metric).map(|stats| [point.value, f(stats)]))
+            .collect()
+    };
+
+    Plot::new("labs1to3_sweep_plot")
+        .height(260.0)
+        .legend(Legend::default())
+        .x_axis_label(state.sweep.parameter.label())
+        .include_y(0.0)
+        .show(ui, |plot_ui| {
+            for metric in metrics {
+                plot_ui.line(
+                    Line::new(
+                        format!("{} (среднее)", metric.label()),
+                        series(metric, |s| s.mean),
+                    )
+                    .width(2.0),
+                );
+                plot_ui.line(
+                    Line::new(
+                        format!("{} (мин./макс.)", metric.label()),
+                        series(metric, |s| s.min),
+                    )
+                    .style(LineStyle::dashed_dense()),
+                );
+                plot_ui.line(
+                    Line::new(
+                        format!("{} (мин./макс.)", metric.label()),
+                        series(metric, |s| s.max),
+                    )
+                    .style(LineStyle::dashed_dense()),
+                );
+            }
+        });
+}
+
+/// Таблица результатов перебора
+fn render_sweep_table(ui: &mut egui::Ui, state: &Labs1To3State) {
+    let metrics = sweep_metrics(state);
+
+    egui::ScrollArea::both()
+        .id_salt("sweep_table_scroll")
+        .max_height(300.0)
+        .show(ui, |ui| {
+            egui::Grid::new("sweep_table_grid")
+                .striped(true)
+                .num_columns(1 + metrics.len() * 3)
+                .show(ui, |ui| {
+                    ui.label(egui::RichText::new(state.sweep.parameter.label()).strong());
+                    for metric in &metrics {
+                        for suffix in ["ср.", "мин.", "макс."] {
+                            ui.label(
+                                egui::RichText::new(format!("{} {suffix}", metric.label()))
+                                    .strong(),
+                            );
+                        }
+                    }
+                    ui.end_row();
+
+                    for point in &state.sweep_results {
+                        ui.label(format!("{:.3}", point.value));
+                        for &metric in &metrics {
+                            if let Some(stats) = point.get(metric) {
+                                ui.label(format!("{:.5}", stats.mean));
+                                ui.label(format!("{:.5}", stats.min));
+                                ui.label(format!("{:.5}", stats.max));
+                            } else {
+                                ui.label("-");
+                                ui.label("-");
+                                ui.label("-");
+                            }
+                        }
+                        ui.end_row();
+                    }
+                });
+        });
+}
@@ -21,3 +21,15 @@ pub fn format_probabilities_row(probs: &[f64], prefix: &str, items_per_row: usiz
         })
         .collect()
 }
+
+/// Поле seed генератора случайных чисел
+pub fn render_seed_controls(ui: &mut egui::Ui, seed: &mut u64, randomize_seed: &mut bool) {
+    ui.horizontal(|ui| {
+        ui.label("Seed:");
+        ui.add_enabled(
+            !*randomize_seed,
+            egui::DragValue::new(seed).range(0..=u32::MAX),
+        );
+        ui.checkbox(randomize_seed, "Новый seed при каждом запуске");
+    });
+}
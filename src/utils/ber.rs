@@ -0,0 +1,258 @@
+use crate::session::CodeConfigSnapshot;
+use crate::state::{CodeConfig, SelectedCodeType};
+use crate::utils::random::{generate_message, rng_from_seed, transmit_bsc};
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::mpsc::{self, Receiver, TryRecvError};
+use std::thread;
+
+/// Настройки моделирования вероятности ошибки на бит
+#[derive(Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct BerSettings {
+    /// Минимальная вероятность ошибки в канале
+    pub min_probability: f64,
+    /// Максимальная вероятность ошибки в канале
+    pub max_probability: f64,
+    /// Число точек (логарифмическая шкала)
+    pub points: usize,
+    /// Число кодовых слов в каждой точке
+    pub codewords_per_point: usize,
+}
+
+impl Default for BerSettings {
+    fn default() -> Self {
+        Self {
+            min_probability: 1e-4,
+            max_probability: 1e-1,
+            points: 10,
+            codewords_per_point: 2000,
+        }
+    }
+}
+
+impl BerSettings {
+    /// Вероятности ошибки в канале, равномерно распределенные по логарифмической шкале
+    #[must_use]
+    #[allow(clippy::cast_precision_loss)]
+    pub fn probabilities(&self) -> Vec<f64> {
+        let from = self.min_probability.clamp(1e-9, 0.5).log10();
+        let to = self.max_probability.clamp(1e-9, 0.5).log10();
+        let points = self.points.max(1);
+        (0..points)
+            .map(|i| {
+                if points == 1 {
+                    from
+                } else {
+                    from + (to - from) * i as f64 / (points - 1) as f64
+                }
+            })
+            .map(|exponent| 10f64.powf(exponent))
+            .collect()
+    }
+}
+
+/// Результат моделирования в одной точке
+#[derive(Clone, Serialize, Deserialize)]
+pub struct BerPoint {
+    /// Вероятность ошибки на бит в канале (без кодирования)
+    pub raw_ber: f64,
+    /// Доля ошибочных информационных битов после декодирования
+    pub decoded_ber: f64,
+    /// Доля неверно декодированных кодовых слов
+    pub word_error_rate: f64,
+    /// Доля слов, в которых декодер обнаружил неисправимую ошибку
+    pub detected_rate: f64,
+    /// Теоретическая оценка BER для кода, исправляющего t ошибок (`None`, если t не определено)
+    pub bound_ber: Option<f64>,
+    /// Теоретическая оценка WER для кода, исправляющего t ошибок (`None`, если t не определено)
+    pub bound_wer: Option<f64>,
+}
+
+/// Моделирование BER/WER выбранного кода методом Монте-Карло в двоичном симметричном канале
+///
+/// BER считается по информационным разрядам: декодированное сообщение сравнивается
+/// с переданным. Каждая готовая точка передается в `on_point`; если `cancel` установлен,
+/// моделирование прерывается
+///
+/// # Errors
+/// Возвращает ошибку, если код не инициализирован или у блочного кода нельзя выделить
+/// информационные разряды, по которым считается BER
+#[allow(clippy::cast_precision_loss)]
+pub fn simulate_ber(
+    config: &CodeConfig,
+    settings: &BerSettings,
+    seed: u64,
+    cancel: &AtomicBool,
+    mut on_point: impl FnMut(BerPoint),
+) -> Result<(), String> {
+    const NOT_INITIALIZED: &str = "код не инициализирован";
+
+    let mut rng = rng_from_seed(seed);
+    if !config.can_run_experiments() {
+        return Err(NOT_INITIALIZED.to_string());
+    }
+    let positions = config.information_positions();
+    if positions.is_none() && config.code_type != SelectedCodeType::Convolutional {
+        return Err("BER недоступен для этого кода".to_string());
+    }
+
+    for raw_ber in settings.probabilities() {
+        let mut bit_errors = 0usize;
+        let mut word_errors = 0usize;
+        let mut detected = 0usize;
+        let mut length = 0;
+
+        for _ in 0..settings.codewords_per_point {
+            if cancel.load(Ordering::Relaxed) {
+                return Ok(());
+            }
+            let message = generate_message(config.k, &mut rng);
+            let codeword = config.encode(&message).ok_or(NOT_INITIALIZED)?;
+            let (received, _) = transmit_bsc(&codeword, raw_ber, &mut rng);
+            let (corrected, error_info) = config.decode(&received).ok_or(NOT_INITIALIZED)?;
+            let decoded = config
+                .decoded_message(&received, &corrected, positions.as_deref())
+                .ok_or(NOT_INITIALIZED)?;
+
+            length = codeword.len();
+            let errors = decoded.iter().zip(&message).filter(|(a, b)| a != b).count();
+            bit_errors += errors;
+            word_errors += usize::from(errors > 0);
+            detected += usize::from(error_info.is_uncorrectable());
+        }
+
+        let words = settings.codewords_per_point.max(1) as f64;
+        let bound = code_bound(config, length, raw_ber);
+        on_point(BerPoint {
+            raw_ber,
+            decoded_ber: bit_errors as f64 / (words * config.k.max(1) as f64),
+            word_error_rate: word_errors as f64 / words,
+            detected_rate: detected as f64 / words,
+            bound_ber: bound.map(|(ber, _)| ber),
+            bound_wer: bound.map(|(_, wer)| wer),
+        });
+    }
+
+    Ok(())
+}
+
+/// Моделирование BER/WER, запущенное в фоновом потоке
+///
+/// Код для потока строится заново из снимка конфигурации, поэтому изменения
+/// параметров в интерфейсе на идущее моделирование не влияют
+pub struct BerJob {
+    receiver: Receiver<Result<BerPoint, String>>,
+    cancel: Arc<AtomicBool>,
+    /// Число точек моделирования
+    pub total: usize,
+    /// Поток завершился
+    pub finished: bool,
+    /// Причина, по которой моделирование не выполнено
+    pub error: Option<String>,
+}
+
+impl BerJob {
+    /// Запуск моделирования в фоновом потоке
+    #[must_use]
+    pub fn spawn(config: &CodeConfig, settings: BerSettings, seed: u64) -> Self {
+        let snapshot = CodeConfigSnapshot::from(config);
+        let (sender, receiver) = mpsc::channel();
+        let cancel = Arc::new(AtomicBool::new(false));
+        let total = settings.probabilities().len();
+
+        let worker_cancel = Arc::clone(&cancel);
+        thread::spawn(move || {
+            let result = CodeConfig::try_from(snapshot).and_then(|config| {
+                simulate_ber(&config, &settings, seed, &worker_cancel, |point| {
+                    let _ = sender.send(Ok(point));
+                })
+            });
+            if let Err(err) = result {
+                let _ = sender.send(Err(err));
+            }
+        });
+
+        Self {
+            receiver,
+            cancel,
+            total,
+            finished: false,
+            error: None,
+        }
+    }
+
+    /// Перенос готовых точек в `results`
+    pub fn poll(&mut self, results: &mut Vec<BerPoint>) {
+        while !self.finished {
+            match self.receiver.try_recv() {
+                Ok(Ok(point)) => results.push(point),
+                Ok(Err(err)) => self.error = Some(err),
+                Err(TryRecvError::Empty) => return,
+                Err(TryRecvError::Disconnected) => self.finished = true,
+            }
+        }
+    }
+
+    /// Остановка моделирования
+    pub fn cancel(&self) {
+        self.cancel.store(true, Ordering::Relaxed);
+    }
+}
+
+impl Drop for BerJob {
+    fn drop(&mut self) {
+        self.cancel();
+    }
+}
+
+/// Теоретические BER и WER выбранного кода с передаваемым словом длины `length`
+///
+/// Код Рида–Соломона исправляет символы, поэтому оценка для него строится по символам:
+/// символ из m бит искажается с вероятностью `p_s = 1 - (1 - p)^m`, а в ошибочном символе
+/// в среднем ошибочна доля `2^(m-1) / (2^m - 1)` битов. Для кодов без блочного t
+/// (см. [`CodeConfig::correctable_errors`]) оценки нет
+fn code_bound(config: &CodeConfig, length: usize, p: f64) -> Option<(f64, f64)> {
+    let t = config.correctable_errors()?;
+    Some(match config.rs_code {
+        Some(ref code) if config.code_type == SelectedCodeType::ReedSolomon => {
+            let m = i32::try_from(code.m()).unwrap_or(i32::MAX);
+            let symbol_p = 1.0 - (1.0 - p).powi(m);
+            let (symbol_ber, wer) = error_correcting_bound(code.n, t, symbol_p);
+            let bits_per_symbol = 2f64.powi(m - 1) / (2f64.powi(m) - 1.0);
+            (symbol_ber * bits_per_symbol, wer)
+        }
+        _ => error_correcting_bound(length, t, p),
+    })
+}
+
+/// Теоретические BER и WER для кода длины `n`, исправляющего `t` ошибок
+///
+/// WER - вероятность `t + 1` и более ошибок в слове. Для BER считается, что слово
+/// с `j > t` ошибками после декодирования содержит не более `j + t` ошибочных битов
+#[must_use]
+#[allow(clippy::cast_precision_loss)]
+pub fn error_correcting_bound(n: usize, t: usize, p: f64) -> (f64, f64) {
+    if n == 0 || p <= 0.0 {
+        return (0.0, 0.0);
+    }
+    if p >= 1.0 {
+        return (1.0, 1.0);
+    }
+
+    // term = C(n, j) p^j (1-p)^(n-j)
+    let mut term = (1.0 - p).powi(i32::try_from(n).unwrap_or(i32::MAX));
+    let ratio = p / (1.0 - p);
+    let mut wer = 0.0;
+    let mut ber = 0.0;
+    for j in 0..=n {
+        if j > t {
+            wer += term;
+            ber += (j + t).min(n) as f64 * term;
+        }
+        term *= (n - j) as f64 / (j + 1) as f64 * ratio;
+    }
+
+    (ber / n as f64, wer)
+}
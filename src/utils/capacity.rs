@@ -0,0 +1,102 @@
+use serde::{Deserialize, Serialize};
+
+/// Результат расчета пропускной способности канала
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct CapacityResult {
+    /// Пропускная способность C, бит на символ
+    pub capacity: f64,
+    /// Распределение на входе, на котором достигается C
+    pub input_distribution: Vec<f64>,
+    /// Число выполненных итераций
+    pub iterations: usize,
+    /// Достигнута ли заданная точность до исчерпания лимита итераций
+    pub converged: bool,
+}
+
+/// Расчет пропускной способности дискретного канала алгоритмом Блахута–Аримото
+///
+/// `transition[i][j]` = `p(y_j | x_i)`. Итерации прекращаются, когда верхняя
+/// (`max_i D_i`) и нижняя (`log2 Σ p_i 2^D_i`) оценки C сходятся ближе `tolerance`
+#[must_use]
+#[allow(clippy::cast_precision_loss)]
+pub fn blahut_arimoto(
+    transition: &[Vec<f64>],
+    tolerance: f64,
+    max_iterations: usize,
+) -> CapacityResult {
+    let inputs = transition.len();
+    if inputs == 0 {
+        return CapacityResult {
+            capacity: 0.0,
+            input_distribution: vec![],
+            iterations: 0,
+            converged: true,
+        };
+    }
+
+    let mut distribution = vec![1.0 / inputs as f64; inputs];
+    let mut capacity = 0.0;
+
+    for iteration in 1..=max_iterations {
+        let divergences = divergences(transition, &distribution);
+
+        let weights: Vec<f64> = distribution
+            .iter()
+            .zip(&divergences)
+            .map(|(p, d)| p * d.exp2())
+            .collect();
+        let total: f64 = weights.iter().sum();
+
+        let lower = total.log2();
+        let upper = divergences
+            .iter()
+            .copied()
+            .fold(f64::NEG_INFINITY, f64::max);
+        capacity = lower;
+
+        for (p, w) in distribution.iter_mut().zip(&weights) {
+            *p = w / total;
+        }
+
+        if upper - lower < tolerance {
+            return CapacityResult {
+                capacity,
+                input_distribution: distribution,
+                iterations: iteration,
+                converged: true,
+            };
+        }
+    }
+
+    CapacityResult {
+        capacity,
+        input_distribution: distribution,
+        iterations: max_iterations,
+        converged: false,
+    }
+}
+
+/// Относительные энтропии `D(p(y|x_i) || q(y))` в битах для каждого входа
+fn divergences(transition: &[Vec<f64>], distribution: &[f64]) -> Vec<f64> {
+    let outputs = transition.first().map_or(0, Vec::len);
+    let output_probs: Vec<f64> = (0..outputs)
+        .map(|j| {
+            transition
+                .iter()
+                .zip(distribution)
+                .map(|(row, p)| p * row[j])
+                .sum()
+        })
+        .collect();
+
+    transition
+        .iter()
+        .map(|row| {
+            row.iter()
+                .zip(&output_probs)
+                .filter(|(w, q)| **w > 0.0 && **q > 0.0)
+                .map(|(w, q)| w * (w / q).log2())
+                .sum()
+        })
+        .collect()
+}
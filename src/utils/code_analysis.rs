@@ -0,0 +1,200 @@
+use crate::utils::random::rng_from_seed;
+use rand::Rng;
+
+/// Наибольшее `k`, при котором кодовые слова перебираются полностью
+pub const EXHAUSTIVE_MAX_K: usize = 20;
+
+/// Наибольшее число комбинаций строк базиса при ограниченном поиске
+const MAX_COMBINATIONS: usize = 2_000_000;
+
+/// Результат анализа весов кодовых слов
+#[derive(Clone)]
+pub struct CodeAnalysis {
+    pub n: usize,
+    pub k: usize,
+    /// Перебраны все 2^k кодовых слов
+    pub exhaustive: bool,
+    /// Число рассмотренных кодовых слов
+    pub examined: u64,
+    /// Число слов каждого веса: `A_w` при полном переборе, иначе частоты в случайной выборке
+    pub weight_distribution: Vec<u64>,
+    /// Минимальное расстояние (при неполном переборе - оценка сверху)
+    pub min_distance: usize,
+    /// Ненулевое кодовое слово минимального веса
+    pub min_weight_word: Vec<bool>,
+}
+
+impl CodeAnalysis {
+    /// Гарантированно обнаруживаемая кратность ошибок
+    #[must_use]
+    pub const fn detectable(&self) -> usize {
+        self.min_distance.saturating_sub(1)
+    }
+
+    /// Гарантированно исправляемая кратность ошибок
+    #[must_use]
+    pub const fn correctable(&self) -> usize {
+        self.min_distance.saturating_sub(1) / 2
+    }
+}
+
+/// Слово в виде битового набора
+type Word = Vec<u64>;
+
+fn to_word(bits: &[bool]) -> Word {
+    let mut word = vec![0u64; bits.len().div_ceil(64)];
+    for (i, &bit) in bits.iter().enumerate() {
+        if bit {
+            word[i / 64] |= 1 << (i % 64);
+        }
+    }
+    word
+}
+
+fn from_word(word: &[u64], n: usize) -> Vec<bool> {
+    (0..n)
+        .map(|i| (word[i / 64] >> (i % 64)) & 1 == 1)
+        .collect()
+}
+
+fn xor_into(target: &mut [u64], source: &[u64]) {
+    for (t, s) in target.iter_mut().zip(source) {
+        *t ^= s;
+    }
+}
+
+fn weight(word: &[u64]) -> usize {
+    word.iter().map(|chunk| chunk.count_ones() as usize).sum()
+}
+
+/// Анализ весов линейного кода, заданного базисом
+///
+/// При `k <= EXHAUSTIVE_MAX_K` кодовые слова перебираются полностью (в порядке кода Грея).
+/// Иначе минимальное расстояние оценивается по суммам одной-трех строк базиса и
+/// случайной выборке из `samples` слов, по которой же строится распределение весов
+#[must_use]
+pub fn analyze_code(basis: &[Vec<bool>], samples: usize, seed: u64) -> CodeAnalysis {
+    let k = basis.len();
+    let n = basis.first().map_or(0, Vec::len);
+    let rows: Vec<Word> = basis.iter().map(|row| to_word(row)).collect();
+    let mut weight_distribution = vec![0u64; n + 1];
+    let mut min_distance = usize::MAX;
+    let mut min_weight_word = vec![0u64; n.div_ceil(64)];
+    let mut consider = |word: &[u64], min_word: &mut Word| -> usize {
+        let w = weight(word);
+        if w > 0 && w < min_distance {
+            min_distance = w;
+            min_word.copy_from_slice(word);
+        }
+        w
+    };
+
+    let exhaustive = k <= EXHAUSTIVE_MAX_K;
+    let examined = if exhaustive {
+        let mut word = vec![0u64; n.div_ceil(64)];
+        weight_distribution[0] = 1;
+        for i in 1..(1u64 << k) {
+            xor_into(&mut word, &rows[i.trailing_zeros() as usize]);
+            weight_distribution[consider(&word, &mut min_weight_word)] += 1;
+        }
+        1 << k
+    } else {
+        for (i, first) in rows.iter().enumerate() {
+            consider(first, &mut min_weight_word);
+            for (j, second) in rows.iter().enumerate().skip(i + 1) {
+                let mut pair = first.clone();
+                xor_into(&mut pair, second);
+                consider(&pair, &mut min_weight_word);
+                if k.saturating_mul(k).saturating_mul(k) / 6 > MAX_COMBINATIONS {
+                    continue;
+                }
+                for third in rows.iter().skip(j + 1) {
+                    let mut triple = pair.clone();
+                    xor_into(&mut triple, third);
+                    consider(&triple, &mut min_weight_word);
+                }
+            }
+        }
+
+        let mut rng = rng_from_seed(seed);
+        for _ in 0..samples {
+            let mut word = vec![0u64; n.div_ceil(64)];
+            for row in &rows {
+                if rng.random_bool(0.5) {
+                    xor_into(&mut word, row);
+                }
+            }
+            weight_distribution[consider(&word, &mut min_weight_word)] += 1;
+        }
+        samples as u64
+    };
+
+    CodeAnalysis {
+        n,
+        k,
+        exhaustive,
+        examined,
+        weight_distribution,
+        min_distance: if min_distance == usize::MAX {
+            0
+        } else {
+            min_distance
+        },
+        min_weight_word: from_word(&min_weight_word, n),
+    }
+}
+
+/// Граница Синглтона: `d <= n - k + 1`
+#[must_use]
+pub const fn singleton_bound(n: usize, k: usize) -> usize {
+    (n + 1).saturating_sub(k)
+}
+
+/// Граница Хэмминга (сферической упаковки)
+///
+/// Возвращает наибольшее `t`, для которого шары радиуса `t` вокруг 2^k кодовых слов
+/// помещаются в пространство: `sum C(n, i), i = 0..t <= 2^(n-k)`. Отсюда `d <= 2t + 2`
+#[must_use]
+#[allow(clippy::cast_precision_loss)]
+pub fn hamming_bound(n: usize, k: usize) -> usize {
+    let capacity = 2f64.powi(i32::try_from(n.saturating_sub(k)).unwrap_or(i32::MAX));
+    let mut volume = 0.0;
+    let mut binomial = 1.0;
+    for t in 0..=n {
+        volume += binomial;
+        if volume > capacity * (1.0 + 1e-12) {
+            return t.saturating_sub(1);
+        }
+        binomial *= (n - t) as f64 / (t + 1) as f64;
+    }
+    n
+}
+
+/// Достигается ли граница Хэмминга с равенством (совершенный код)
+#[must_use]
+#[allow(clippy::cast_precision_loss)]
+pub fn is_perfect(n: usize, k: usize, t: usize) -> bool {
+    let capacity = 2f64.powi(i32::try_from(n.saturating_sub(k)).unwrap_or(i32::MAX));
+    let mut volume = 0.0;
+    let mut binomial = 1.0;
+    for i in 0..=t.min(n) {
+        volume += binomial;
+        binomial *= (n - i) as f64 / (i + 1) as f64;
+    }
+    (volume - capacity).abs() <= capacity * 1e-12
+}
+
+/// Граница Плоткина: средний вес ненулевого слова `n 2^(k-1) / (2^k - 1)` не меньше `d`
+#[must_use]
+#[allow(
+    clippy::cast_precision_loss,
+    clippy::cast_possible_truncation,
+    clippy::cast_sign_loss
+)]
+pub fn plotkin_bound(n: usize, k: usize) -> usize {
+    if k == 0 {
+        return n;
+    }
+    let fraction = 1.0 / (2.0 - 2f64.powi(1 - i32::try_from(k).unwrap_or(i32::MAX)));
+    (n as f64).mul_add(fraction, 1e-9).floor() as usize
+}
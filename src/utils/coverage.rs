@@ -0,0 +1,137 @@
+use crate::state::code_config::{CodeConfig, DecodingOutcome};
+use crate::utils::random::{generate_message, rng_from_seed};
+
+/// Число сохраняемых примеров шаблонов для каждого итога
+const MAX_EXAMPLES: usize = 5;
+
+/// Наибольшее число шаблонов, которое перебирается за один запуск
+pub const MAX_PATTERNS: usize = 1_000_000;
+
+/// Итоги декодирования всех шаблонов ошибок одного веса
+#[derive(Clone)]
+pub struct CoverageRow {
+    pub weight: usize,
+    pub total: usize,
+    /// Число шаблонов по итогам в порядке `DecodingOutcome::ALL`
+    pub counts: [usize; DecodingOutcome::ALL.len()],
+    /// Примеры ложно исправленных и пропущенных шаблонов (позиции с 1)
+    pub failures: Vec<(DecodingOutcome, Vec<usize>)>,
+}
+
+impl CoverageRow {
+    #[must_use]
+    pub const fn count(&self, outcome: DecodingOutcome) -> usize {
+        self.counts[outcome as usize]
+    }
+
+    /// Все шаблоны этого веса имеют заданный итог
+    #[must_use]
+    pub const fn all(&self, outcome: DecodingOutcome) -> bool {
+        self.count(outcome) == self.total
+    }
+}
+
+/// Отчет о покрытии шаблонов ошибок
+#[derive(Clone)]
+pub struct CoverageReport {
+    /// Длина передаваемого слова
+    pub length: usize,
+    pub rows: Vec<CoverageRow>,
+}
+
+impl CoverageReport {
+    #[must_use]
+    pub fn row(&self, weight: usize) -> Option<&CoverageRow> {
+        self.rows.iter().find(|row| row.weight == weight)
+    }
+
+    /// Все одиночные ошибки исправляются, все двойные обнаруживаются (SECDED)
+    #[must_use]
+    pub fn is_secded(&self) -> bool {
+        self.row(1)
+            .is_some_and(|row| row.all(DecodingOutcome::Corrected))
+            && self
+                .row(2)
+                .is_some_and(|row| row.all(DecodingOutcome::Detected))
+    }
+}
+
+/// Перебор всех шаблонов ошибок веса от 1 до `max_weight` для текущего кода
+///
+/// Шаблоны накладываются на кодовое слово случайного сообщения (seed `seed`).
+/// Если шаблонов больше [`MAX_PATTERNS`], перебор не выполняется
+#[must_use]
+pub fn error_pattern_coverage(
+    config: &CodeConfig,
+    max_weight: usize,
+    seed: u64,
+) -> Option<CoverageReport> {
+    let mut rng = rng_from_seed(seed);
+    let message = generate_message(config.k, &mut rng);
+    let transmitted = config.encode(&message)?;
+    let length = transmitted.len();
+    if pattern_count(length, max_weight) > MAX_PATTERNS {
+        return None;
+    }
+
+    let mut rows = vec![];
+    for weight in 1..=max_weight.min(length) {
+        let mut row = CoverageRow {
+            weight,
+            total: 0,
+            counts: [0; DecodingOutcome::ALL.len()],
+            failures: vec![],
+        };
+
+        let mut positions: Vec<usize> = (0..weight).collect();
+        loop {
+            let mut received = transmitted.clone();
+            for &position in &positions {
+                received[position] = !received[position];
+            }
+            let outcome = config.evaluate(seed, message.clone(), received)?.outcome();
+            row.total += 1;
+            row.counts[outcome as usize] += 1;
+            if matches!(
+                outcome,
+                DecodingOutcome::Miscorrected | DecodingOutcome::Undetected
+            ) && row.failures.iter().filter(|(o, _)| *o == outcome).count() < MAX_EXAMPLES
+            {
+                row.failures
+                    .push((outcome, positions.iter().map(|p| p + 1).collect()));
+            }
+
+            if !next_combination(&mut positions, length) {
+                break;
+            }
+        }
+        rows.push(row);
+    }
+
+    Some(CoverageReport { length, rows })
+}
+
+/// Следующее сочетание позиций в лексикографическом порядке
+fn next_combination(positions: &mut [usize], n: usize) -> bool {
+    let k = positions.len();
+    let Some(i) = (0..k).rev().find(|&i| positions[i] < n - k + i) else {
+        return false;
+    };
+    positions[i] += 1;
+    for j in i + 1..k {
+        positions[j] = positions[j - 1] + 1;
+    }
+    true
+}
+
+/// Число шаблонов ошибок веса от 1 до `max_weight` в слове длины `n`
+#[must_use]
+pub fn pattern_count(n: usize, max_weight: usize) -> usize {
+    let mut total = 0usize;
+    let mut binomial = 1usize;
+    for w in 1..=max_weight.min(n) {
+        binomial = binomial * (n - w + 1) / w;
+        total = total.saturating_add(binomial);
+    }
+    total
+}
@@ -1 +1,8 @@
+pub mod ber;
+pub mod capacity;
+pub mod code_analysis;
+pub mod coverage;
+pub mod random;
 pub mod statistics;
+pub mod transmission;
+pub mod validation;
@@ -0,0 +1,301 @@
+use crate::state::channel::GilbertElliott;
+use rand::{Rng, SeedableRng};
+use rand_chacha::ChaCha8Rng;
+
+/// Воспроизводимый генератор случайных чисел
+///
+/// `ChaCha8Rng` дает одинаковую последовательность на всех платформах,
+/// поэтому эксперимент можно повторить по seed бит в бит
+pub type SeededRng = ChaCha8Rng;
+
+/// Создание генератора из seed
+#[must_use]
+pub fn rng_from_seed(seed: u64) -> SeededRng {
+    SeededRng::seed_from_u64(seed)
+}
+
+/// Случайный seed для нового запуска
+///
+/// Значение ограничено диапазоном `u32`, чтобы его можно было без потерь
+/// редактировать в `DragValue` (который работает через `f64`)
+#[must_use]
+pub fn random_seed() -> u64 {
+    u64::from(rand::rng().random::<u32>())
+}
+
+/// Seed отдельного эксперимента в серии
+#[must_use]
+pub const fn experiment_seed(base_seed: u64, index: usize) -> u64 {
+    base_seed.wrapping_add(index as u64)
+}
+
+/// Генерация распределения вероятностей источника (аналог `entropy_fx::generate_probabilities`)
+#[must_use]
+pub fn generate_probabilities<R: Rng>(signals: usize, rng: &mut R) -> Vec<f64> {
+    let weights: Vec<f64> = (0..signals).map(|_| rng.random_range(0.01..1.0)).collect();
+    let sum: f64 = weights.iter().sum();
+    weights.into_iter().map(|w| w / sum).collect()
+}
+
+/// Генерация матрицы переходов p(y|x) с вероятностью правильного приема не ниже порога
+/// (аналог `entropy_fx::generate_transition_matrix`)
+#[must_use]
+pub fn generate_transition_matrix<R: Rng>(
+    signals: usize,
+    min_threshold: f64,
+    rng: &mut R,
+) -> Vec<Vec<f64>> {
+    let min_threshold = min_threshold.clamp(0.0, 1.0);
+    (0..signals)
+        .map(|i| {
+            let diagonal = if signals == 1 || min_threshold >= 1.0 {
+                1.0
+            } else {
+                rng.random_range(min_threshold..=1.0)
+            };
+            distribute_row(i, signals, diagonal, rng)
+        })
+        .collect()
+}
+
+/// Генерация матрицы ошибок для режима расчета по длительностям
+/// (аналог `entropy_fx::generate_error_probability_matrix`)
+#[must_use]
+pub fn generate_error_probability_matrix<R: Rng>(signals: usize, rng: &mut R) -> Vec<Vec<f64>> {
+    (0..signals)
+        .map(|i| {
+            let diagonal = if signals == 1 {
+                1.0
+            } else {
+                rng.random_range(0.8..=1.0)
+            };
+            distribute_row(i, signals, diagonal, rng)
+        })
+        .collect()
+}
+
+/// Строка стохастической матрицы: `diagonal` на главной диагонали,
+/// остаток случайно распределяется по остальным элементам
+fn distribute_row<R: Rng>(row: usize, signals: usize, diagonal: f64, rng: &mut R) -> Vec<f64> {
+    let weights: Vec<f64> = (0..signals)
+        .map(|j| {
+            if j == row {
+                0.0
+            } else {
+                rng.random_range(0.01..1.0)
+            }
+        })
+        .collect();
+    let sum: f64 = weights.iter().sum();
+    let rest = 1.0 - diagonal;
+
+    weights
+        .into_iter()
+        .enumerate()
+        .map(|(j, w)| {
+            if j == row {
+                diagonal
+            } else if sum > 0.0 {
+                rest * w / sum
+            } else {
+                0.0
+            }
+        })
+        .collect()
+}
+
+/// Генерация длительностей символов в мкс (аналог `entropy_fx::generate_symbol_durations`)
+#[must_use]
+pub fn generate_symbol_durations<R: Rng>(signals: usize, rng: &mut R) -> Vec<f64> {
+    (0..signals).map(|_| rng.random_range(1.0..=10.0)).collect()
+}
+
+/// Генерация случайного сообщения длины `k`
+#[must_use]
+pub fn generate_message<R: Rng>(k: usize, rng: &mut R) -> Vec<bool> {
+    (0..k).map(|_| rng.random_range(0..2) == 1).collect()
+}
+
+/// Внесение одиночной ошибки с заданной вероятностью
+///
+/// Возвращает принятое слово и позицию ошибки (с 0), если ошибка была внесена.
+/// Используется для систематического и циклического кодов
+/// (аналог `inject_single_error` и `inject_single_error_cyclic`)
+#[must_use]
+pub fn inject_single_error<R: Rng>(
+    codeword: &[bool],
+    error_probability: f64,
+    rng: &mut R,
+) -> (Vec<bool>, Option<usize>) {
+    let mut received = codeword.to_vec();
+    if received.is_empty() || !rng.random_bool(error_probability.clamp(0.0, 1.0)) {
+        return (received, None);
+    }
+    let position = rng.random_range(0..received.len());
+    received[position] = !received[position];
+    (received, Some(position))
+}
+
+/// Случайная кратность ошибки для кода Хэмминга: 0, 1 или 2
+/// (аналог `entropy_fx::coding::hamming::generate_error_multiplicity`)
+#[must_use]
+pub fn generate_error_multiplicity<R: Rng>(rng: &mut R) -> usize {
+    rng.random_range(0..=2)
+}
+
+/// Внесение ошибок заданной кратности в различные разряды
+///
+/// Возвращает принятое слово и отсортированные позиции ошибок (с 1), как
+/// `entropy_fx::coding::hamming::inject_errors`
+#[must_use]
+pub fn inject_errors<R: Rng>(
+    codeword: &[bool],
+    multiplicity: usize,
+    rng: &mut R,
+) -> (Vec<bool>, Vec<usize>) {
+    let mut received = codeword.to_vec();
+    let multiplicity = multiplicity.min(received.len());
+    let mut positions = rand::seq::index::sample(rng, received.len(), multiplicity).into_vec();
+    positions.sort_unstable();
+    for &position in &positions {
+        received[position] = !received[position];
+    }
+    (received, positions.into_iter().map(|p| p + 1).collect())
+}
+
+/// Искажение `count` различных символов по `m` бит: каждый символ складывается
+/// со случайным ненулевым значением
+///
+/// Возвращает принятое слово и отсортированные номера искаженных символов (с 0)
+#[must_use]
+pub fn inject_symbol_errors<R: Rng>(
+    codeword: &[bool],
+    m: usize,
+    count: usize,
+    rng: &mut R,
+) -> (Vec<bool>, Vec<usize>) {
+    let mut received = codeword.to_vec();
+    let symbols = received.len() / m.max(1);
+    let mut positions = rand::seq::index::sample(rng, symbols, count.min(symbols)).into_vec();
+    positions.sort_unstable();
+    for &symbol in &positions {
+        let error = rng.random_range(1..1usize << m);
+        for bit in 0..m {
+            received[symbol * m + bit] ^= (error >> (m - 1 - bit)) & 1 == 1;
+        }
+    }
+    (received, positions)
+}
+
+/// Стирание `count` различных символов по `m` бит: разряды стертого символа обнуляются
+///
+/// Возвращает отсортированные номера стертых символов (с 0)
+#[must_use]
+pub fn erase_symbols<R: Rng>(
+    received: &mut [bool],
+    m: usize,
+    count: usize,
+    rng: &mut R,
+) -> Vec<usize> {
+    let symbols = received.len() / m.max(1);
+    let mut positions = rand::seq::index::sample(rng, symbols, count.min(symbols)).into_vec();
+    positions.sort_unstable();
+    for &symbol in &positions {
+        received[symbol * m..(symbol + 1) * m].fill(false);
+    }
+    positions
+}
+
+/// Двоичный симметричный канал: каждый бит инвертируется независимо с вероятностью `p`
+///
+/// Возвращает принятое слово и позиции ошибок (с 0)
+#[must_use]
+pub fn transmit_bsc<R: Rng>(codeword: &[bool], p: f64, rng: &mut R) -> (Vec<bool>, Vec<usize>) {
+    let p = p.clamp(0.0, 1.0);
+    let mut positions = vec![];
+    let received = codeword
+        .iter()
+        .enumerate()
+        .map(|(i, &bit)| {
+            if rng.random_bool(p) {
+                positions.push(i);
+                !bit
+            } else {
+                bit
+            }
+        })
+        .collect();
+    (received, positions)
+}
+
+/// Внесение пакета ошибок длины `length`
+///
+/// Первый и последний разряды пакета инвертируются всегда, внутренние - с вероятностью 1/2.
+/// Если `start` не задан (с 0), начало пакета выбирается случайно.
+/// Возвращает принятое слово и позиции ошибок (с 0)
+#[must_use]
+pub fn inject_burst<R: Rng>(
+    codeword: &[bool],
+    length: usize,
+    start: Option<usize>,
+    rng: &mut R,
+) -> (Vec<bool>, Vec<usize>) {
+    let mut received = codeword.to_vec();
+    let length = length.min(received.len());
+    if length == 0 {
+        return (received, vec![]);
+    }
+    let last_start = received.len() - length;
+    let start = start.map_or_else(|| rng.random_range(0..=last_start), |s| s.min(last_start));
+
+    let mut positions = vec![];
+    for offset in 0..length {
+        if offset == 0 || offset == length - 1 || rng.random_bool(0.5) {
+            let position = start + offset;
+            received[position] = !received[position];
+            positions.push(position);
+        }
+    }
+    (received, positions)
+}
+
+/// Канал Гилберта–Эллиота: вероятность ошибки зависит от скрытого состояния канала
+///
+/// Начальное состояние выбирается по стационарному распределению.
+/// Возвращает принятое слово и позиции ошибок (с 0)
+#[must_use]
+pub fn transmit_gilbert_elliott<R: Rng>(
+    codeword: &[bool],
+    params: &GilbertElliott,
+    rng: &mut R,
+) -> (Vec<bool>, Vec<usize>) {
+    let mut bad = rng.random_bool(params.bad_state_probability().clamp(0.0, 1.0));
+    let mut positions = vec![];
+    let received = codeword
+        .iter()
+        .enumerate()
+        .map(|(i, &bit)| {
+            let p = if bad {
+                params.bad_error_probability
+            } else {
+                params.good_error_probability
+            };
+            let flipped = rng.random_bool(p.clamp(0.0, 1.0));
+            let switch = if bad {
+                params.bad_to_good
+            } else {
+                params.good_to_bad
+            };
+            if rng.random_bool(switch.clamp(0.0, 1.0)) {
+                bad = !bad;
+            }
+            if flipped {
+                positions.push(i);
+                !bit
+            } else {
+                bit
+            }
+        })
+        .collect();
+    (received, positions)
+}
@@ -12,3 +12,52 @@ where
     }
     results.iter().map(f).sum::<f64>() / results.len() as f64
 }
+
+/// Минимум и максимум по результатам экспериментов
+#[must_use]
+pub fn calculate_min_max<F>(results: &[ExperimentResult], f: F) -> (f64, f64)
+where
+    F: Fn(&ExperimentResult) -> f64,
+{
+    if results.is_empty() {
+        return (0.0, 0.0);
+    }
+    results
+        .iter()
+        .map(f)
+        .fold((f64::INFINITY, f64::NEG_INFINITY), |(min, max), v| {
+            (min.min(v), max.max(v))
+        })
+}
+
+/// Гистограмма значений: центры интервалов и число попаданий
+#[must_use]
+#[allow(
+    clippy::cast_precision_loss,
+    clippy::cast_possible_truncation,
+    clippy::cast_sign_loss
+)]
+pub fn build_histogram(values: &[f64], bins: usize) -> Vec<(f64, usize)> {
+    if values.is_empty() || bins == 0 {
+        return vec![];
+    }
+    let min = values.iter().copied().fold(f64::INFINITY, f64::min);
+    let max = values.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+    let width = if max > min {
+        (max - min) / bins as f64
+    } else {
+        1.0
+    };
+
+    let mut counts = vec![0; bins];
+    for &value in values {
+        let bin = (((value - min) / width) as usize).min(bins - 1);
+        counts[bin] += 1;
+    }
+
+    counts
+        .into_iter()
+        .enumerate()
+        .map(|(i, count)| (min + width * (i as f64 + 0.5), count))
+        .collect()
+}
@@ -0,0 +1,267 @@
+use crate::formatting::message::{bits_to_bytes, bytes_to_bits};
+use crate::session::CodeConfigSnapshot;
+use crate::state::code_config::{CodeConfig, DecodingOutcome, Labs4To6ExperimentResult};
+use crate::utils::random::{experiment_seed, rng_from_seed};
+use std::path::PathBuf;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::mpsc::{self, Receiver, TryRecvError};
+use std::thread;
+
+/// Результат передачи последовательности битов блоками по `k` битов
+#[derive(Clone)]
+pub struct Transmission {
+    /// Исходные биты
+    pub original: Vec<bool>,
+    /// Информационные разряды принятых слов без исправления ошибок
+    /// (`None` для сверточного кода, у которого нет информационных позиций)
+    pub received: Option<Vec<bool>>,
+    /// Информационные разряды после декодирования
+    pub decoded: Vec<bool>,
+    /// Результаты по каждому блоку
+    pub blocks: Vec<Labs4To6ExperimentResult>,
+}
+
+/// Передача битов через выбранный код и канал
+///
+/// Биты разбиваются на сообщения по `k` битов (последнее дополняется нулями), каждое
+/// кодируется, передается через канал и декодируется. Блок `i` использует seed `seed + i`
+#[must_use]
+pub fn transmit_bits(config: &CodeConfig, bits: &[bool], seed: u64) -> Option<Transmission> {
+    let mut blocks = vec![];
+    let (received, decoded) =
+        transmit_blocks(config, bits, seed, &AtomicBool::new(false), |block| {
+            blocks.push(block);
+        })?;
+    Some(Transmission {
+        original: bits.to_vec(),
+        received,
+        decoded,
+        blocks,
+    })
+}
+
+/// Разряды слова на позициях `positions` (недостающие разряды считаются нулевыми)
+#[must_use]
+pub fn select_bits(word: &[bool], positions: &[usize]) -> Vec<bool> {
+    positions
+        .iter()
+        .map(|&j| word.get(j).copied().unwrap_or(false))
+        .collect()
+}
+
+/// Поблочная передача битов; результат каждого блока передается в `on_block`
+///
+/// Возвращает информационные разряды принятых слов без исправления и сообщение после
+/// декодирования. У сверточного кода информационных позиций нет, поэтому принятые
+/// разряды без исправления для него не определены (`None`). Если код не инициализирован
+/// или установлен `cancel`, возвращает `None`
+pub fn transmit_blocks(
+    config: &CodeConfig,
+    bits: &[bool],
+    seed: u64,
+    cancel: &AtomicBool,
+    mut on_block: impl FnMut(Labs4To6ExperimentResult),
+) -> Option<(Option<Vec<bool>>, Vec<bool>)> {
+    let positions = config.information_positions();
+    let mut received = positions.as_ref().map(|_| Vec::with_capacity(bits.len()));
+    let mut decoded = Vec::with_capacity(bits.len());
+
+    for (i, chunk) in bits.chunks(config.k).enumerate() {
+        if cancel.load(Ordering::Relaxed) {
+            return None;
+        }
+        let block_seed = experiment_seed(seed, i);
+        let mut rng = rng_from_seed(block_seed);
+
+        let mut message = chunk.to_vec();
+        message.resize(config.k, false);
+        let transmitted = config.encode(&message)?;
+        let (mut word, _) = config.transmit(&transmitted, &mut rng);
+        let erasures = config.erase(&mut word, &mut rng);
+        let result = config.evaluate_with_erasures(block_seed, message, word, erasures)?;
+
+        if let (Some(received), Some(positions)) = (received.as_mut(), positions.as_deref()) {
+            received.extend(select_bits(&result.received, positions));
+        }
+        decoded.extend(config.decoded_message(
+            &result.received,
+            &result.corrected,
+            positions.as_deref(),
+        )?);
+        on_block(result);
+    }
+
+    if let Some(ref mut received) = received {
+        received.truncate(bits.len());
+    }
+    decoded.truncate(bits.len());
+    Some((received, decoded))
+}
+
+/// Отчет о передаче файла
+#[derive(Clone)]
+pub struct FileReport {
+    /// Размер файла в байтах
+    pub bytes: usize,
+    /// Итог декодирования каждого блока
+    pub outcomes: Vec<DecodingOutcome>,
+    /// Ошибочных битов в принятых данных без исправления
+    /// (`None` для сверточного кода, у которого нет информационных позиций)
+    pub raw_bit_errors: Option<usize>,
+    /// Ошибочных битов после декодирования
+    pub residual_bit_errors: usize,
+    /// Искаженных байтов после декодирования
+    pub corrupted_bytes: usize,
+}
+
+impl FileReport {
+    /// Число блоков с заданным итогом
+    #[must_use]
+    pub fn count(&self, outcome: DecodingOutcome) -> usize {
+        self.outcomes.iter().filter(|&&o| o == outcome).count()
+    }
+
+    /// Текст отчета
+    #[must_use]
+    pub fn to_text(&self, config: &CodeConfig, seed: u64) -> String {
+        let mut lines = vec![
+            format!(
+                "Код: {}, k = {}, n = {}",
+                config.code_type.label(),
+                config.k,
+                config.n
+            ),
+            format!("Канал: {}", config.channel.model.label()),
+            format!("Seed: {seed}"),
+            format!("Размер файла: {} байт", self.bytes),
+            format!("Блоков: {}", self.outcomes.len()),
+        ];
+        lines.extend(
+            DecodingOutcome::ALL
+                .iter()
+                .map(|&outcome| format!("  {}: {}", outcome.label(), self.count(outcome))),
+        );
+        if let Some(raw_bit_errors) = self.raw_bit_errors {
+            lines.push(format!("Ошибочных битов без исправления: {raw_bit_errors}"));
+        }
+        lines.push(format!(
+            "Ошибочных битов после декодирования: {}",
+            self.residual_bit_errors
+        ));
+        lines.push(format!(
+            "Искаженных байтов после декодирования: {}",
+            self.corrupted_bytes
+        ));
+        lines.join("\n") + "\n"
+    }
+}
+
+/// Передача содержимого файла через выбранный код и канал
+///
+/// Число переданных блоков обновляется в `progress`. Возвращает восстановленные байты
+/// и отчет; `None`, если код не инициализирован или передача остановлена через `cancel`
+#[must_use]
+pub fn transmit_file(
+    config: &CodeConfig,
+    data: &[u8],
+    seed: u64,
+    cancel: &AtomicBool,
+    progress: &AtomicUsize,
+) -> Option<(Vec<u8>, FileReport)> {
+    let bits = bytes_to_bits(data);
+    let mut outcomes = vec![];
+    let (received, decoded) = transmit_blocks(config, &bits, seed, cancel, |block| {
+        outcomes.push(block.outcome());
+        progress.fetch_add(1, Ordering::Relaxed);
+    })?;
+
+    let count_errors = |word: &[bool]| word.iter().zip(&bits).filter(|(a, b)| a != b).count();
+    let recovered = bits_to_bytes(&decoded);
+    let report = FileReport {
+        bytes: data.len(),
+        outcomes,
+        raw_bit_errors: received.as_deref().map(count_errors),
+        residual_bit_errors: count_errors(&decoded),
+        corrupted_bytes: recovered.iter().zip(data).filter(|(a, b)| a != b).count(),
+    };
+    Some((recovered, report))
+}
+
+/// Передача файла, запущенная в фоновом потоке
+///
+/// Код для потока строится заново из снимка конфигурации, как в
+/// [`BerJob`](crate::utils::ber::BerJob)
+pub struct FileJob {
+    receiver: Receiver<Option<(Vec<u8>, FileReport)>>,
+    cancel: Arc<AtomicBool>,
+    progress: Arc<AtomicUsize>,
+    /// Исходный файл
+    pub source: PathBuf,
+    /// Seed передачи
+    pub seed: u64,
+    /// Число блоков
+    pub total: usize,
+}
+
+impl FileJob {
+    /// Запуск передачи в фоновом потоке
+    #[must_use]
+    pub fn spawn(config: &CodeConfig, source: PathBuf, data: Vec<u8>, seed: u64) -> Self {
+        let snapshot = CodeConfigSnapshot::from(config);
+        let (sender, receiver) = mpsc::channel();
+        let cancel = Arc::new(AtomicBool::new(false));
+        let progress = Arc::new(AtomicUsize::new(0));
+        let total = (data.len() * 8).div_ceil(config.k.max(1));
+
+        let worker_cancel = Arc::clone(&cancel);
+        let worker_progress = Arc::clone(&progress);
+        thread::spawn(move || {
+            let result = CodeConfig::try_from(snapshot).ok().and_then(|config| {
+                transmit_file(&config, &data, seed, &worker_cancel, &worker_progress)
+            });
+            if result.is_some() || !worker_cancel.load(Ordering::Relaxed) {
+                let _ = sender.send(result);
+            }
+        });
+
+        Self {
+            receiver,
+            cancel,
+            progress,
+            source,
+            seed,
+            total,
+        }
+    }
+
+    /// Число переданных блоков
+    #[must_use]
+    pub fn progress(&self) -> usize {
+        self.progress.load(Ordering::Relaxed)
+    }
+
+    /// Итог передачи, если поток завершился
+    ///
+    /// # Errors
+    /// Возвращает ошибку, если код не инициализирован или передача остановлена
+    pub fn poll(&self) -> Option<Result<(Vec<u8>, FileReport), String>> {
+        match self.receiver.try_recv() {
+            Ok(Some(result)) => Some(Ok(result)),
+            Ok(None) => Some(Err("код не инициализирован".to_string())),
+            Err(TryRecvError::Empty) => None,
+            Err(TryRecvError::Disconnected) => Some(Err("передача остановлена".to_string())),
+        }
+    }
+
+    /// Остановка передачи
+    pub fn cancel(&self) {
+        self.cancel.store(true, Ordering::Relaxed);
+    }
+}
+
+impl Drop for FileJob {
+    fn drop(&mut self) {
+        self.cancel();
+    }
+}
@@ -0,0 +1,78 @@
+/// Допустимое отклонение суммы вероятностей от единицы
+pub const SUM_TOLERANCE: f64 = 1e-6;
+
+/// Проверка распределения вероятностей: неотрицательность и сумма, равная 1
+///
+/// # Errors
+/// Возвращает описание первой найденной проблемы
+pub fn validate_distribution(probs: &[f64]) -> Result<(), String> {
+    if probs.is_empty() {
+        return Err("распределение пустое".to_string());
+    }
+    if let Some(i) = probs.iter().position(|p| !p.is_finite() || *p < 0.0) {
+        return Err(format!("p{} = {} - недопустимое значение", i + 1, probs[i]));
+    }
+    let sum: f64 = probs.iter().sum();
+    if (sum - 1.0).abs() > SUM_TOLERANCE {
+        return Err(format!("сумма вероятностей {sum:.6} ≠ 1"));
+    }
+    Ok(())
+}
+
+/// Проверка матрицы переходов p(y|x): квадратная матрица размера `signals`,
+/// каждая строка - распределение вероятностей
+///
+/// # Errors
+/// Возвращает описание проблем по каждой некорректной строке
+pub fn validate_transition_matrix(matrix: &[Vec<f64>], signals: usize) -> Result<(), Vec<String>> {
+    if matrix.len() != signals {
+        return Err(vec![format!(
+            "ожидается {signals} строк, получено {}",
+            matrix.len()
+        )]);
+    }
+
+    let errors: Vec<String> = matrix
+        .iter()
+        .enumerate()
+        .filter_map(|(i, row)| {
+            if row.len() != signals {
+                return Some(format!(
+                    "строка x{}: ожидается {signals} элементов, получено {}",
+                    i + 1,
+                    row.len()
+                ));
+            }
+            validate_distribution(row)
+                .err()
+                .map(|err| format!("строка x{}: {err}", i + 1))
+        })
+        .collect();
+
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(errors)
+    }
+}
+
+/// Нормировка вектора к сумме 1 (отрицательные значения обнуляются)
+///
+/// Если все значения нулевые, получается равномерное распределение
+#[allow(clippy::cast_precision_loss)]
+pub fn normalize(values: &mut [f64]) {
+    for value in values.iter_mut() {
+        if !value.is_finite() || *value < 0.0 {
+            *value = 0.0;
+        }
+    }
+    let sum: f64 = values.iter().sum();
+    if sum > 0.0 {
+        for value in values.iter_mut() {
+            *value /= sum;
+        }
+    } else if !values.is_empty() {
+        let uniform = 1.0 / values.len() as f64;
+        values.fill(uniform);
+    }
+}